@@ -1,5 +1,5 @@
 import test from 'ava'
-import { transform, Config, State } from '..'
+import { transform, transformSprite, Config, State } from '..'
 
 const svgBaseCode = `
 <svg width="88px" height="88px" xmlns="http://www.w3.org/2000/svg" viewBox="0 0 88 88">
@@ -11,7 +11,7 @@ const svgBaseCode = `
 
 test('without config and state', async t => {
 	const result = await transform(svgBaseCode)
-	t.snapshot(result)
+	t.snapshot(result.code)
 })
 
 const configs: (Config & { state?: Partial<State> })[] = [
@@ -52,7 +52,7 @@ configs.forEach(async c => {
 	test(`accepts options ${JSON.stringify(c)}`, async t => {
 		const { state, ...config } = c
 		const result = await transform(svgBaseCode, config, state)
-		t.snapshot(result)
+		t.snapshot(result.code)
 	})
 })
 
@@ -63,7 +63,7 @@ test('titleProp: without title added', async t => {
 </svg>
 `
 	const result = await transform(svg, { titleProp: true })
-	t.snapshot(result)
+	t.snapshot(result.code)
 })
 
 test('descProp: without desc added', async t => {
@@ -73,5 +73,36 @@ test('descProp: without desc added', async t => {
 </svg>
 `
 	const result = await transform(svg, { descProp: true })
-	t.snapshot(result)
+	t.snapshot(result.code)
+})
+
+test('returns the component name, exports and warnings', async t => {
+	const result = await transform(
+		'<svg><filter/><path/></svg>',
+		{ native: true, exportType: 'named' },
+		{ filePath: 'icons/arrow-left.svg' },
+	)
+	t.is(result.componentName, 'SvgArrowLeft')
+	t.deepEqual(result.exports, ['ReactComponent'])
+	t.deepEqual(result.imports, ['react', 'react-native-svg'])
+	t.deepEqual(result.warnings.map(w => w.code), ['react-native-unsupported-element'])
+})
+
+test('rejects with the code and the location of the error', async t => {
+	const error = await t.throwsAsync<Error & Record<string, unknown>>(
+		transform('<div></div>', {}, { filePath: 'icons/star.svg' }),
+	)
+	t.is(error?.code, 'non-svg-root')
+	t.is(error?.filePath, 'icons/star.svg')
+	t.deepEqual(error?.loc, { line: 1, column: 1 })
+})
+
+test('renders the symbols of a sprite sheet', async t => {
+	const { sprite, results } = await transformSprite(
+		[{ code: '<svg viewBox="0 0 24 24"><path d="M0 0h24"/></svg>', state: { componentName: 'Star' } }],
+		'/icons/sprite.svg',
+	)
+	t.true(sprite.includes('<symbol id="star" viewBox="0 0 24 24">'))
+	t.is(results[0].error, undefined)
+	t.true(results[0].output?.code.includes('/icons/sprite.svg#star'))
 })
@@ -31,6 +31,31 @@ export interface JsJsxRuntimeImport {
   defaultSpecifier?: string
   specifiers?: Array<string>
 }
+export interface JsBaseClass {
+  name: string
+  source?: string
+}
+export interface JsSvgoConfig {
+  removeDoctype?: boolean
+  removeXmlProcInst?: boolean
+  removeComments?: boolean
+  removeMetadata?: boolean
+  removeEditorsNsData?: boolean
+  cleanupAttrs?: boolean
+  removeUselessDefs?: boolean
+  collapseGroups?: boolean
+  removeEmptyAttrs?: boolean
+  removeEmptyText?: boolean
+  removeEmptyContainers?: boolean
+}
+export interface JsPrettierConfig {
+  printWidth?: number
+  tabWidth?: number
+  useTabs?: boolean
+  singleQuote?: boolean
+  semi?: boolean
+  trailingComma?: 'none' | 'es5' | 'all'
+}
 export interface JsConfig {
   /** Setting this to `true` will forward ref to the root SVG tag. */
   ref?: boolean
@@ -64,6 +89,14 @@ export interface JsConfig {
    * All unsupported nodes will be removed.
    */
   native?: boolean
+  /** What happens to the elements not supported by react-native-svg, they are dropped by default. */
+  nativeUnsupported?: 'drop' | 'keep' | 'error'
+  /** Extra element to component mappings with `native`, like `filter` to `Filter`. */
+  nativeComponents?: { [element: string]: string }
+  /** The source the components are imported from with `native`, `react-native-svg` by default. */
+  nativeImportSource?: string
+  /** The policy of specific elements with `native`, it takes precedence over their component. */
+  nativeElements?: { [element: string]: 'drop' | 'keep' | 'error' }
   /** Add props to the root SVG tag. */
   svgProps?: { [key: string]: string }
   /** Generates `.tsx` files with TypeScript typings. */
@@ -88,6 +121,108 @@ export interface JsConfig {
   namedExport?: string
   /** If you prefer named export in any case, you may set the `export_type` option to `named`. */
   exportType?: 'named' | 'default'
+  /**
+   * Customize the code output with a template, the placeholders `%%imports%%`, `%%interfaces%%`,
+   * `%%componentName%%`, `%%props%%`, `%%jsx%%` and `%%exports%%` are replaced by the template variables.
+   */
+  template?: string
+  /**
+   * The names of the built-in plugins to apply, in order.
+   * Defaults to all the built-in plugins.
+   */
+  plugins?: Array<string>
+  /** Optimize the SVG document before it is converted into JSX. */
+  svgo?: boolean
+  /** The SVGO plugins to apply, only used when `svgo` is enabled. */
+  svgoConfig?: JsSvgoConfig
+  /** Format the generated code with the built-in pretty printer. */
+  prettier?: boolean
+  /** The options of the pretty printer, only used when `prettier` is enabled. */
+  prettierConfig?: JsPrettierConfig
+  /**
+   * Load the nearest runtime config file (`.svgrrc`, `svgr.config.json`, `svgr.config.toml`, the `svgr` key of `package.json`...),
+   * searched from the directory of the transformed file upwards. Options set explicitly take precedence over the ones of the file.
+   */
+  runtimeConfig?: boolean
+  /** Use this config file instead of searching for one, only used when `runtime_config` is enabled. */
+  configFile?: string
+  /** Generate an index file re-exporting the components when transforming a directory, only used by the CLI. */
+  index?: boolean
+  /**
+   * Generate a v3 source map from the SVG to the generated code, returned as `map`.
+   * No source map is generated when `prettier` is enabled.
+   */
+  sourceMaps?: boolean
+  /** Fail when the XML parser recovered from errors, they are only reported as warnings otherwise. */
+  strict?: boolean
+  /** Keep the comments of the SVG, at the top of the module or as JSX comments. */
+  preserveComments?: boolean
+  /** Inline the rules of the `<style>` elements into the matching elements, then remove them. */
+  inlineStyles?: boolean
+  /** Extract the rules of the `<style>` elements into a CSS module returned in `files`. */
+  cssModules?: boolean
+  /**
+   * The framework the component is generated for, React by default.
+   * * "vue": a component created with `defineComponent`, rendering the SVG with `h()`
+   * * "vue-sfc": a single-file component, rendering the SVG in its `<template>`
+   * * "solid": a SolidJS component, splitting its props with `splitProps`
+   * * "svelte": a Svelte component, rendering the SVG in its markup
+   * * "lit": a custom element extending `LitElement`
+   * * "web-component": a custom element extending `HTMLElement`, rendering the SVG in its shadow root
+   */
+  target?: 'react' | 'vue' | 'vue-sfc' | 'solid' | 'svelte' | 'lit' | 'web-component'
+  /** The name the custom element is registered under, defaults to the kebab-case component name. */
+  elementName?: string
+  /** The class extended by the custom element, imported from `source` when it is specified. */
+  baseClass?: JsBaseClass
+  /**
+   * Rewrite the ids of the SVG and the references to them so they do not collide.
+   * * "prefix": prefix them with the file name and a hash of the file path
+   * * "use-id": prefix them with an id generated at runtime, React and SolidJS only
+   */
+  uniqueIds?: 'none' | 'prefix' | 'use-id'
+  /**
+   * Generate a default `titleId` and `descId` when they are not given, React and SolidJS only.
+   * * "use-id": with `useId` or `createUniqueId`
+   * * "counter": with a module counter prefixed with the file name, for React versions without `useId`
+   */
+  fallbackIds?: 'none' | 'use-id' | 'counter'
+}
+export interface JsIndexFile {
+  /** The path of the generated file, relative to the directory of the index file. */
+  path: string
+  componentName: string
+}
+/** A position in the SVG source code, the line and the column start at 1. */
+export interface JsLocation {
+  line: number
+  column: number
+}
+export interface JsWarning {
+  /** A stable identifier of the kind of warning, for example `react-native-unsupported-element`. */
+  code: string
+  message: string
+  loc?: JsLocation
+}
+export interface JsOutputFile {
+  /** The path of the file, relative to the directory of the component. */
+  path: string
+  code: string
+}
+export interface JsTransformOutput {
+  /** The generated code of the component. */
+  code: string
+  /** The v3 source map of the generated code as JSON, only generated when `sourceMaps` is enabled. */
+  map?: string
+  /** The name of the component, either given in the state or derived from the file path. */
+  componentName: string
+  /** The names exported by the generated module, `default` for a default export. */
+  exports: Array<string>
+  /** The sources imported by the generated module, in order. */
+  imports: Array<string>
+  warnings: Array<JsWarning>
+  /** The files generated with the component, like the CSS module of `cssModules`. */
+  files: Array<JsOutputFile>
 }
 export interface JsCaller {
   name?: string
@@ -99,3 +234,40 @@ export interface JsState {
   caller?: JsCaller
 }
 export declare function transform(code: string, config?: JsConfig | undefined | null, state?: JsState | undefined | null): Promise<unknown>
+export interface JsTransformInput {
+  code: string
+  state?: JsState
+}
+/** An error of a transformation, with the location of the offending SVG code when known. */
+export interface JsTransformError {
+  message: string
+  /** A stable identifier of the kind of error, for example `non-svg-root`. */
+  code: string
+  filePath?: string
+  loc?: JsLocation
+  /** The offending line of the SVG with a caret under the location. */
+  snippet?: string
+}
+/** The result of a file of a batch, either the output of the transformation or its error. */
+export interface JsTransformResult {
+  output?: JsTransformOutput
+  error?: JsTransformError
+}
+/** Transform many SVG in parallel with the same config, the results are in the order of the inputs. */
+export declare function transformBatch(inputs: Array<JsTransformInput>, config?: JsConfig | undefined | null): Promise<unknown>
+/** The sprite sheet and the components rendering its symbols. */
+export interface JsSpriteOutput {
+  /** The sprite sheet, an SVG with a `<symbol>` for each SVG transformed successfully. */
+  sprite: string
+  /** The components rendering the symbols with `<use>`, in the order of the inputs. */
+  results: Array<JsTransformResult>
+}
+/**
+ * Merge many SVG into a sprite sheet served from `href`, and transform each of them into a
+ * component rendering its symbol with `<use>`.
+ */
+export declare function transformSprite(inputs: Array<JsTransformInput>, href: string, config?: JsConfig | undefined | null): Promise<unknown>
+/** Find the nearest runtime config file from the given file or directory and return its options. */
+export declare function resolveConfig(path: string): any | null
+/** Create the code of an index file re-exporting the given generated components. */
+export declare function createIndex(files: Array<JsIndexFile>, config?: JsConfig | undefined | null): string
@@ -252,6 +252,10 @@ if (!nativeBinding) {
   throw new Error(`Failed to load native binding`)
 }
 
-const { transform } = nativeBinding
+const { transform, transformBatch, transformSprite, resolveConfig, createIndex } = nativeBinding
 
 module.exports.transform = transform
+module.exports.transformBatch = transformBatch
+module.exports.transformSprite = transformSprite
+module.exports.resolveConfig = resolveConfig
+module.exports.createIndex = createIndex
@@ -1,10 +1,17 @@
-use std::collections::HashMap;
+use std::{
+  collections::{HashMap, HashSet},
+  sync::Arc,
+};
 
 use napi::{
   bindgen_prelude::{Either3, FromNapiValue, Object},
   Either,
 };
-use svgr_rs::{Config, ExpandProps, ExportType, Icon, JSXRuntime, JSXRuntimeImport, SvgProp};
+use svgr_rs::{
+  default_plugins, get_builtin_plugin, BaseClass, Config, ConfigOption, ExpandProps, ExportType,
+  FallbackIds, Icon, JSXRuntime, JSXRuntimeImport, NativeElementPolicy, PrettierConfig,
+  StringTemplate, SvgProp, SvgoConfig, Target, TrailingComma, UniqueIds,
+};
 
 #[derive(Clone)]
 pub struct JsSvgProps(Vec<SvgProp>);
@@ -28,23 +35,23 @@ impl FromNapiValue for JsSvgProps {
 }
 
 #[derive(Clone)]
-pub struct JsReplaceAttrValues(HashMap<String, String>);
+pub struct JsStringMap(HashMap<String, String>);
 
-impl FromNapiValue for JsReplaceAttrValues {
+impl FromNapiValue for JsStringMap {
   unsafe fn from_napi_value(
     env: napi::sys::napi_env,
     napi_val: napi::sys::napi_value,
   ) -> napi::Result<Self> {
     let js_object: Object = FromNapiValue::from_napi_value(env, napi_val)?;
     let keys = Object::keys(&js_object)?;
-    let mut replace_attr_values = HashMap::new();
+    let mut map = HashMap::new();
     for key in keys {
       let value = js_object.get::<&str, String>(&key)?;
       if let Some(value) = value {
-        replace_attr_values.insert(key, value);
+        map.insert(key, value);
       }
     }
-    Ok(JsReplaceAttrValues(replace_attr_values))
+    Ok(JsStringMap(map))
   }
 }
 
@@ -57,6 +64,96 @@ pub struct JsJSXRuntimeImport {
   pub specifiers: Option<Vec<String>>,
 }
 
+#[napi(object, object_to_js = false)]
+#[derive(Clone)]
+pub struct JsBaseClass {
+  pub name: String,
+  pub source: Option<String>,
+}
+
+#[napi(object, object_to_js = false)]
+#[derive(Clone)]
+pub struct JsSvgoConfig {
+  pub remove_doctype: Option<bool>,
+  pub remove_xml_proc_inst: Option<bool>,
+  pub remove_comments: Option<bool>,
+  pub remove_metadata: Option<bool>,
+  pub remove_editors_ns_data: Option<bool>,
+  pub cleanup_attrs: Option<bool>,
+  pub remove_useless_defs: Option<bool>,
+  pub collapse_groups: Option<bool>,
+  pub remove_empty_attrs: Option<bool>,
+  pub remove_empty_text: Option<bool>,
+  pub remove_empty_containers: Option<bool>,
+}
+
+impl From<JsSvgoConfig> for SvgoConfig {
+  fn from(val: JsSvgoConfig) -> Self {
+    let default = SvgoConfig::default();
+    Self {
+      remove_doctype: val.remove_doctype.unwrap_or(default.remove_doctype),
+      remove_xml_proc_inst: val
+        .remove_xml_proc_inst
+        .unwrap_or(default.remove_xml_proc_inst),
+      remove_comments: val.remove_comments.unwrap_or(default.remove_comments),
+      remove_metadata: val.remove_metadata.unwrap_or(default.remove_metadata),
+      remove_editors_ns_data: val
+        .remove_editors_ns_data
+        .unwrap_or(default.remove_editors_ns_data),
+      cleanup_attrs: val.cleanup_attrs.unwrap_or(default.cleanup_attrs),
+      remove_useless_defs: val
+        .remove_useless_defs
+        .unwrap_or(default.remove_useless_defs),
+      collapse_groups: val.collapse_groups.unwrap_or(default.collapse_groups),
+      remove_empty_attrs: val.remove_empty_attrs.unwrap_or(default.remove_empty_attrs),
+      remove_empty_text: val.remove_empty_text.unwrap_or(default.remove_empty_text),
+      remove_empty_containers: val
+        .remove_empty_containers
+        .unwrap_or(default.remove_empty_containers),
+    }
+  }
+}
+
+#[napi(object, object_to_js = false)]
+#[derive(Clone)]
+pub struct JsPrettierConfig {
+  pub print_width: Option<u32>,
+  pub tab_width: Option<u32>,
+  pub use_tabs: Option<bool>,
+  pub single_quote: Option<bool>,
+  pub semi: Option<bool>,
+  #[napi(ts_type = "'none' | 'es5' | 'all'")]
+  pub trailing_comma: Option<String>,
+}
+
+impl From<JsPrettierConfig> for PrettierConfig {
+  fn from(val: JsPrettierConfig) -> Self {
+    let default = PrettierConfig::default();
+    let trailing_comma = match val.trailing_comma {
+      Some(s) => match s.as_str() {
+        "none" => TrailingComma::None,
+        "es5" => TrailingComma::Es5,
+        _ => TrailingComma::All,
+      },
+      None => default.trailing_comma,
+    };
+    Self {
+      print_width: val
+        .print_width
+        .map(|w| w as usize)
+        .unwrap_or(default.print_width),
+      tab_width: val
+        .tab_width
+        .map(|w| w as usize)
+        .unwrap_or(default.tab_width),
+      use_tabs: val.use_tabs.unwrap_or(default.use_tabs),
+      single_quote: val.single_quote.unwrap_or(default.single_quote),
+      semi: val.semi.unwrap_or(default.semi),
+      trailing_comma,
+    }
+  }
+}
+
 #[napi(object, object_to_js = false)]
 #[derive(Clone)]
 pub struct JsConfig {
@@ -87,6 +184,21 @@ pub struct JsConfig {
   /// All unsupported nodes will be removed.
   pub native: Option<bool>,
 
+  /// What happens to the elements not supported by react-native-svg, they are dropped by default.
+  #[napi(ts_type = "'drop' | 'keep' | 'error'")]
+  pub native_unsupported: Option<String>,
+
+  /// Extra element to component mappings with `native`, like `filter` to `Filter`.
+  #[napi(ts_type = "{ [element: string]: string }")]
+  pub native_components: Option<JsStringMap>,
+
+  /// The source the components are imported from with `native`, `react-native-svg` by default.
+  pub native_import_source: Option<String>,
+
+  /// The policy of specific elements with `native`, it takes precedence over their component.
+  #[napi(ts_type = "{ [element: string]: 'drop' | 'keep' | 'error' }")]
+  pub native_elements: Option<JsStringMap>,
+
   /// Add props to the root SVG tag.
   #[napi(ts_type = "{ [key: string]: string }")]
   pub svg_props: Option<JsSvgProps>,
@@ -100,7 +212,7 @@ pub struct JsConfig {
   /// Replace an attribute value by an other.
   /// The main usage of this option is to change an icon color to "currentColor" in order to inherit from text color.
   #[napi(ts_type = "{ [key: string]: string }")]
-  pub replace_attr_values: Option<JsReplaceAttrValues>,
+  pub replace_attr_values: Option<JsStringMap>,
 
   /// Specify a JSX runtime to use.
   /// * "classic": adds `import * as React from 'react'` on the top of file
@@ -117,12 +229,146 @@ pub struct JsConfig {
   /// If you prefer named export in any case, you may set the `export_type` option to `named`.
   #[napi(ts_type = "'named' | 'default'")]
   pub export_type: Option<String>,
+
+  /// Customize the code output with a template, the placeholders `%%imports%%`, `%%interfaces%%`,
+  /// `%%componentName%%`, `%%props%%`, `%%jsx%%` and `%%exports%%` are replaced by the template variables.
+  pub template: Option<String>,
+
+  /// The names of the built-in plugins to apply, in order.
+  /// Defaults to all the built-in plugins.
+  pub plugins: Option<Vec<String>>,
+
+  /// Optimize the SVG document before it is converted into JSX.
+  pub svgo: Option<bool>,
+
+  /// The SVGO plugins to apply, only used when `svgo` is enabled.
+  pub svgo_config: Option<JsSvgoConfig>,
+
+  /// Format the generated code with the built-in pretty printer.
+  pub prettier: Option<bool>,
+
+  /// The options of the pretty printer, only used when `prettier` is enabled.
+  pub prettier_config: Option<JsPrettierConfig>,
+
+  /// Load the nearest runtime config file (`.svgrrc`, `svgr.config.json`, `svgr.config.toml`, the `svgr` key of `package.json`...),
+  /// searched from the directory of the transformed file upwards. Options set explicitly take precedence over the ones of the file.
+  pub runtime_config: Option<bool>,
+
+  /// Use this config file instead of searching for one, only used when `runtime_config` is enabled.
+  pub config_file: Option<String>,
+
+  /// Generate an index file re-exporting the components when transforming a directory, only used by the CLI.
+  pub index: Option<bool>,
+
+  /// Generate a v3 source map from the SVG to the generated code, returned as `map`.
+  /// No source map is generated when `prettier` is enabled.
+  pub source_maps: Option<bool>,
+
+  /// Fail when the XML parser recovered from errors, they are only reported as warnings otherwise.
+  pub strict: Option<bool>,
+
+  /// Keep the comments of the SVG, at the top of the module or as JSX comments.
+  pub preserve_comments: Option<bool>,
+
+  /// Inline the rules of the `<style>` elements into the matching elements, then remove them.
+  pub inline_styles: Option<bool>,
+
+  /// Extract the rules of the `<style>` elements into a CSS module returned in `files`.
+  pub css_modules: Option<bool>,
+
+  /// The framework the component is generated for, React by default.
+  /// * "vue": a component created with `defineComponent`, rendering the SVG with `h()`
+  /// * "vue-sfc": a single-file component, rendering the SVG in its `<template>`
+  /// * "solid": a SolidJS component, splitting its props with `splitProps`
+  /// * "svelte": a Svelte component, rendering the SVG in its markup
+  /// * "lit": a custom element extending `LitElement`
+  /// * "web-component": a custom element extending `HTMLElement`, rendering the SVG in its shadow root
+  #[napi(ts_type = "'react' | 'vue' | 'vue-sfc' | 'solid' | 'svelte' | 'lit' | 'web-component'")]
+  pub target: Option<String>,
+
+  /// The name the custom element is registered under, defaults to the kebab-case component name.
+  pub element_name: Option<String>,
+
+  /// The class extended by the custom element, imported from `source` when it is specified.
+  pub base_class: Option<JsBaseClass>,
+
+  /// Rewrite the ids of the SVG and the references to them so they do not collide.
+  /// * "prefix": prefix them with the file name and a hash of the file path
+  /// * "use-id": prefix them with an id generated at runtime, React and SolidJS only
+  #[napi(ts_type = "'none' | 'prefix' | 'use-id'")]
+  pub unique_ids: Option<String>,
+
+  /// Generate a default `titleId` and `descId` when they are not given, React and SolidJS only.
+  /// * "use-id": with `useId` or `createUniqueId`
+  /// * "counter": with a module counter prefixed with the file name, for React versions without `useId`
+  #[napi(ts_type = "'none' | 'use-id' | 'counter'")]
+  pub fallback_ids: Option<String>,
+}
+
+fn to_native_element_policy(raw: Option<&str>) -> NativeElementPolicy {
+  match raw {
+    Some("keep") => NativeElementPolicy::Keep,
+    Some("error") => NativeElementPolicy::Error,
+    _ => NativeElementPolicy::Drop,
+  }
+}
+
+/// The options set in the JavaScript config, they take precedence over the runtime config file.
+fn get_explicit_options(val: &JsConfig) -> HashSet<ConfigOption> {
+  macro_rules! explicit_options {
+    ($($field:ident => $option:ident),* $(,)?) => {
+      [$((ConfigOption::$option, val.$field.is_some())),*]
+        .into_iter()
+        .filter(|(_, is_set)| *is_set)
+        .map(|(option, _)| option)
+        .collect()
+    };
+  }
+
+  explicit_options!(
+    r#ref => Ref,
+    title_prop => TitleProp,
+    desc_prop => DescProp,
+    expand_props => ExpandProps,
+    dimensions => Dimensions,
+    icon => Icon,
+    native => Native,
+    native_unsupported => NativeUnsupported,
+    native_components => NativeComponents,
+    native_import_source => NativeImportSource,
+    native_elements => NativeElements,
+    svg_props => SvgProps,
+    typescript => Typescript,
+    memo => Memo,
+    replace_attr_values => ReplaceAttrValues,
+    jsx_runtime => JsxRuntime,
+    jsx_runtime_import => JsxRuntimeImport,
+    named_export => NamedExport,
+    export_type => ExportType,
+    template => Template,
+    plugins => Plugins,
+    svgo => Svgo,
+    svgo_config => SvgoConfig,
+    prettier => Prettier,
+    prettier_config => PrettierConfig,
+    index => Index,
+    strict => Strict,
+    preserve_comments => PreserveComments,
+    inline_styles => InlineStyles,
+    css_modules => CssModules,
+    target => Target,
+    element_name => ElementName,
+    base_class => BaseClass,
+    unique_ids => UniqueIds,
+    fallback_ids => FallbackIds,
+  )
 }
 
 impl TryFrom<JsConfig> for Config {
   type Error = napi::Error;
 
   fn try_from(val: JsConfig) -> Result<Self, Self::Error> {
+    let explicit_options = get_explicit_options(&val);
     let expand_props = match val.expand_props {
       Some(raw) => match raw {
         Either::A(b) => {
@@ -189,6 +435,46 @@ impl TryFrom<JsConfig> for Config {
       None => ExportType::Default,
     };
 
+    let target = match val.target {
+      Some(raw) => match raw.as_str() {
+        "vue" => Target::Vue,
+        "vue-sfc" => Target::VueSfc,
+        "solid" => Target::Solid,
+        "svelte" => Target::Svelte,
+        "lit" => Target::Lit,
+        "web-component" => Target::WebComponent,
+        _ => Target::React,
+      },
+      None => Target::React,
+    };
+
+    let unique_ids = match val.unique_ids.as_deref() {
+      Some("prefix") => UniqueIds::Prefix,
+      Some("use-id") => UniqueIds::UseId,
+      _ => UniqueIds::None,
+    };
+
+    let fallback_ids = match val.fallback_ids.as_deref() {
+      Some("use-id") => FallbackIds::UseId,
+      Some("counter") => FallbackIds::Counter,
+      _ => FallbackIds::None,
+    };
+
+    let plugins = match val.plugins {
+      Some(names) => names
+        .iter()
+        .map(|name| {
+          get_builtin_plugin(name).ok_or_else(|| {
+            napi::Error::from_reason(format!(
+              "invalid configuration option: unknown plugin \"{}\"",
+              name
+            ))
+          })
+        })
+        .collect::<Result<Vec<_>, _>>()?,
+      None => default_plugins(),
+    };
+
     Ok(Self {
       r#ref: val.r#ref.unwrap_or(false),
       title_prop: val.title_prop.unwrap_or(false),
@@ -197,6 +483,21 @@ impl TryFrom<JsConfig> for Config {
       dimensions: val.dimensions.unwrap_or(true),
       icon,
       native: val.native.unwrap_or(false),
+      native_unsupported: to_native_element_policy(val.native_unsupported.as_deref()),
+      native_components: val.native_components.map(|raw| raw.0).unwrap_or_default(),
+      native_import_source: val
+        .native_import_source
+        .unwrap_or_else(|| "react-native-svg".to_string()),
+      native_elements: val
+        .native_elements
+        .map(|raw| {
+          raw
+            .0
+            .into_iter()
+            .map(|(element, raw)| (element, to_native_element_policy(Some(&raw))))
+            .collect()
+        })
+        .unwrap_or_default(),
       svg_props,
       typescript: val.typescript.unwrap_or(false),
       memo: val.memo.unwrap_or(false),
@@ -205,6 +506,33 @@ impl TryFrom<JsConfig> for Config {
       jsx_runtime_import,
       named_export,
       export_type,
+      plugins,
+      template: val
+        .template
+        .map(|source| Arc::new(StringTemplate::new(source)) as _),
+      svgo: val.svgo.unwrap_or(false),
+      svgo_config: val.svgo_config.map(|c| c.into()).unwrap_or_default(),
+      prettier: val.prettier.unwrap_or(false),
+      prettier_config: val.prettier_config.map(|c| c.into()).unwrap_or_default(),
+      runtime_config: val.runtime_config.unwrap_or(true),
+      config_file: val.config_file,
+      explicit_options,
+      index: val.index.unwrap_or(false),
+      index_template: None,
+      ast: false,
+      source_maps: val.source_maps.unwrap_or(false),
+      strict: val.strict.unwrap_or(false),
+      preserve_comments: val.preserve_comments.unwrap_or(false),
+      inline_styles: val.inline_styles.unwrap_or(false),
+      css_modules: val.css_modules.unwrap_or(false),
+      target,
+      element_name: val.element_name,
+      base_class: val.base_class.map(|raw| BaseClass {
+        name: raw.name,
+        source: raw.source,
+      }),
+      unique_ids,
+      fallback_ids,
     })
   }
 }
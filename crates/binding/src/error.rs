@@ -0,0 +1,55 @@
+use napi::Env;
+use svgr_rs::SvgrError;
+
+use crate::output::JsLocation;
+
+/// An error of a transformation, with the location of the offending SVG code when known.
+#[napi(object, object_from_js = false)]
+pub struct JsTransformError {
+  pub message: String,
+  /// A stable identifier of the kind of error, for example `non-svg-root`.
+  pub code: String,
+  pub file_path: Option<String>,
+  pub loc: Option<JsLocation>,
+  /// The offending line of the SVG with a caret under the location.
+  pub snippet: Option<String>,
+}
+
+impl From<&SvgrError> for JsTransformError {
+  fn from(val: &SvgrError) -> Self {
+    let span = val.span();
+    Self {
+      message: val.to_string(),
+      code: val.code().to_string(),
+      file_path: val.file_path().map(|file_path| file_path.to_string()),
+      loc: span.map(|span| span.loc.into()),
+      snippet: span.map(|span| span.snippet.clone()),
+    }
+  }
+}
+
+/// Create a JavaScript `Error` with the `code`, `filePath`, `loc` and `snippet` properties of the error.
+pub fn to_napi_error(env: Env, error: &SvgrError) -> napi::Error {
+  let error = JsTransformError::from(error);
+  let create = || -> napi::Result<napi::Error> {
+    let mut js_error = env.create_error(napi::Error::from_reason(error.message.clone()))?;
+    js_error.set_named_property("code", error.code.clone())?;
+    if let Some(file_path) = &error.file_path {
+      js_error.set_named_property("filePath", file_path.clone())?;
+    }
+    if let Some(loc) = &error.loc {
+      js_error.set_named_property(
+        "loc",
+        JsLocation {
+          line: loc.line,
+          column: loc.column,
+        },
+      )?;
+    }
+    if let Some(snippet) = &error.snippet {
+      js_error.set_named_property("snippet", snippet.clone())?;
+    }
+    Ok(napi::Error::from(js_error.into_unknown()))
+  };
+  create().unwrap_or_else(|_| napi::Error::from_reason(error.message.clone()))
+}
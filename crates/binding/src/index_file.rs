@@ -0,0 +1,18 @@
+use svgr_rs::IndexFile;
+
+#[napi(object, object_to_js = false)]
+#[derive(Clone)]
+pub struct JsIndexFile {
+  /// The path of the generated file, relative to the directory of the index file.
+  pub path: String,
+  pub component_name: String,
+}
+
+impl From<JsIndexFile> for IndexFile {
+  fn from(val: JsIndexFile) -> Self {
+    Self {
+      path: val.path,
+      component_name: val.component_name,
+    }
+  }
+}
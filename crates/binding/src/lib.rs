@@ -5,22 +5,32 @@
 extern crate napi_derive;
 
 mod config;
+mod error;
+mod index_file;
+mod output;
 mod state;
 
 use config::JsConfig;
-use napi::{bindgen_prelude::AsyncTask, Env, JsString, Result, Task};
+use error::{to_napi_error, JsTransformError};
+use index_file::JsIndexFile;
+use napi::{bindgen_prelude::AsyncTask, Env, Result, Task};
+use output::JsTransformOutput;
 use state::JsState;
-use svgr_rs::{transform, Config};
+use svgr_rs::{
+  create_index, transform, transform_many, transform_sprite, Config, IndexFile, SvgrError,
+  TransformOutput,
+};
 
 pub struct TransformTask {
   code: Option<String>,
   config: Option<JsConfig>,
   state: Option<JsState>,
+  error: Option<SvgrError>,
 }
 
 impl Task for TransformTask {
-  type Output = String;
-  type JsValue = JsString;
+  type Output = JsTransformOutput;
+  type JsValue = JsTransformOutput;
 
   fn compute(&mut self) -> Result<Self::Output> {
     let config: Config = match self.config.take() {
@@ -30,13 +40,24 @@ impl Task for TransformTask {
     let state = self.state.take().map(|s| s.into()).unwrap_or_default();
     let code = self.code.take().unwrap();
     match transform(code, config, state) {
-      Ok(result) => napi::Result::Ok(result),
-      Err(reason) => napi::Result::Err(napi::Error::from_reason(reason.to_string())),
+      Ok(result) => napi::Result::Ok(result.into()),
+      Err(error) => {
+        let reason = error.to_string();
+        self.error = Some(error);
+        napi::Result::Err(napi::Error::from_reason(reason))
+      }
     }
   }
 
-  fn resolve(&mut self, env: Env, output: Self::Output) -> Result<Self::JsValue> {
-    env.create_string(&output)
+  fn resolve(&mut self, _env: Env, output: Self::Output) -> Result<Self::JsValue> {
+    Ok(output)
+  }
+
+  fn reject(&mut self, env: Env, err: napi::Error) -> Result<Self::JsValue> {
+    match self.error.take() {
+      Some(error) => Err(to_napi_error(env, &error)),
+      None => Err(err),
+    }
   }
 }
 
@@ -50,5 +71,159 @@ pub fn transform_node(
     code: Some(code),
     config,
     state,
+    error: None,
+  })
+}
+
+#[napi(object, object_to_js = false)]
+pub struct JsTransformInput {
+  pub code: String,
+  pub state: Option<JsState>,
+}
+
+/// The result of a file of a batch, either the output of the transformation or its error.
+#[napi(object, object_from_js = false)]
+pub struct JsTransformResult {
+  pub output: Option<JsTransformOutput>,
+  pub error: Option<JsTransformError>,
+}
+
+fn to_js_result(result: std::result::Result<TransformOutput, SvgrError>) -> JsTransformResult {
+  match result {
+    Ok(output) => JsTransformResult {
+      output: Some(output.into()),
+      error: None,
+    },
+    Err(error) => JsTransformResult {
+      output: None,
+      error: Some((&error).into()),
+    },
+  }
+}
+
+fn to_inputs(inputs: Vec<JsTransformInput>) -> Vec<(String, svgr_rs::State)> {
+  inputs
+    .into_iter()
+    .map(|input| {
+      (
+        input.code,
+        input.state.map(|s| s.into()).unwrap_or_default(),
+      )
+    })
+    .collect()
+}
+
+pub struct TransformBatchTask {
+  inputs: Vec<JsTransformInput>,
+  config: Option<JsConfig>,
+}
+
+impl Task for TransformBatchTask {
+  type Output = Vec<JsTransformResult>;
+  type JsValue = Vec<JsTransformResult>;
+
+  fn compute(&mut self) -> Result<Self::Output> {
+    let config: Config = match self.config.take() {
+      Some(val) => val.try_into()?,
+      None => Config::default(),
+    };
+    let inputs = to_inputs(std::mem::take(&mut self.inputs));
+    let results = transform_many(inputs, &config)
+      .into_iter()
+      .map(to_js_result)
+      .collect();
+    Ok(results)
+  }
+
+  fn resolve(&mut self, _env: Env, output: Self::Output) -> Result<Self::JsValue> {
+    Ok(output)
+  }
+}
+
+/// Transform many SVG in parallel with the same config, the results are in the order of the inputs.
+#[napi(js_name = "transformBatch")]
+pub fn transform_batch_node(
+  inputs: Vec<JsTransformInput>,
+  config: Option<JsConfig>,
+) -> AsyncTask<TransformBatchTask> {
+  AsyncTask::new(TransformBatchTask { inputs, config })
+}
+
+/// The sprite sheet and the components rendering its symbols.
+#[napi(object, object_from_js = false)]
+pub struct JsSpriteOutput {
+  /// The sprite sheet, an SVG with a `<symbol>` for each SVG transformed successfully.
+  pub sprite: String,
+  /// The components rendering the symbols with `<use>`, in the order of the inputs.
+  pub results: Vec<JsTransformResult>,
+}
+
+pub struct TransformSpriteTask {
+  inputs: Vec<JsTransformInput>,
+  href: String,
+  config: Option<JsConfig>,
+}
+
+impl Task for TransformSpriteTask {
+  type Output = JsSpriteOutput;
+  type JsValue = JsSpriteOutput;
+
+  fn compute(&mut self) -> Result<Self::Output> {
+    let config: Config = match self.config.take() {
+      Some(val) => val.try_into()?,
+      None => Config::default(),
+    };
+    let inputs = to_inputs(std::mem::take(&mut self.inputs));
+    let output = transform_sprite(inputs, &config, &self.href);
+    Ok(JsSpriteOutput {
+      sprite: output.sprite,
+      results: output.components.into_iter().map(to_js_result).collect(),
+    })
+  }
+
+  fn resolve(&mut self, _env: Env, output: Self::Output) -> Result<Self::JsValue> {
+    Ok(output)
+  }
+}
+
+/// Merge many SVG into a sprite sheet served from `href`, and transform each of them into a
+/// component rendering its symbol with `<use>`.
+#[napi(js_name = "transformSprite")]
+pub fn transform_sprite_node(
+  inputs: Vec<JsTransformInput>,
+  href: String,
+  config: Option<JsConfig>,
+) -> AsyncTask<TransformSpriteTask> {
+  AsyncTask::new(TransformSpriteTask {
+    inputs,
+    href,
+    config,
   })
 }
+
+/// Find the nearest runtime config file from the given file or directory and return its options.
+#[napi(js_name = "resolveConfig")]
+pub fn resolve_config_node(env: Env, path: String) -> Result<Option<serde_json::Value>> {
+  match svgr_rs::resolve_config(path) {
+    Ok(Some(runtime_config)) => serde_json::to_value(runtime_config)
+      .map(Some)
+      .map_err(|e| napi::Error::from_reason(e.to_string())),
+    Ok(None) => Ok(None),
+    Err(error) => Err(to_napi_error(env, &error)),
+  }
+}
+
+/// Create the code of an index file re-exporting the given generated components.
+#[napi(js_name = "createIndex")]
+pub fn create_index_node(
+  env: Env,
+  files: Vec<JsIndexFile>,
+  config: Option<JsConfig>,
+) -> Result<String> {
+  let config: Config = match config {
+    Some(val) => val.try_into()?,
+    None => Config::default(),
+  };
+  let files: Vec<IndexFile> = files.into_iter().map(|f| f.into()).collect();
+  create_index(&files, &config).map_err(|error| to_napi_error(env, &error))
+}
@@ -0,0 +1,82 @@
+use svgr_rs::{Location, OutputFile, TransformOutput, Warning};
+
+/// A position in the SVG source code, the line and the column start at 1.
+#[napi(object, object_from_js = false)]
+pub struct JsLocation {
+  pub line: u32,
+  pub column: u32,
+}
+
+impl From<Location> for JsLocation {
+  fn from(val: Location) -> Self {
+    Self {
+      line: val.line as u32,
+      column: val.column as u32,
+    }
+  }
+}
+
+#[napi(object, object_from_js = false)]
+pub struct JsWarning {
+  /// A stable identifier of the kind of warning, for example `react-native-unsupported-element`.
+  pub code: String,
+  pub message: String,
+  pub loc: Option<JsLocation>,
+}
+
+impl From<Warning> for JsWarning {
+  fn from(val: Warning) -> Self {
+    Self {
+      code: val.code,
+      message: val.message,
+      loc: val.loc.map(|loc| loc.into()),
+    }
+  }
+}
+
+#[napi(object, object_from_js = false)]
+pub struct JsOutputFile {
+  /// The path of the file, relative to the directory of the component.
+  pub path: String,
+  pub code: String,
+}
+
+impl From<OutputFile> for JsOutputFile {
+  fn from(val: OutputFile) -> Self {
+    Self {
+      path: val.path,
+      code: val.code,
+    }
+  }
+}
+
+#[napi(object, object_from_js = false)]
+pub struct JsTransformOutput {
+  /// The generated code of the component.
+  pub code: String,
+  /// The v3 source map of the generated code as JSON, only generated when `sourceMaps` is enabled.
+  pub map: Option<String>,
+  /// The name of the component, either given in the state or derived from the file path.
+  pub component_name: String,
+  /// The names exported by the generated module, `default` for a default export.
+  pub exports: Vec<String>,
+  /// The sources imported by the generated module, in order.
+  pub imports: Vec<String>,
+  pub warnings: Vec<JsWarning>,
+  /// The files generated with the component, like the CSS module of `cssModules`.
+  pub files: Vec<JsOutputFile>,
+}
+
+impl From<TransformOutput> for JsTransformOutput {
+  fn from(val: TransformOutput) -> Self {
+    Self {
+      code: val.code,
+      map: val.map,
+      component_name: val.component_name,
+      exports: val.exports,
+      imports: val.imports,
+      warnings: val.warnings.into_iter().map(|w| w.into()).collect(),
+      files: val.files.into_iter().map(|f| f.into()).collect(),
+    }
+  }
+}
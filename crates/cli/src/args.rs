@@ -0,0 +1,474 @@
+use std::{collections::HashMap, fs, path::PathBuf};
+
+use anyhow::{bail, Context, Result};
+use clap::Parser;
+use serde::de::DeserializeOwned;
+use svgr_rs::{
+  set_options, Config, RuntimeBaseClass, RuntimeConfig, RuntimeExpandProps, RuntimeIcon, Target,
+};
+
+use crate::filename::FilenameCase;
+
+/// Transform SVG files into React components.
+#[derive(Debug, Parser)]
+#[command(name = "svgr-rs", version, about)]
+pub struct Args {
+  /// The SVG files, directories or glob patterns to transform, reads from stdin when omitted or `-`.
+  pub inputs: Vec<String>,
+
+  /// Output the components into this directory, a single file is written to stdout otherwise.
+  #[arg(short = 'd', long)]
+  pub out_dir: Option<PathBuf>,
+
+  /// The extension of the generated files, defaults to `tsx` with `--typescript` and `js` otherwise.
+  #[arg(long)]
+  pub ext: Option<String>,
+
+  /// The case of the generated file names.
+  #[arg(long, value_enum, default_value_t)]
+  pub filename_case: FilenameCase,
+
+  /// Do not overwrite the existing files in the output directory.
+  #[arg(long)]
+  pub ignore_existing: bool,
+
+  /// The path of the SVG read from stdin, used to name the component and find the runtime config file.
+  #[arg(long)]
+  pub stdin_filepath: Option<String>,
+
+  /// Generate an index file re-exporting the components in each output directory.
+  #[arg(long)]
+  pub index: bool,
+
+  /// Write a source map next to each generated file, as `<file>.map`.
+  #[arg(long)]
+  pub source_maps: bool,
+
+  /// Do not log the transformed files.
+  #[arg(long)]
+  pub silent: bool,
+
+  /// Forward ref to the root SVG tag.
+  #[arg(long = "ref")]
+  pub r#ref: bool,
+
+  /// Add title tag via title property.
+  #[arg(long)]
+  pub title_prop: bool,
+
+  /// Add desc tag via desc property.
+  #[arg(long)]
+  pub desc_prop: bool,
+
+  /// Forward the properties given to the component on the SVG tag.
+  #[arg(long, value_parser = ["start", "end", "none"])]
+  pub expand_props: Option<String>,
+
+  /// Remove `width` and `height` from the root SVG tag.
+  #[arg(long)]
+  pub no_dimensions: bool,
+
+  /// Replace SVG `width` and `height` by a custom value, `1em` when omitted.
+  #[arg(long, value_name = "SIZE", num_args = 0..=1)]
+  pub icon: Option<Option<String>>,
+
+  /// Use a specific template with `react-native-svg` imports.
+  #[arg(long)]
+  pub native: bool,
+
+  /// What happens to the elements not supported by react-native-svg.
+  #[arg(long, value_parser = ["drop", "keep", "error"], requires = "native")]
+  pub native_unsupported: Option<String>,
+
+  /// Map extra elements to react-native-svg components.
+  #[arg(
+    long,
+    value_name = "ELEMENT=COMPONENT",
+    value_delimiter = ',',
+    requires = "native"
+  )]
+  pub native_components: Vec<String>,
+
+  /// Import the react-native-svg components from another source.
+  #[arg(long, requires = "native")]
+  pub native_import_source: Option<String>,
+
+  /// Drop, keep or fail on specific elements with react-native-svg.
+  #[arg(
+    long,
+    value_name = "ELEMENT=POLICY",
+    value_delimiter = ',',
+    requires = "native"
+  )]
+  pub native_elements: Vec<String>,
+
+  /// Add props to the root SVG tag.
+  #[arg(long, value_name = "PROPERTY=VALUE", value_delimiter = ',')]
+  pub svg_props: Vec<String>,
+
+  /// Generate `.tsx` files with TypeScript typings.
+  #[arg(long)]
+  pub typescript: bool,
+
+  /// Wrap the exported component in `React.memo`.
+  #[arg(long)]
+  pub memo: bool,
+
+  /// Replace an attribute value by an other.
+  #[arg(long, value_name = "OLD=NEW", value_delimiter = ',')]
+  pub replace_attr_values: Vec<String>,
+
+  /// Specify a JSX runtime to use.
+  #[arg(long, value_parser = ["classic", "classic-preact", "automatic"])]
+  pub jsx_runtime: Option<String>,
+
+  /// Specify a custom JSX runtime import, as JSON or the path of a JSON file.
+  #[arg(long, value_name = "JSON_OR_FILE")]
+  pub jsx_runtime_import: Option<String>,
+
+  /// Specify the named export.
+  #[arg(long)]
+  pub named_export: Option<String>,
+
+  /// Specify the export type.
+  #[arg(long, value_parser = ["named", "default"])]
+  pub export_type: Option<String>,
+
+  /// The names of the built-in plugins to apply, in order.
+  #[arg(long, value_delimiter = ',')]
+  pub plugins: Option<Vec<String>>,
+
+  /// The path of a template file.
+  #[arg(long, value_name = "FILE")]
+  pub template: Option<PathBuf>,
+
+  /// Optimize the SVG before transforming it.
+  #[arg(long)]
+  pub svgo: bool,
+
+  /// The SVGO plugins to apply, as JSON or the path of a JSON file.
+  #[arg(long, value_name = "JSON_OR_FILE")]
+  pub svgo_config: Option<String>,
+
+  /// Format the generated code with the built-in pretty printer.
+  #[arg(long)]
+  pub prettier: bool,
+
+  /// The options of the pretty printer, as JSON or the path of a JSON file.
+  #[arg(long, value_name = "JSON_OR_FILE")]
+  pub prettier_config: Option<String>,
+
+  /// Fail when the XML parser recovered from errors instead of reporting them as warnings.
+  #[arg(long)]
+  pub strict: bool,
+
+  /// Keep the comments of the SVG, like license attributions.
+  #[arg(long)]
+  pub preserve_comments: bool,
+
+  /// Inline the CSS rules of the `<style>` elements into the elements.
+  #[arg(long)]
+  pub inline_styles: bool,
+
+  /// Extract the CSS rules of the `<style>` elements into a CSS module next to the component.
+  #[arg(long)]
+  pub css_modules: bool,
+
+  /// The framework the components are generated for.
+  #[arg(long, value_parser = ["react", "vue", "vue-sfc", "solid", "svelte", "lit", "web-component"])]
+  pub target: Option<String>,
+
+  /// The name the custom elements are registered under, defaults to the kebab-case component name.
+  #[arg(long)]
+  pub element_name: Option<String>,
+
+  /// The class extended by the custom elements.
+  #[arg(long)]
+  pub base_class: Option<String>,
+
+  /// The module the base class is imported from.
+  #[arg(long, requires = "base_class")]
+  pub base_class_source: Option<String>,
+
+  /// Rewrite the ids of the SVG so they do not collide, with a prefix derived from the file path
+  /// or with an id generated at runtime.
+  #[arg(long, value_parser = ["none", "prefix", "use-id"])]
+  pub unique_ids: Option<String>,
+
+  /// Generate a default `titleId` and `descId` in the component when they are not given.
+  #[arg(long, value_parser = ["none", "use-id", "counter"])]
+  pub fallback_ids: Option<String>,
+
+  /// Do not load the runtime config files.
+  #[arg(long)]
+  pub no_runtime_config: bool,
+
+  /// Use this config file instead of searching for one.
+  #[arg(long, value_name = "FILE")]
+  pub config_file: Option<String>,
+}
+
+fn flag(value: bool) -> Option<bool> {
+  if value {
+    Some(true)
+  } else {
+    None
+  }
+}
+
+fn parse_key_values(option: &str, values: &[String]) -> Result<Option<HashMap<String, String>>> {
+  if values.is_empty() {
+    return Ok(None);
+  }
+  values
+    .iter()
+    .map(|value| match value.split_once('=') {
+      Some((key, value)) => Ok((key.to_string(), value.to_string())),
+      None => bail!(
+        "invalid --{} value \"{}\", expected KEY=VALUE",
+        option,
+        value
+      ),
+    })
+    .collect::<Result<_>>()
+    .map(Some)
+}
+
+fn parse_json_or_file<T: DeserializeOwned>(option: &str, value: &str) -> Result<T> {
+  let json = if value.trim_start().starts_with('{') {
+    value.to_string()
+  } else {
+    fs::read_to_string(value).with_context(|| format!("failed to read --{} file", option))?
+  };
+  serde_json::from_str(&json).with_context(|| format!("invalid --{} value", option))
+}
+
+impl Args {
+  /// The options set on the command line, in the format of the runtime config files.
+  pub fn to_runtime_config(&self) -> Result<RuntimeConfig> {
+    let template = match &self.template {
+      Some(path) => Some(
+        fs::read_to_string(path)
+          .with_context(|| format!("failed to read template \"{}\"", path.display()))?,
+      ),
+      None => None,
+    };
+
+    Ok(RuntimeConfig {
+      r#ref: flag(self.r#ref),
+      title_prop: flag(self.title_prop),
+      desc_prop: flag(self.desc_prop),
+      expand_props: self
+        .expand_props
+        .as_ref()
+        .map(|value| match value.as_str() {
+          "none" => RuntimeExpandProps::Bool(false),
+          value => RuntimeExpandProps::Str(value.to_string()),
+        }),
+      dimensions: if self.no_dimensions {
+        Some(false)
+      } else {
+        None
+      },
+      icon: self.icon.as_ref().map(|size| match size {
+        None => RuntimeIcon::Bool(true),
+        Some(size) => match size.parse::<f64>() {
+          Ok(num) => RuntimeIcon::Num(num),
+          Err(_) => RuntimeIcon::Str(size.clone()),
+        },
+      }),
+      native: flag(self.native),
+      native_unsupported: self.native_unsupported.clone(),
+      native_components: parse_key_values("native-components", &self.native_components)?,
+      native_import_source: self.native_import_source.clone(),
+      native_elements: parse_key_values("native-elements", &self.native_elements)?,
+      svg_props: parse_key_values("svg-props", &self.svg_props)?,
+      typescript: flag(self.typescript),
+      memo: flag(self.memo),
+      replace_attr_values: parse_key_values("replace-attr-values", &self.replace_attr_values)?,
+      jsx_runtime: self.jsx_runtime.clone(),
+      jsx_runtime_import: self
+        .jsx_runtime_import
+        .as_ref()
+        .map(|value| parse_json_or_file("jsx-runtime-import", value))
+        .transpose()?,
+      named_export: self.named_export.clone(),
+      export_type: self.export_type.clone(),
+      svgo: flag(self.svgo),
+      svgo_config: self
+        .svgo_config
+        .as_ref()
+        .map(|value| parse_json_or_file("svgo-config", value))
+        .transpose()?,
+      prettier: flag(self.prettier),
+      prettier_config: self
+        .prettier_config
+        .as_ref()
+        .map(|value| parse_json_or_file("prettier-config", value))
+        .transpose()?,
+      template,
+      plugins: self.plugins.clone(),
+      index: flag(self.index),
+      strict: flag(self.strict),
+      preserve_comments: flag(self.preserve_comments),
+      inline_styles: flag(self.inline_styles),
+      css_modules: flag(self.css_modules),
+      target: self.target.clone(),
+      element_name: self.element_name.clone(),
+      base_class: self.base_class.clone().map(|name| RuntimeBaseClass {
+        name,
+        source: self.base_class_source.clone(),
+      }),
+      unique_ids: self.unique_ids.clone(),
+      fallback_ids: self.fallback_ids.clone(),
+    })
+  }
+
+  pub fn to_config(&self) -> Result<Config> {
+    let config = set_options(Config::default(), self.to_runtime_config()?)?;
+    Ok(Config {
+      source_maps: self.source_maps,
+      runtime_config: !self.no_runtime_config,
+      config_file: self.config_file.clone(),
+      ..config
+    })
+  }
+
+  /// The extension of the generated files.
+  pub fn ext(&self, config: &Config) -> String {
+    match (&self.ext, &config.target) {
+      (Some(ext), _) => ext.trim_start_matches('.').to_string(),
+      (None, Target::VueSfc) => "vue".to_string(),
+      (None, Target::Svelte) => "svelte".to_string(),
+      // The render functions and the custom elements do not contain JSX.
+      (None, Target::Vue | Target::Lit | Target::WebComponent) if config.typescript => {
+        "ts".to_string()
+      }
+      (None, Target::Vue | Target::Lit | Target::WebComponent) => "js".to_string(),
+      (None, _) if config.typescript => "tsx".to_string(),
+      (None, _) => "js".to_string(),
+    }
+  }
+
+  /// The extension of the index files, which do not contain JSX.
+  pub fn index_ext(&self, config: &Config) -> String {
+    match self.ext(config).as_str() {
+      "tsx" => "ts".to_string(),
+      "jsx" => "js".to_string(),
+      "vue" | "svelte" if config.typescript => "ts".to_string(),
+      "vue" | "svelte" => "js".to_string(),
+      ext => ext.to_string(),
+    }
+  }
+}
+
+#[cfg(test)]
+mod tests {
+  use svgr_rs::{ExpandProps, Icon, JSXRuntime};
+
+  use super::*;
+
+  fn parse(args: &[&str]) -> Args {
+    Args::try_parse_from(std::iter::once("svgr-rs").chain(args.iter().copied())).unwrap()
+  }
+
+  #[test]
+  fn converts_flags_to_config() {
+    let args = parse(&[
+      "--ref",
+      "--icon",
+      "--expand-props",
+      "start",
+      "--svg-props",
+      "role=img,focusable=false",
+      "--jsx-runtime",
+      "automatic",
+      "--plugins",
+      "add-jsx-attribute,svg-em-dimensions",
+      "--prettier-config",
+      r#"{ "semi": false }"#,
+      "--no-runtime-config",
+      "icons",
+    ]);
+    let config = args.to_config().unwrap();
+
+    assert!(config.r#ref);
+    assert_eq!(config.icon, Some(Icon::Bool(true)));
+    assert_eq!(config.expand_props, ExpandProps::Start);
+    assert_eq!(
+      config
+        .svg_props
+        .iter()
+        .map(|prop| format!("{}={}", prop.key, prop.value))
+        .collect::<Vec<String>>(),
+      vec!["focusable=false", "role=img"]
+    );
+    assert_eq!(config.jsx_runtime, JSXRuntime::Automatic);
+    assert_eq!(
+      config
+        .plugins
+        .iter()
+        .map(|plugin| plugin.name())
+        .collect::<Vec<&str>>(),
+      vec!["add-jsx-attribute", "svg-em-dimensions"]
+    );
+    assert!(!config.prettier_config.semi);
+    assert!(!config.runtime_config);
+    assert_eq!(args.inputs, vec!["icons"]);
+  }
+
+  #[test]
+  fn parses_icon_size() {
+    let config = parse(&["--icon", "24"]).to_config().unwrap();
+    assert_eq!(config.icon, Some(Icon::Num(24.0)));
+
+    let config = parse(&["--icon", "2rem"]).to_config().unwrap();
+    assert_eq!(config.icon, Some(Icon::Str("2rem".to_string())));
+  }
+
+  #[test]
+  fn defaults_extension_to_tsx_with_typescript() {
+    let args = parse(&["--typescript"]);
+    assert_eq!(args.ext(&args.to_config().unwrap()), "tsx");
+
+    let args = parse(&["--typescript", "--ext", ".ts"]);
+    assert_eq!(args.ext(&args.to_config().unwrap()), "ts");
+  }
+
+  #[test]
+  fn defaults_extension_to_the_target() {
+    let args = parse(&["--target", "vue-sfc", "--typescript"]);
+    let config = args.to_config().unwrap();
+    assert_eq!(args.ext(&config), "vue");
+    assert_eq!(args.index_ext(&config), "ts");
+
+    let args = parse(&["--target", "vue"]);
+    assert_eq!(args.ext(&args.to_config().unwrap()), "js");
+
+    let args = parse(&["--target", "svelte"]);
+    let config = args.to_config().unwrap();
+    assert_eq!(args.ext(&config), "svelte");
+    assert_eq!(args.index_ext(&config), "js");
+
+    let args = parse(&["--target", "solid", "--typescript"]);
+    assert_eq!(args.ext(&args.to_config().unwrap()), "tsx");
+  }
+
+  #[test]
+  fn defaults_index_extension_to_ts_with_typescript() {
+    let args = parse(&["--typescript"]);
+    assert_eq!(args.index_ext(&args.to_config().unwrap()), "ts");
+
+    let args = parse(&["--ext", "jsx"]);
+    assert_eq!(args.index_ext(&args.to_config().unwrap()), "js");
+  }
+
+  #[test]
+  fn fails_with_invalid_key_value() {
+    let err = parse(&["--svg-props", "role"]).to_config().unwrap_err();
+    assert_eq!(
+      err.to_string(),
+      r#"invalid --svg-props value "role", expected KEY=VALUE"#
+    );
+  }
+}
@@ -0,0 +1,95 @@
+use clap::ValueEnum;
+
+/// The case of the generated file names.
+#[derive(Debug, Clone, Copy, Default, PartialEq, ValueEnum)]
+pub enum FilenameCase {
+  Camel,
+  Kebab,
+  #[default]
+  Pascal,
+  Snake,
+}
+
+/// Split a file name into lowercase words, on separators and case changes.
+fn split_words(name: &str) -> Vec<String> {
+  let mut words = vec![];
+  let mut word = String::new();
+  let mut previous: Option<char> = None;
+
+  for c in name.chars() {
+    if !c.is_alphanumeric() {
+      if !word.is_empty() {
+        words.push(std::mem::take(&mut word));
+      }
+      previous = None;
+      continue;
+    }
+    if let Some(p) = previous {
+      if p.is_lowercase() && c.is_uppercase() && !word.is_empty() {
+        words.push(std::mem::take(&mut word));
+      }
+    }
+    word.extend(c.to_lowercase());
+    previous = Some(c);
+  }
+  if !word.is_empty() {
+    words.push(word);
+  }
+
+  words
+}
+
+fn capitalize(word: &str) -> String {
+  let mut chars = word.chars();
+  match chars.next() {
+    Some(first) => first.to_uppercase().chain(chars).collect(),
+    None => String::new(),
+  }
+}
+
+/// Format a file name, without extension, in the given case.
+pub fn format_filename(name: &str, case: FilenameCase) -> String {
+  let words = split_words(name);
+  match case {
+    FilenameCase::Camel => words
+      .iter()
+      .enumerate()
+      .map(|(i, word)| {
+        if i == 0 {
+          word.clone()
+        } else {
+          capitalize(word)
+        }
+      })
+      .collect(),
+    FilenameCase::Kebab => words.join("-"),
+    FilenameCase::Pascal => words.iter().map(|word| capitalize(word)).collect(),
+    FilenameCase::Snake => words.join("_"),
+  }
+}
+
+#[cfg(test)]
+mod tests {
+  use super::*;
+
+  #[test]
+  fn formats_filename_in_each_case() {
+    let name = "arrow-left_iconSmall 2";
+    assert_eq!(
+      format_filename(name, FilenameCase::Camel),
+      "arrowLeftIconSmall2"
+    );
+    assert_eq!(
+      format_filename(name, FilenameCase::Kebab),
+      "arrow-left-icon-small-2"
+    );
+    assert_eq!(
+      format_filename(name, FilenameCase::Pascal),
+      "ArrowLeftIconSmall2"
+    );
+    assert_eq!(
+      format_filename(name, FilenameCase::Snake),
+      "arrow_left_icon_small_2"
+    );
+  }
+}
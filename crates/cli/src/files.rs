@@ -0,0 +1,173 @@
+use std::path::{Path, PathBuf};
+
+use anyhow::{bail, Context, Result};
+use walkdir::WalkDir;
+
+use crate::filename::{format_filename, FilenameCase};
+
+/// An SVG file to transform.
+#[derive(Debug, PartialEq)]
+pub struct Input {
+  pub path: PathBuf,
+
+  /// The path of the file relative to the input directory, or its file name.
+  pub relative_path: PathBuf,
+
+  /// Whether the file has been found in an input directory.
+  pub from_dir: bool,
+}
+
+fn is_svg(path: &Path) -> bool {
+  path
+    .extension()
+    .and_then(|ext| ext.to_str())
+    .map_or(false, |ext| ext.eq_ignore_ascii_case("svg"))
+}
+
+fn file_input(path: PathBuf) -> Input {
+  let relative_path = PathBuf::from(path.file_name().unwrap_or_default());
+  Input {
+    path,
+    relative_path,
+    from_dir: false,
+  }
+}
+
+/// Collect the SVG files of the files, directories and glob patterns given on the command line.
+pub fn collect_inputs(patterns: &[String]) -> Result<Vec<Input>> {
+  let mut inputs = vec![];
+
+  for pattern in patterns {
+    let path = Path::new(pattern);
+
+    if path.is_dir() {
+      let mut files = vec![];
+      for entry in WalkDir::new(path) {
+        let entry = entry.with_context(|| format!("failed to read directory \"{}\"", pattern))?;
+        if entry.file_type().is_file() && is_svg(entry.path()) {
+          files.push(entry.into_path());
+        }
+      }
+      files.sort();
+      inputs.extend(files.into_iter().map(|file| Input {
+        relative_path: file.strip_prefix(path).unwrap_or(&file).to_path_buf(),
+        path: file,
+        from_dir: true,
+      }));
+    } else if path.is_file() {
+      inputs.push(file_input(path.to_path_buf()));
+    } else {
+      let paths =
+        glob::glob(pattern).with_context(|| format!("invalid pattern \"{}\"", pattern))?;
+      let mut files = vec![];
+      for path in paths {
+        let path = path.with_context(|| format!("failed to read \"{}\"", pattern))?;
+        if path.is_file() {
+          files.push(path);
+        }
+      }
+      if files.is_empty() {
+        bail!("no such file or directory \"{}\"", pattern);
+      }
+      files.sort();
+      inputs.extend(files.into_iter().map(file_input));
+    }
+  }
+
+  Ok(inputs)
+}
+
+/// The path of the generated file of an input in the output directory.
+pub fn get_output_path(input: &Input, out_dir: &Path, ext: &str, case: FilenameCase) -> PathBuf {
+  let stem = input
+    .relative_path
+    .file_stem()
+    .and_then(|stem| stem.to_str())
+    .unwrap_or_default();
+  let file_name = format!("{}.{}", format_filename(stem, case), ext);
+  match input.relative_path.parent() {
+    Some(parent) => out_dir.join(parent).join(file_name),
+    None => out_dir.join(file_name),
+  }
+}
+
+#[cfg(test)]
+mod tests {
+  use std::fs;
+
+  use super::*;
+
+  fn create_dir(name: &str, files: &[&str]) -> PathBuf {
+    let dir = std::env::temp_dir().join(format!("svgr-rs-cli-files-{}", name));
+    let _ = fs::remove_dir_all(&dir);
+    for file in files {
+      let path = dir.join(file);
+      fs::create_dir_all(path.parent().unwrap()).unwrap();
+      fs::write(path, "<svg/>").unwrap();
+    }
+    dir
+  }
+
+  #[test]
+  fn collects_files_directories_and_globs() {
+    let dir = create_dir(
+      "collect",
+      &[
+        "icons/star.svg",
+        "icons/nested/arrow-left.svg",
+        "icons/readme.md",
+        "logo.svg",
+        "other/a.svg",
+        "other/b.svg",
+      ],
+    );
+    let patterns = vec![
+      dir.join("icons").to_string_lossy().to_string(),
+      dir.join("logo.svg").to_string_lossy().to_string(),
+      dir.join("other/*.svg").to_string_lossy().to_string(),
+    ];
+
+    let inputs = collect_inputs(&patterns).unwrap();
+    let relative_paths: Vec<PathBuf> = inputs
+      .iter()
+      .map(|input| input.relative_path.clone())
+      .collect();
+    assert_eq!(
+      relative_paths,
+      vec![
+        PathBuf::from("nested/arrow-left.svg"),
+        PathBuf::from("star.svg"),
+        PathBuf::from("logo.svg"),
+        PathBuf::from("a.svg"),
+        PathBuf::from("b.svg"),
+      ]
+    );
+    assert!(inputs[0].from_dir);
+    assert!(!inputs[2].from_dir);
+  }
+
+  #[test]
+  fn fails_when_nothing_matches() {
+    let dir = create_dir("missing", &[]);
+    let pattern = dir.join("*.svg").to_string_lossy().to_string();
+
+    let err = collect_inputs(&[pattern.clone()]).unwrap_err();
+    assert_eq!(
+      err.to_string(),
+      format!("no such file or directory \"{}\"", pattern)
+    );
+  }
+
+  #[test]
+  fn keeps_relative_directories_in_output_path() {
+    let input = Input {
+      path: PathBuf::from("icons/nested/arrow-left.svg"),
+      relative_path: PathBuf::from("nested/arrow-left.svg"),
+      from_dir: true,
+    };
+    assert_eq!(
+      get_output_path(&input, Path::new("dist"), "js", FilenameCase::Pascal),
+      PathBuf::from("dist/nested/ArrowLeft.js")
+    );
+  }
+}
@@ -0,0 +1,435 @@
+#![deny(clippy::all)]
+
+use std::{
+  collections::{BTreeMap, HashMap},
+  fs,
+  io::{self, Read, Write},
+  path::{Path, PathBuf},
+  process,
+};
+
+use anyhow::{bail, Context, Result};
+use clap::Parser;
+use svgr_rs::{create_index, expand_state, transform, Config, IndexFile, State, TransformOutput};
+
+mod args;
+mod filename;
+mod files;
+
+use self::args::Args;
+use self::files::{collect_inputs, get_output_path, Input};
+
+/// The state of a file, the component name is derived from its path.
+/// No caller is set, it would make the component a named export like in bundlers.
+fn get_state(file_path: Option<String>) -> Result<State> {
+  let state = expand_state(&State {
+    file_path,
+    component_name: None,
+    caller: None,
+  })?;
+  Ok(State {
+    file_path: state.file_path,
+    component_name: Some(state.component_name),
+    caller: state.caller,
+  })
+}
+
+/// Print the warnings of a transformation to stderr.
+fn report_warnings(output: &TransformOutput, name: &str) {
+  for warning in &output.warnings {
+    match warning.loc {
+      Some(loc) => eprintln!("warning: {}:{}: {}", name, loc, warning.message),
+      None => eprintln!("warning: {}: {}", name, warning.message),
+    }
+  }
+}
+
+fn transform_file(path: &Path, config: &Config) -> Result<TransformOutput> {
+  let code =
+    fs::read_to_string(path).with_context(|| format!("failed to read \"{}\"", path.display()))?;
+  let state = get_state(Some(path.to_string_lossy().to_string()))?;
+  let output = transform(code, config.clone(), state)
+    .with_context(|| format!("failed to transform \"{}\"", path.display()))?;
+  report_warnings(&output, &path.to_string_lossy());
+  Ok(output)
+}
+
+/// Print the code of a component, the files generated with it can not be written to stdout.
+fn print_output(output: &TransformOutput) -> Result<()> {
+  if let Some(file) = output.files.first() {
+    bail!("--out-dir is required to write \"{}\"", file.path);
+  }
+  if output.map.is_some() {
+    bail!("--out-dir is required to write source maps");
+  }
+  io::stdout().write_all(output.code.as_bytes())?;
+  Ok(())
+}
+
+fn run_stdin(args: &Args, config: Config) -> Result<()> {
+  let mut code = String::new();
+  io::stdin()
+    .read_to_string(&mut code)
+    .context("failed to read stdin")?;
+  let output = transform(code, config, get_state(args.stdin_filepath.clone())?)?;
+  report_warnings(&output, args.stdin_filepath.as_deref().unwrap_or("<stdin>"));
+  print_output(&output)
+}
+
+fn run(args: Args) -> Result<()> {
+  let config = args.to_config()?;
+
+  if args.inputs.is_empty() || args.inputs == ["-"] {
+    return run_stdin(&args, config);
+  }
+
+  let inputs = collect_inputs(&args.inputs)?;
+
+  let out_dir = match &args.out_dir {
+    Some(out_dir) => out_dir,
+    None => {
+      if inputs.len() != 1 || inputs[0].from_dir {
+        bail!("--out-dir is required to transform directories or multiple files");
+      }
+      if config.index {
+        bail!("--out-dir is required to generate index files");
+      }
+      let output = transform_file(&inputs[0].path, &config)?;
+      return print_output(&output);
+    }
+  };
+
+  let ext = args.ext(&config);
+  let output_paths = inputs
+    .iter()
+    .map(|input| get_output_path(input, out_dir, &ext, args.filename_case))
+    .collect::<Vec<PathBuf>>();
+  // Different file names can have the same output path once their case is changed, like
+  // `a-b.svg` and `a_b.svg`, the first component would be overwritten.
+  let mut sources: HashMap<&Path, &Input> = HashMap::new();
+  for (input, output_path) in inputs.iter().zip(&output_paths) {
+    if let Some(other) = sources.insert(output_path, input) {
+      bail!(
+        "\"{}\" and \"{}\" are both written to \"{}\"",
+        other.path.display(),
+        input.path.display(),
+        output_path.display()
+      );
+    }
+  }
+
+  let mut indexes: BTreeMap<PathBuf, Vec<IndexFile>> = BTreeMap::new();
+  for (input, output_path) in inputs.iter().zip(output_paths) {
+    if config.index {
+      add_index_file(&mut indexes, input, &output_path)?;
+    }
+    if args.ignore_existing && output_path.exists() {
+      if !args.silent {
+        println!(
+          "{} -> {} (skipped)",
+          input.path.display(),
+          output_path.display()
+        );
+      }
+      continue;
+    }
+
+    let output = transform_file(&input.path, &config)?;
+    let parent = output_path.parent().unwrap_or(out_dir);
+    fs::create_dir_all(parent)
+      .with_context(|| format!("failed to create directory \"{}\"", parent.display()))?;
+    let code = match output.map {
+      Some(map) => write_source_map(&output_path, output.code, map)?,
+      None => output.code,
+    };
+    fs::write(&output_path, code)
+      .with_context(|| format!("failed to write \"{}\"", output_path.display()))?;
+    for file in output.files {
+      let file_path = parent.join(&file.path);
+      fs::write(&file_path, file.code)
+        .with_context(|| format!("failed to write \"{}\"", file_path.display()))?;
+    }
+
+    if !args.silent {
+      println!("{} -> {}", input.path.display(), output_path.display());
+    }
+  }
+
+  let index_ext = args.index_ext(&config);
+  for (dir, files) in indexes {
+    let index_path = dir.join(format!("index.{}", index_ext));
+    let result = create_index(&files, &config)
+      .with_context(|| format!("failed to create \"{}\"", index_path.display()))?;
+    fs::write(&index_path, result)
+      .with_context(|| format!("failed to write \"{}\"", index_path.display()))?;
+
+    if !args.silent {
+      println!("{}", index_path.display());
+    }
+  }
+
+  Ok(())
+}
+
+/// Write the source map of a generated file as `<file>.map` next to it, the code is returned with
+/// a comment referencing it.
+fn write_source_map(output_path: &Path, code: String, map: String) -> Result<String> {
+  let mut map_path = output_path.as_os_str().to_owned();
+  map_path.push(".map");
+  let map_path = PathBuf::from(map_path);
+  fs::write(&map_path, map)
+    .with_context(|| format!("failed to write \"{}\"", map_path.display()))?;
+
+  let map_name = map_path.file_name().unwrap_or_default().to_string_lossy();
+  let separator = if code.ends_with('\n') { "" } else { "\n" };
+  Ok(format!(
+    "{}{}//# sourceMappingURL={}\n",
+    code, separator, map_name
+  ))
+}
+
+/// Register a generated file in the index of its output directory,
+/// under the component name derived from the SVG file path.
+fn add_index_file(
+  indexes: &mut BTreeMap<PathBuf, Vec<IndexFile>>,
+  input: &Input,
+  output_path: &Path,
+) -> Result<()> {
+  let (Some(dir), Some(file_name)) = (output_path.parent(), output_path.file_name()) else {
+    return Ok(());
+  };
+  let state = get_state(Some(input.path.to_string_lossy().to_string()))?;
+  indexes
+    .entry(dir.to_path_buf())
+    .or_default()
+    .push(IndexFile {
+      path: file_name.to_string_lossy().to_string(),
+      component_name: state.component_name.unwrap_or_default(),
+    });
+  Ok(())
+}
+
+fn main() {
+  if let Err(err) = run(Args::parse()) {
+    eprintln!("error: {:#}", err);
+    process::exit(1);
+  }
+}
+
+#[cfg(test)]
+mod tests {
+  use super::*;
+
+  fn create_dir(name: &str, files: &[(&str, &str)]) -> PathBuf {
+    let dir = std::env::temp_dir().join(format!("svgr-rs-cli-{}", name));
+    let _ = fs::remove_dir_all(&dir);
+    for (file, content) in files {
+      let path = dir.join(file);
+      fs::create_dir_all(path.parent().unwrap()).unwrap();
+      fs::write(path, content).unwrap();
+    }
+    dir
+  }
+
+  fn run_args(args: &[&str]) -> Result<()> {
+    run(Args::try_parse_from(std::iter::once("svgr-rs").chain(args.iter().copied())).unwrap())
+  }
+
+  #[test]
+  fn derives_component_name_from_file_path() {
+    let state = get_state(Some("icons/arrow-left.svg".to_string())).unwrap();
+    assert_eq!(state.component_name.unwrap(), "SvgArrowLeft");
+
+    let state = get_state(None).unwrap();
+    assert_eq!(state.component_name.unwrap(), "SvgComponent");
+    assert!(state.caller.is_none());
+  }
+
+  #[test]
+  fn writes_components_into_out_dir() {
+    let dir = create_dir(
+      "out-dir",
+      &[
+        ("icons/arrow-left.svg", "<svg><path/></svg>"),
+        ("icons/nested/star.svg", "<svg/>"),
+      ],
+    );
+    let out_dir = dir.join("dist");
+
+    run_args(&[
+      "--silent",
+      "--no-runtime-config",
+      "--typescript",
+      "--filename-case",
+      "kebab",
+      "-d",
+      &out_dir.to_string_lossy(),
+      &dir.join("icons").to_string_lossy(),
+    ])
+    .unwrap();
+
+    let arrow_left = fs::read_to_string(out_dir.join("arrow-left.tsx")).unwrap();
+    assert!(arrow_left.contains("const SvgArrowLeft = (props: SVGProps<SVGSVGElement>)"));
+    assert!(out_dir.join("nested/star.tsx").exists());
+  }
+
+  #[test]
+  fn writes_source_maps_next_to_components() {
+    let dir = create_dir("source-maps", &[("icons/star.svg", "<svg><path/></svg>")]);
+    let out_dir = dir.join("dist");
+
+    run_args(&[
+      "--silent",
+      "--no-runtime-config",
+      "--source-maps",
+      "-d",
+      &out_dir.to_string_lossy(),
+      &dir.join("icons/star.svg").to_string_lossy(),
+    ])
+    .unwrap();
+
+    let star = fs::read_to_string(out_dir.join("Star.js")).unwrap();
+    assert!(star.ends_with("\n//# sourceMappingURL=Star.js.map\n"));
+    let map: serde_json::Value =
+      serde_json::from_str(&fs::read_to_string(out_dir.join("Star.js.map")).unwrap()).unwrap();
+    assert_eq!(
+      map["sourcesContent"],
+      serde_json::json!(["<svg><path/></svg>"])
+    );
+
+    let err = run_args(&[
+      "--no-runtime-config",
+      "--source-maps",
+      &dir.join("icons/star.svg").to_string_lossy(),
+    ])
+    .unwrap_err();
+    assert_eq!(
+      err.to_string(),
+      "--out-dir is required to write source maps"
+    );
+  }
+
+  #[test]
+  fn writes_css_modules_next_to_components() {
+    let dir = create_dir(
+      "css-modules",
+      &[(
+        "icons/star.svg",
+        r#"<svg><style>.a{fill:red}</style><path class="a"/></svg>"#,
+      )],
+    );
+    let out_dir = dir.join("dist");
+
+    run_args(&[
+      "--silent",
+      "--no-runtime-config",
+      "--css-modules",
+      "-d",
+      &out_dir.to_string_lossy(),
+      &dir.join("icons/star.svg").to_string_lossy(),
+    ])
+    .unwrap();
+
+    let star = fs::read_to_string(out_dir.join("Star.js")).unwrap();
+    assert!(star.contains(r#"import styles from "./SvgStar.module.css";"#));
+    let css = fs::read_to_string(out_dir.join("SvgStar.module.css")).unwrap();
+    assert_eq!(css, ".a{fill:red}\n");
+  }
+
+  #[test]
+  fn ignores_existing_files() {
+    let dir = create_dir(
+      "ignore-existing",
+      &[("icons/star.svg", "<svg/>"), ("dist/Star.js", "existing")],
+    );
+    let out_dir = dir.join("dist");
+
+    run_args(&[
+      "--silent",
+      "--ignore-existing",
+      "-d",
+      &out_dir.to_string_lossy(),
+      &dir.join("icons/star.svg").to_string_lossy(),
+    ])
+    .unwrap();
+
+    assert_eq!(
+      fs::read_to_string(out_dir.join("Star.js")).unwrap(),
+      "existing"
+    );
+  }
+
+  #[test]
+  fn generates_index_files() {
+    let dir = create_dir(
+      "index",
+      &[
+        ("icons/arrow-left.svg", "<svg/>"),
+        ("icons/star.svg", "<svg/>"),
+        ("icons/nested/star.svg", "<svg/>"),
+      ],
+    );
+    let out_dir = dir.join("dist");
+
+    run_args(&[
+      "--silent",
+      "--no-runtime-config",
+      "--index",
+      "--typescript",
+      "-d",
+      &out_dir.to_string_lossy(),
+      &dir.join("icons").to_string_lossy(),
+    ])
+    .unwrap();
+
+    assert_eq!(
+      fs::read_to_string(out_dir.join("index.ts")).unwrap(),
+      r#"export { default as SvgArrowLeft } from "./ArrowLeft";
+export { default as SvgStar } from "./Star";
+"#
+    );
+    assert_eq!(
+      fs::read_to_string(out_dir.join("nested/index.ts")).unwrap(),
+      "export { default as SvgStar } from \"./Star\";\n"
+    );
+  }
+
+  #[test]
+  fn fails_when_files_are_written_to_the_same_path() {
+    let dir = create_dir(
+      "same-output-path",
+      &[("icons/a-b.svg", "<svg/>"), ("icons/a_b.svg", "<svg/>")],
+    );
+    let out_dir = dir.join("dist");
+
+    let err = run_args(&[
+      "--silent",
+      "-d",
+      &out_dir.to_string_lossy(),
+      &dir.join("icons/a-b.svg").to_string_lossy(),
+      &dir.join("icons/a_b.svg").to_string_lossy(),
+    ])
+    .unwrap_err();
+    assert_eq!(
+      err.to_string(),
+      format!(
+        "\"{}\" and \"{}\" are both written to \"{}\"",
+        dir.join("icons/a-b.svg").display(),
+        dir.join("icons/a_b.svg").display(),
+        out_dir.join("AB.js").display()
+      )
+    );
+    assert!(!out_dir.exists());
+  }
+
+  #[test]
+  fn requires_out_dir_for_directories() {
+    let dir = create_dir("no-out-dir", &[("icons/star.svg", "<svg/>")]);
+
+    let err = run_args(&[&dir.join("icons").to_string_lossy()]).unwrap_err();
+    assert_eq!(
+      err.to_string(),
+      "--out-dir is required to transform directories or multiple files"
+    );
+  }
+}
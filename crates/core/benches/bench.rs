@@ -1,7 +1,7 @@
 use std::fs;
 
 use codspeed_criterion_compat::{criterion_group, criterion_main, Criterion};
-use svgr_rs::transform;
+use svgr_rs::{transform, transform_many};
 
 pub fn criterion_benchmark(c: &mut Criterion) {
   let path = "benches/rspack-logo.svg";
@@ -15,6 +15,14 @@ pub fn criterion_benchmark(c: &mut Criterion) {
       )
     })
   });
+  c.bench_function("Rspack logo x100 in parallel", |b| {
+    b.iter(|| {
+      transform_many(
+        vec![(rspack_logo.to_string(), Default::default()); 100],
+        &Default::default(),
+      )
+    })
+  });
 }
 
 criterion_group!(benches, criterion_benchmark);
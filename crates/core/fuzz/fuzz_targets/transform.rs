@@ -0,0 +1,100 @@
+#![no_main]
+
+use arbitrary::Arbitrary;
+use libfuzzer_sys::fuzz_target;
+use svgr_rs::{
+  transform, Caller, Config, ExpandProps, ExportType, FallbackIds, Icon, State, Target, UniqueIds,
+};
+
+#[derive(Debug, Arbitrary)]
+struct Input {
+  svg: String,
+  r#ref: bool,
+  title_prop: bool,
+  desc_prop: bool,
+  expand_props: u8,
+  dimensions: bool,
+  icon: Option<u8>,
+  native: bool,
+  typescript: bool,
+  memo: bool,
+  named_export: bool,
+  svgo: bool,
+  prettier: bool,
+  source_maps: bool,
+  strict: bool,
+  target: u8,
+  unique_ids: u8,
+  fallback_ids: u8,
+  css_modules: bool,
+  inline_styles: bool,
+  file_path: Option<String>,
+  component_name: Option<String>,
+  previous_export: Option<String>,
+}
+
+fuzz_target!(|input: Input| {
+  let config = Config {
+    r#ref: input.r#ref,
+    title_prop: input.title_prop,
+    desc_prop: input.desc_prop,
+    expand_props: match input.expand_props % 3 {
+      0 => ExpandProps::None,
+      1 => ExpandProps::Start,
+      _ => ExpandProps::End,
+    },
+    dimensions: input.dimensions,
+    icon: input.icon.map(|icon| match icon % 3 {
+      0 => Icon::Bool(true),
+      1 => Icon::Str(format!("{}px", icon)),
+      _ => Icon::Num(icon as f64),
+    }),
+    native: input.native,
+    typescript: input.typescript,
+    memo: input.memo,
+    export_type: if input.named_export {
+      ExportType::Named
+    } else {
+      ExportType::Default
+    },
+    svgo: input.svgo,
+    prettier: input.prettier,
+    source_maps: input.source_maps,
+    strict: input.strict,
+    target: match input.target % 7 {
+      0 => Target::React,
+      1 => Target::Vue,
+      2 => Target::VueSfc,
+      3 => Target::Solid,
+      4 => Target::Svelte,
+      5 => Target::Lit,
+      _ => Target::WebComponent,
+    },
+    unique_ids: match input.unique_ids % 3 {
+      0 => UniqueIds::None,
+      1 => UniqueIds::Prefix,
+      _ => UniqueIds::UseId,
+    },
+    fallback_ids: match input.fallback_ids % 3 {
+      0 => FallbackIds::None,
+      1 => FallbackIds::UseId,
+      _ => FallbackIds::Counter,
+    },
+    css_modules: input.css_modules,
+    inline_styles: input.inline_styles,
+    // Never read configuration files from the disk.
+    runtime_config: false,
+    ..Default::default()
+  };
+  let state = State {
+    file_path: input.file_path,
+    component_name: input.component_name,
+    caller: input.previous_export.map(|previous_export| Caller {
+      name: None,
+      previous_export: Some(previous_export),
+    }),
+  };
+
+  // Any input is allowed to fail, but never to panic.
+  let _ = transform(input.svg, config, state);
+});
@@ -5,7 +5,7 @@ use swc_core::{
 };
 
 use super::core;
-use crate::core::config::SvgProp;
+use crate::core::config::{SvgProp, Target};
 
 pub enum AttributePosition {
   Start,
@@ -35,11 +35,25 @@ impl Visitor {
       attributes.push(attr);
     }
 
-    if config.r#ref {
+    let expand_props = !matches!(config.expand_props, core::config::ExpandProps::None);
+
+    // The single-file components refer to the `svg` ref by name, the render functions by value,
+    // and the Svelte components bind the element to their `ref` prop.
+    // A SolidJS ref is a regular prop, it is already given to the SVG when the props are expanded,
+    // and the custom elements are their own ref.
+    let ref_attr = match config.target {
+      Target::React => Some(("ref", "ref", true)),
+      Target::Solid if !expand_props => Some(("ref", "ref", true)),
+      Target::Vue => Some(("ref", "svg", true)),
+      Target::VueSfc => Some(("ref", "svg", false)),
+      Target::Svelte => Some(("bind:this", "ref", true)),
+      Target::Solid | Target::Lit | Target::WebComponent => None,
+    };
+    if let Some((name, value, literal)) = ref_attr.filter(|_| config.r#ref) {
       attributes.push(Attribute {
-        name: "ref".to_string(),
-        value: Some("ref".to_string()),
-        literal: true,
+        name: name.to_string(),
+        value: Some(value.to_string()),
+        literal,
         ..Default::default()
       });
     }
@@ -62,15 +76,22 @@ impl Visitor {
       });
     }
 
-    let expand_props = !matches!(config.expand_props, core::config::ExpandProps::None);
-    if expand_props {
+    // The attributes given to a Vue component fall through to its root element,
+    // and the ones of a custom element stay on the host element.
+    // The attributes given to a Svelte component without being declared are in `$$restProps`.
+    let props = match config.target {
+      Target::React | Target::Solid => Some("props"),
+      Target::Svelte => Some("$$restProps"),
+      Target::Vue | Target::VueSfc | Target::Lit | Target::WebComponent => None,
+    };
+    if let Some(props) = props.filter(|_| expand_props) {
       let position = match config.expand_props {
         core::config::ExpandProps::Start => Some(AttributePosition::Start),
         core::config::ExpandProps::End => Some(AttributePosition::End),
         core::config::ExpandProps::None => None,
       };
       attributes.push(Attribute {
-        name: "props".to_string(),
+        name: props.to_string(),
         spread: true,
         position,
         ..Default::default()
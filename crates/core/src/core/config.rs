@@ -1,6 +1,11 @@
-use std::collections::HashMap;
+use std::{
+  collections::{HashMap, HashSet},
+  sync::Arc,
+};
 
-#[derive(Debug, Clone)]
+use crate::{index_template::IndexTemplate, plugin::Plugin, transform_svg_component::Template};
+
+#[derive(Debug, Clone, PartialEq)]
 pub enum Icon {
   Bool(bool),
   Str(String),
@@ -13,7 +18,7 @@ impl Default for Icon {
   }
 }
 
-#[derive(Debug, Clone, Default)]
+#[derive(Debug, Clone, Default, PartialEq)]
 pub enum ExpandProps {
   None,
   Start,
@@ -21,7 +26,7 @@ pub enum ExpandProps {
   End,
 }
 
-#[derive(Debug, Clone, Default)]
+#[derive(Debug, Clone, Default, PartialEq)]
 pub enum JSXRuntime {
   #[default]
   Classic,
@@ -29,7 +34,7 @@ pub enum JSXRuntime {
   Automatic,
 }
 
-#[derive(Debug, Clone, Default)]
+#[derive(Debug, Clone, Default, PartialEq)]
 pub struct JSXRuntimeImport {
   pub source: String,
   pub namespace: Option<String>,
@@ -37,19 +42,228 @@ pub struct JSXRuntimeImport {
   pub specifiers: Option<Vec<String>>,
 }
 
-#[derive(Debug, Clone, Default)]
+#[derive(Debug, Clone, Default, PartialEq)]
 pub enum ExportType {
   Named,
   #[default]
   Default,
 }
 
-#[derive(Debug, Clone)]
+/// What happens to the elements not supported by react-native-svg, with `native`.
+#[derive(Debug, Clone, Default, PartialEq)]
+pub enum NativeElementPolicy {
+  /// The element is dropped, with a warning and a comment in the generated code.
+  #[default]
+  Drop,
+  /// The element is kept as is, for the renderers which support it.
+  Keep,
+  /// The transformation fails with `SvgrError::UnsupportedNativeElements`.
+  Error,
+}
+
+/// How the ids of the SVG are made unique, so they do not collide when many SVG are rendered in
+/// the same page.
+#[derive(Debug, Clone, Default, PartialEq)]
+pub enum UniqueIds {
+  /// The ids are kept.
+  #[default]
+  None,
+  /// The ids are prefixed with the file name and a hash of the file path.
+  Prefix,
+  /// The ids are prefixed with an id generated at runtime by `useId` or `createUniqueId`.
+  UseId,
+}
+
+/// How the `titleId` and `descId` props default when they are not given, so `aria-labelledby`
+/// always references the title.
+#[derive(Debug, Clone, Default, PartialEq)]
+pub enum FallbackIds {
+  /// The props are `undefined` when they are not given.
+  #[default]
+  None,
+  /// They default to an id generated by `useId` or `createUniqueId`.
+  UseId,
+  /// They default to an id from a module counter prefixed with the file name, for React versions
+  /// without `useId`.
+  Counter,
+}
+
+/// The framework the component is generated for.
+#[derive(Debug, Clone, Default, PartialEq)]
+pub enum Target {
+  /// A React component, the JSX is kept in the generated code.
+  #[default]
+  React,
+  /// A Vue 3 component created with `defineComponent`, rendering the SVG with `h()`.
+  Vue,
+  /// A Vue 3 single-file component, rendering the SVG in its `<template>`.
+  VueSfc,
+  /// A SolidJS component, the JSX is kept with the SVG attribute names and kebab-case styles.
+  Solid,
+  /// A Svelte component, rendering the SVG in its markup.
+  Svelte,
+  /// A custom element extending `LitElement`, rendering the SVG with the `html` and `svg` tagged templates of Lit.
+  Lit,
+  /// A custom element extending `HTMLElement`, rendering the SVG in its shadow root.
+  WebComponent,
+}
+
+/// The class extended by a custom element, imported from `source` when it is specified.
+#[derive(Debug, Clone, Default, PartialEq)]
+pub struct BaseClass {
+  pub name: String,
+  pub source: Option<String>,
+}
+
+#[derive(Debug, Clone, PartialEq)]
 pub struct SvgProp {
   pub key: String,
   pub value: String,
 }
 
+/// The SVGO plugins applied to the SVG document when `svgo` is enabled.
+/// The defaults follow the SVGO `preset-default`.
+#[derive(Debug, Clone, PartialEq)]
+pub struct SvgoConfig {
+  /// Remove the `<!DOCTYPE>` declaration.
+  pub remove_doctype: bool,
+
+  /// Remove the `<?xml ?>` processing instruction.
+  pub remove_xml_proc_inst: bool,
+
+  /// Remove comments, except the ones starting with `<!--!` which are usually legal notices.
+  pub remove_comments: bool,
+
+  /// Remove `<metadata>` elements.
+  pub remove_metadata: bool,
+
+  /// Remove elements, attributes and namespace declarations added by editors like Inkscape, Illustrator or Sketch.
+  pub remove_editors_ns_data: bool,
+
+  /// Cleanup newlines, leading, trailing and repeated whitespaces in attribute values.
+  pub cleanup_attrs: bool,
+
+  /// Remove elements inside `<defs>` which can not be referenced because they have no `id`.
+  pub remove_useless_defs: bool,
+
+  /// Remove `<g>` elements without attributes and move the attributes of a `<g>` with a single child onto that child.
+  pub collapse_groups: bool,
+
+  /// Remove attributes with an empty value.
+  pub remove_empty_attrs: bool,
+
+  /// Remove empty `<text>`, `<tspan>` and `<tref>` elements.
+  pub remove_empty_text: bool,
+
+  /// Remove container elements, like `<g>` or `<defs>`, which have no children.
+  pub remove_empty_containers: bool,
+}
+
+impl Default for SvgoConfig {
+  fn default() -> Self {
+    Self {
+      remove_doctype: true,
+      remove_xml_proc_inst: true,
+      remove_comments: true,
+      remove_metadata: true,
+      remove_editors_ns_data: true,
+      cleanup_attrs: true,
+      remove_useless_defs: true,
+      collapse_groups: true,
+      remove_empty_attrs: true,
+      remove_empty_text: true,
+      remove_empty_containers: true,
+    }
+  }
+}
+
+#[derive(Debug, Clone, Default, PartialEq)]
+pub enum TrailingComma {
+  None,
+  Es5,
+  #[default]
+  All,
+}
+
+/// The options of the pretty printer applied to the generated code when `prettier` is enabled.
+/// The defaults follow the Prettier defaults.
+#[derive(Debug, Clone, PartialEq)]
+pub struct PrettierConfig {
+  /// The line length the printer will try to wrap on.
+  pub print_width: usize,
+
+  /// The number of spaces per indentation level.
+  pub tab_width: usize,
+
+  /// Indent lines with tabs instead of spaces.
+  pub use_tabs: bool,
+
+  /// Use single quotes instead of double quotes, JSX attributes always use double quotes.
+  pub single_quote: bool,
+
+  /// Print semicolons at the ends of statements.
+  pub semi: bool,
+
+  /// Print trailing commas wherever possible in multi-line comma-separated syntactic structures.
+  /// * "all": wherever possible, including function parameters and calls
+  /// * "es5": where valid in ES5, like objects, arrays and imports
+  /// * "none": no trailing commas
+  pub trailing_comma: TrailingComma,
+}
+
+impl Default for PrettierConfig {
+  fn default() -> Self {
+    Self {
+      print_width: 80,
+      tab_width: 2,
+      use_tabs: false,
+      single_quote: false,
+      semi: true,
+      trailing_comma: TrailingComma::All,
+    }
+  }
+}
+
+/// An option which can be set by a runtime config file, see `Config::explicit_options`.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum ConfigOption {
+  Ref,
+  TitleProp,
+  DescProp,
+  ExpandProps,
+  Dimensions,
+  Icon,
+  Native,
+  NativeUnsupported,
+  NativeComponents,
+  NativeImportSource,
+  NativeElements,
+  SvgProps,
+  Typescript,
+  Memo,
+  ReplaceAttrValues,
+  JsxRuntime,
+  JsxRuntimeImport,
+  NamedExport,
+  ExportType,
+  Template,
+  Plugins,
+  Svgo,
+  SvgoConfig,
+  Prettier,
+  PrettierConfig,
+  Index,
+  Strict,
+  PreserveComments,
+  InlineStyles,
+  CssModules,
+  Target,
+  ElementName,
+  BaseClass,
+  UniqueIds,
+  FallbackIds,
+}
+
 /// The options used to transform the SVG.
 #[derive(Debug, Clone)]
 pub struct Config {
@@ -80,6 +294,20 @@ pub struct Config {
   /// All unsupported nodes will be removed.
   pub native: bool,
 
+  /// What happens to the elements not supported by react-native-svg, they are dropped by default.
+  pub native_unsupported: NativeElementPolicy,
+
+  /// Extra element to component mappings with `native`, like `filter` to `Filter` for the
+  /// versions of react-native-svg which support it. They override the built-in mappings.
+  pub native_components: HashMap<String, String>,
+
+  /// The source the components are imported from with `native`, `react-native-svg` by default.
+  pub native_import_source: String,
+
+  /// The policy of specific elements with `native`, it takes precedence over their component and
+  /// over `native_unsupported`.
+  pub native_elements: HashMap<String, NativeElementPolicy>,
+
   /// Add props to the root SVG tag.
   pub svg_props: Vec<SvgProp>,
 
@@ -107,6 +335,86 @@ pub struct Config {
 
   /// If you prefer named export in any case, you may set the `export_type` option to `named`.
   pub export_type: ExportType,
+
+  /// The plugins applied to the SVG document, the JSX element and the module of the component.
+  /// Defaults to the built-in plugins, which can be inserted into, reordered or removed.
+  pub plugins: Vec<Arc<dyn Plugin>>,
+
+  /// Customize the code output, the template receives the template variables and returns the module of the component.
+  pub template: Option<Arc<dyn Template>>,
+
+  /// Optimize the SVG document before it is converted into JSX.
+  pub svgo: bool,
+
+  /// The SVGO plugins to apply, only used when `svgo` is enabled.
+  pub svgo_config: SvgoConfig,
+
+  /// Format the generated code with the built-in pretty printer.
+  pub prettier: bool,
+
+  /// The options of the pretty printer, only used when `prettier` is enabled.
+  pub prettier_config: PrettierConfig,
+
+  /// Load the nearest runtime config file (`.svgrrc`, `svgr.config.json`, `svgr.config.toml`, the `svgr` key of `package.json`...),
+  /// searched from the directory of the transformed file upwards. Options set explicitly take precedence over the ones of the file.
+  pub runtime_config: bool,
+
+  /// Use this config file instead of searching for one, only used when `runtime_config` is enabled.
+  pub config_file: Option<String>,
+
+  /// The fields set explicitly, like `title_prop`, they take precedence over the runtime config
+  /// file even when they have their default value. The other fields only take precedence when
+  /// they differ from their default value.
+  pub explicit_options: HashSet<ConfigOption>,
+
+  /// Generate an index file re-exporting the components when transforming a directory, only used by the CLI.
+  pub index: bool,
+
+  /// Customize the index file, the template receives the generated files and returns the code of the index.
+  pub index_template: Option<Arc<dyn IndexTemplate>>,
+
+  /// Keep the final module in the output of the transformation.
+  pub ast: bool,
+
+  /// Generate a v3 source map from the SVG to the generated code.
+  /// It is not supported by the built-in pretty printer, no source map is generated when `prettier` is enabled.
+  pub source_maps: bool,
+
+  /// Fail with `SvgrError::InvalidXml` when the XML parser recovered from errors,
+  /// they are only reported as warnings otherwise.
+  pub strict: bool,
+
+  /// Keep the comments of the SVG, like the attribution required by icon licenses.
+  /// The comments outside the root element are printed at the top of the module,
+  /// the ones inside it become JSX comments.
+  pub preserve_comments: bool,
+
+  /// Inline the rules of the `<style>` elements into the matching elements, as presentation
+  /// attributes or `style` declarations, then remove them with the classes they used.
+  pub inline_styles: bool,
+
+  /// Extract the rules of the `<style>` elements into a CSS module written next to the component,
+  /// the classes it defines are read from its `styles` import. Only supported by React.
+  pub css_modules: bool,
+
+  /// The framework the component is generated for, React by default.
+  /// The attributes keep their SVG names for the other targets, and `jsx_runtime`, `memo`, `native` and `template` only apply to React.
+  pub target: Target,
+
+  /// The name the custom element is registered under, for the `Lit` and `WebComponent` targets.
+  /// Defaults to the kebab-case component name, for example `svg-arrow-left`.
+  pub element_name: Option<String>,
+
+  /// The class extended by the custom element, `LitElement` or `HTMLElement` by default.
+  pub base_class: Option<BaseClass>,
+
+  /// Rewrite the ids of the SVG and the references to them, like `url(#a)` or `href="#a"`,
+  /// so they do not collide. Generating them at runtime is only supported by React and SolidJS.
+  pub unique_ids: UniqueIds,
+
+  /// Generate a default `titleId` and `descId` in the component body, when `title_prop` or
+  /// `desc_prop` is set. Only supported by React and SolidJS.
+  pub fallback_ids: FallbackIds,
 }
 
 impl Default for Config {
@@ -119,6 +427,10 @@ impl Default for Config {
       dimensions: true,
       icon: None,
       native: false,
+      native_unsupported: NativeElementPolicy::Drop,
+      native_components: HashMap::new(),
+      native_import_source: "react-native-svg".to_string(),
+      native_elements: HashMap::new(),
       svg_props: vec![],
       typescript: false,
       memo: false,
@@ -127,6 +439,28 @@ impl Default for Config {
       jsx_runtime_import: Default::default(),
       named_export: "ReactComponent".to_string(),
       export_type: ExportType::Default,
+      plugins: crate::plugin::default_plugins(),
+      template: None,
+      svgo: false,
+      svgo_config: Default::default(),
+      prettier: false,
+      prettier_config: Default::default(),
+      runtime_config: true,
+      config_file: None,
+      explicit_options: HashSet::new(),
+      index: false,
+      index_template: None,
+      ast: false,
+      source_maps: false,
+      strict: false,
+      preserve_comments: false,
+      inline_styles: false,
+      css_modules: false,
+      target: Target::React,
+      element_name: None,
+      base_class: None,
+      unique_ids: UniqueIds::None,
+      fallback_ids: FallbackIds::None,
     }
   }
 }
@@ -1,2 +1,3 @@
 pub mod config;
+pub mod runtime_config;
 pub mod state;
@@ -0,0 +1,997 @@
+use std::{
+  collections::HashMap,
+  fs,
+  path::{Path, PathBuf},
+  sync::{Arc, Mutex},
+  time::SystemTime,
+};
+
+use lazy_static::lazy_static;
+use serde::{Deserialize, Serialize};
+
+use super::config::{
+  BaseClass, Config, ConfigOption, ExpandProps, ExportType, FallbackIds, Icon, JSXRuntime,
+  JSXRuntimeImport, NativeElementPolicy, PrettierConfig, SvgProp, SvgoConfig, Target,
+  TrailingComma, UniqueIds,
+};
+use crate::{plugin::get_builtin_plugin, transform_svg_component::StringTemplate, SvgrError};
+
+/// The runtime config files, in the order they are searched in each directory.
+const CONFIG_FILES: [&str; 10] = [
+  "package.json",
+  ".svgrrc",
+  ".svgrrc.json",
+  ".svgrrc.yaml",
+  ".svgrrc.yml",
+  ".svgrrc.toml",
+  "svgr.config.json",
+  "svgr.config.yaml",
+  "svgr.config.yml",
+  "svgr.config.toml",
+];
+
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[serde(untagged)]
+pub enum RuntimeExpandProps {
+  Bool(bool),
+  Str(String),
+}
+
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[serde(untagged)]
+pub enum RuntimeIcon {
+  Bool(bool),
+  Num(f64),
+  Str(String),
+}
+
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct RuntimeJSXRuntimeImport {
+  pub source: String,
+  #[serde(skip_serializing_if = "Option::is_none")]
+  pub namespace: Option<String>,
+  #[serde(skip_serializing_if = "Option::is_none")]
+  pub default_specifier: Option<String>,
+  #[serde(skip_serializing_if = "Option::is_none")]
+  pub specifiers: Option<Vec<String>>,
+}
+
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct RuntimeBaseClass {
+  pub name: String,
+  #[serde(skip_serializing_if = "Option::is_none")]
+  pub source: Option<String>,
+}
+
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct RuntimeSvgoConfig {
+  #[serde(skip_serializing_if = "Option::is_none")]
+  pub remove_doctype: Option<bool>,
+  #[serde(skip_serializing_if = "Option::is_none")]
+  pub remove_xml_proc_inst: Option<bool>,
+  #[serde(skip_serializing_if = "Option::is_none")]
+  pub remove_comments: Option<bool>,
+  #[serde(skip_serializing_if = "Option::is_none")]
+  pub remove_metadata: Option<bool>,
+  #[serde(skip_serializing_if = "Option::is_none")]
+  pub remove_editors_ns_data: Option<bool>,
+  #[serde(skip_serializing_if = "Option::is_none")]
+  pub cleanup_attrs: Option<bool>,
+  #[serde(skip_serializing_if = "Option::is_none")]
+  pub remove_useless_defs: Option<bool>,
+  #[serde(skip_serializing_if = "Option::is_none")]
+  pub collapse_groups: Option<bool>,
+  #[serde(skip_serializing_if = "Option::is_none")]
+  pub remove_empty_attrs: Option<bool>,
+  #[serde(skip_serializing_if = "Option::is_none")]
+  pub remove_empty_text: Option<bool>,
+  #[serde(skip_serializing_if = "Option::is_none")]
+  pub remove_empty_containers: Option<bool>,
+}
+
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct RuntimePrettierConfig {
+  #[serde(skip_serializing_if = "Option::is_none")]
+  pub print_width: Option<usize>,
+  #[serde(skip_serializing_if = "Option::is_none")]
+  pub tab_width: Option<usize>,
+  #[serde(skip_serializing_if = "Option::is_none")]
+  pub use_tabs: Option<bool>,
+  #[serde(skip_serializing_if = "Option::is_none")]
+  pub single_quote: Option<bool>,
+  #[serde(skip_serializing_if = "Option::is_none")]
+  pub semi: Option<bool>,
+  #[serde(skip_serializing_if = "Option::is_none")]
+  pub trailing_comma: Option<String>,
+}
+
+/// The options read from a runtime config file.
+/// The keys are the same as the ones of the Node.js API, for example `titleProp` or `expandProps`.
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct RuntimeConfig {
+  #[serde(skip_serializing_if = "Option::is_none")]
+  pub r#ref: Option<bool>,
+  #[serde(skip_serializing_if = "Option::is_none")]
+  pub title_prop: Option<bool>,
+  #[serde(skip_serializing_if = "Option::is_none")]
+  pub desc_prop: Option<bool>,
+  #[serde(skip_serializing_if = "Option::is_none")]
+  pub expand_props: Option<RuntimeExpandProps>,
+  #[serde(skip_serializing_if = "Option::is_none")]
+  pub dimensions: Option<bool>,
+  #[serde(skip_serializing_if = "Option::is_none")]
+  pub icon: Option<RuntimeIcon>,
+  #[serde(skip_serializing_if = "Option::is_none")]
+  pub native: Option<bool>,
+  #[serde(skip_serializing_if = "Option::is_none")]
+  pub native_unsupported: Option<String>,
+  #[serde(skip_serializing_if = "Option::is_none")]
+  pub native_components: Option<HashMap<String, String>>,
+  #[serde(skip_serializing_if = "Option::is_none")]
+  pub native_import_source: Option<String>,
+  #[serde(skip_serializing_if = "Option::is_none")]
+  pub native_elements: Option<HashMap<String, String>>,
+  #[serde(skip_serializing_if = "Option::is_none")]
+  pub svg_props: Option<HashMap<String, String>>,
+  #[serde(skip_serializing_if = "Option::is_none")]
+  pub typescript: Option<bool>,
+  #[serde(skip_serializing_if = "Option::is_none")]
+  pub memo: Option<bool>,
+  #[serde(skip_serializing_if = "Option::is_none")]
+  pub replace_attr_values: Option<HashMap<String, String>>,
+  #[serde(skip_serializing_if = "Option::is_none")]
+  pub jsx_runtime: Option<String>,
+  #[serde(skip_serializing_if = "Option::is_none")]
+  pub jsx_runtime_import: Option<RuntimeJSXRuntimeImport>,
+  #[serde(skip_serializing_if = "Option::is_none")]
+  pub named_export: Option<String>,
+  #[serde(skip_serializing_if = "Option::is_none")]
+  pub export_type: Option<String>,
+  #[serde(skip_serializing_if = "Option::is_none")]
+  pub svgo: Option<bool>,
+  #[serde(skip_serializing_if = "Option::is_none")]
+  pub svgo_config: Option<RuntimeSvgoConfig>,
+  #[serde(skip_serializing_if = "Option::is_none")]
+  pub prettier: Option<bool>,
+  #[serde(skip_serializing_if = "Option::is_none")]
+  pub prettier_config: Option<RuntimePrettierConfig>,
+  #[serde(skip_serializing_if = "Option::is_none")]
+  pub template: Option<String>,
+  #[serde(skip_serializing_if = "Option::is_none")]
+  pub plugins: Option<Vec<String>>,
+  #[serde(skip_serializing_if = "Option::is_none")]
+  pub index: Option<bool>,
+  #[serde(skip_serializing_if = "Option::is_none")]
+  pub strict: Option<bool>,
+  #[serde(skip_serializing_if = "Option::is_none")]
+  pub preserve_comments: Option<bool>,
+  #[serde(skip_serializing_if = "Option::is_none")]
+  pub inline_styles: Option<bool>,
+  #[serde(skip_serializing_if = "Option::is_none")]
+  pub css_modules: Option<bool>,
+  #[serde(skip_serializing_if = "Option::is_none")]
+  pub target: Option<String>,
+  #[serde(skip_serializing_if = "Option::is_none")]
+  pub element_name: Option<String>,
+  #[serde(skip_serializing_if = "Option::is_none")]
+  pub base_class: Option<RuntimeBaseClass>,
+  #[serde(skip_serializing_if = "Option::is_none")]
+  pub unique_ids: Option<String>,
+  #[serde(skip_serializing_if = "Option::is_none")]
+  pub fallback_ids: Option<String>,
+}
+
+#[derive(Deserialize)]
+struct PackageJson {
+  svgr: Option<RuntimeConfig>,
+}
+
+fn invalid_config_file(path: &Path, reason: impl ToString) -> SvgrError {
+  SvgrError::Configuration(format!(
+    "failed to load config file \"{}\": {}",
+    path.display(),
+    reason.to_string()
+  ))
+}
+
+/// Load a runtime config file, the format is inferred from the file extension.
+/// A file without extension, like `.svgrrc`, can be written in JSON or YAML.
+pub fn load_config_file(path: impl AsRef<Path>) -> Result<RuntimeConfig, SvgrError> {
+  let path = path.as_ref();
+  let content = fs::read_to_string(path).map_err(|e| invalid_config_file(path, e))?;
+
+  let extension = path.extension().and_then(|e| e.to_str());
+  match extension {
+    Some("toml") => toml::from_str(&content).map_err(|e| invalid_config_file(path, e)),
+    Some("json") => {
+      if path.file_name().and_then(|n| n.to_str()) == Some("package.json") {
+        let package_json: PackageJson =
+          serde_json::from_str(&content).map_err(|e| invalid_config_file(path, e))?;
+        return Ok(package_json.svgr.unwrap_or_default());
+      }
+      serde_json::from_str(&content).map_err(|e| invalid_config_file(path, e))
+    }
+    // YAML is a superset of JSON.
+    _ => serde_yaml::from_str(&content).map_err(|e| invalid_config_file(path, e)),
+  }
+}
+
+fn has_svgr_key(package_json: &Path) -> bool {
+  fs::read_to_string(package_json)
+    .ok()
+    .and_then(|content| serde_json::from_str::<serde_json::Value>(&content).ok())
+    .map_or(false, |value| value.get("svgr").is_some())
+}
+
+/// The modification times a lookup depends on in a directory: adding or removing a config file
+/// changes the one of the directory, and the `svgr` key can be added to its `package.json`.
+#[derive(Debug, Clone, PartialEq)]
+struct DirStamp {
+  dir: PathBuf,
+  modified: Option<SystemTime>,
+  package_json_modified: Option<SystemTime>,
+}
+
+impl DirStamp {
+  fn new(dir: &Path) -> Self {
+    let modified = |path: &Path| fs::metadata(path).and_then(|m| m.modified()).ok();
+    Self {
+      dir: dir.to_path_buf(),
+      modified: modified(dir),
+      package_json_modified: modified(&dir.join("package.json")),
+    }
+  }
+
+  fn is_fresh(&self) -> bool {
+    *self == Self::new(&self.dir)
+  }
+}
+
+#[derive(Debug)]
+struct CachedLookup {
+  config_file: PathBuf,
+  /// The directories searched from the cached one up to the one of the config file.
+  stamps: Vec<DirStamp>,
+}
+
+lazy_static! {
+  /// The nearest config file of each directory searched so far, the files of a directory share
+  /// the same lookup. An entry is dropped as soon as one of the searched directories changes,
+  /// and directories without a config file are not cached.
+  static ref CONFIG_FILE_CACHE: Mutex<HashMap<PathBuf, CachedLookup>> = Default::default();
+}
+
+fn find_config_file_in(dir: &Path) -> Option<PathBuf> {
+  CONFIG_FILES.iter().find_map(|name| {
+    let candidate = dir.join(name);
+    if !candidate.is_file() || (*name == "package.json" && !has_svgr_key(&candidate)) {
+      return None;
+    }
+    Some(candidate)
+  })
+}
+
+fn find_config_file(path: &Path) -> Option<PathBuf> {
+  let path = if path.is_absolute() {
+    path.to_path_buf()
+  } else {
+    std::env::current_dir().ok()?.join(path)
+  };
+  let start = if path.is_dir() {
+    path.as_path()
+  } else {
+    path.parent()?
+  };
+
+  let mut cache = CONFIG_FILE_CACHE
+    .lock()
+    .unwrap_or_else(|poisoned| poisoned.into_inner());
+  let mut stamps = vec![];
+  let mut searched = 0;
+  let mut config_file = None;
+  for dir in start.ancestors() {
+    if let Some(cached) = cache.get(dir) {
+      if cached.stamps.iter().all(DirStamp::is_fresh) {
+        stamps.extend(cached.stamps.iter().cloned());
+        config_file = Some(cached.config_file.clone());
+        break;
+      }
+      cache.remove(dir);
+    }
+    // Stamp the directory before reading it, so a change made meanwhile invalidates the entry.
+    stamps.push(DirStamp::new(dir));
+    searched += 1;
+    if let Some(found) = find_config_file_in(dir) {
+      config_file = Some(found);
+      break;
+    }
+  }
+
+  let config_file = config_file?;
+  for i in 0..searched {
+    cache.insert(
+      stamps[i].dir.clone(),
+      CachedLookup {
+        config_file: config_file.clone(),
+        stamps: stamps[i..].to_vec(),
+      },
+    );
+  }
+  Some(config_file)
+}
+
+/// Find the nearest runtime config file, walking up from the given file or directory, and load it.
+/// Returns `None` if no config file is found. The lookup of each directory is cached until the
+/// directory or one of its ancestors up to the config file changes.
+///
+/// # Examples
+///
+/// ```rust
+/// use std::fs;
+///
+/// use svgr_rs::resolve_config;
+///
+/// let dir = std::env::temp_dir().join("svgr-rs-resolve-config-example");
+/// fs::create_dir_all(dir.join("icons")).unwrap();
+/// fs::write(dir.join(".svgrrc"), r#"{ "titleProp": true }"#).unwrap();
+///
+/// let runtime_config = resolve_config(dir.join("icons/star.svg")).unwrap().unwrap();
+/// assert_eq!(runtime_config.title_prop, Some(true));
+/// ```
+pub fn resolve_config(path: impl AsRef<Path>) -> Result<Option<RuntimeConfig>, SvgrError> {
+  match find_config_file(path.as_ref()) {
+    Some(config_file) => load_config_file(config_file).map(Some),
+    None => Ok(None),
+  }
+}
+
+fn to_expand_props(raw: RuntimeExpandProps) -> Result<ExpandProps, SvgrError> {
+  match raw {
+    RuntimeExpandProps::Bool(true) => Ok(ExpandProps::End),
+    RuntimeExpandProps::Bool(false) => Ok(ExpandProps::None),
+    RuntimeExpandProps::Str(s) => match s.as_str() {
+      "start" => Ok(ExpandProps::Start),
+      "end" => Ok(ExpandProps::End),
+      _ => Err(SvgrError::Configuration(format!(
+        r#""expandProps" must be a boolean, "start" or "end", got "{}""#,
+        s
+      ))),
+    },
+  }
+}
+
+fn to_jsx_runtime(raw: String) -> Result<JSXRuntime, SvgrError> {
+  match raw.as_str() {
+    "classic" => Ok(JSXRuntime::Classic),
+    "classic-preact" => Ok(JSXRuntime::ClassicPreact),
+    "automatic" => Ok(JSXRuntime::Automatic),
+    _ => Err(SvgrError::Configuration(format!(
+      r#""jsxRuntime" must be "classic", "classic-preact" or "automatic", got "{}""#,
+      raw
+    ))),
+  }
+}
+
+fn to_export_type(raw: String) -> Result<ExportType, SvgrError> {
+  match raw.as_str() {
+    "named" => Ok(ExportType::Named),
+    "default" => Ok(ExportType::Default),
+    _ => Err(SvgrError::Configuration(format!(
+      r#""exportType" must be "named" or "default", got "{}""#,
+      raw
+    ))),
+  }
+}
+
+fn to_target(raw: String) -> Result<Target, SvgrError> {
+  match raw.as_str() {
+    "react" => Ok(Target::React),
+    "vue" => Ok(Target::Vue),
+    "vue-sfc" => Ok(Target::VueSfc),
+    "solid" => Ok(Target::Solid),
+    "svelte" => Ok(Target::Svelte),
+    "lit" => Ok(Target::Lit),
+    "web-component" => Ok(Target::WebComponent),
+    _ => Err(SvgrError::Configuration(format!(
+      r#""target" must be "react", "vue", "vue-sfc", "solid", "svelte", "lit" or "web-component", got "{}""#,
+      raw
+    ))),
+  }
+}
+
+fn to_native_element_policy(option: &str, raw: String) -> Result<NativeElementPolicy, SvgrError> {
+  match raw.as_str() {
+    "drop" => Ok(NativeElementPolicy::Drop),
+    "keep" => Ok(NativeElementPolicy::Keep),
+    "error" => Ok(NativeElementPolicy::Error),
+    _ => Err(SvgrError::Configuration(format!(
+      r#""{}" must be "drop", "keep" or "error", got "{}""#,
+      option, raw
+    ))),
+  }
+}
+
+fn to_unique_ids(raw: String) -> Result<UniqueIds, SvgrError> {
+  match raw.as_str() {
+    "none" => Ok(UniqueIds::None),
+    "prefix" => Ok(UniqueIds::Prefix),
+    "use-id" => Ok(UniqueIds::UseId),
+    _ => Err(SvgrError::Configuration(format!(
+      r#""uniqueIds" must be "none", "prefix" or "use-id", got "{}""#,
+      raw
+    ))),
+  }
+}
+
+fn to_fallback_ids(raw: String) -> Result<FallbackIds, SvgrError> {
+  match raw.as_str() {
+    "none" => Ok(FallbackIds::None),
+    "use-id" => Ok(FallbackIds::UseId),
+    "counter" => Ok(FallbackIds::Counter),
+    _ => Err(SvgrError::Configuration(format!(
+      r#""fallbackIds" must be "none", "use-id" or "counter", got "{}""#,
+      raw
+    ))),
+  }
+}
+
+fn to_svgo_config(raw: RuntimeSvgoConfig) -> SvgoConfig {
+  let default = SvgoConfig::default();
+  SvgoConfig {
+    remove_doctype: raw.remove_doctype.unwrap_or(default.remove_doctype),
+    remove_xml_proc_inst: raw
+      .remove_xml_proc_inst
+      .unwrap_or(default.remove_xml_proc_inst),
+    remove_comments: raw.remove_comments.unwrap_or(default.remove_comments),
+    remove_metadata: raw.remove_metadata.unwrap_or(default.remove_metadata),
+    remove_editors_ns_data: raw
+      .remove_editors_ns_data
+      .unwrap_or(default.remove_editors_ns_data),
+    cleanup_attrs: raw.cleanup_attrs.unwrap_or(default.cleanup_attrs),
+    remove_useless_defs: raw
+      .remove_useless_defs
+      .unwrap_or(default.remove_useless_defs),
+    collapse_groups: raw.collapse_groups.unwrap_or(default.collapse_groups),
+    remove_empty_attrs: raw.remove_empty_attrs.unwrap_or(default.remove_empty_attrs),
+    remove_empty_text: raw.remove_empty_text.unwrap_or(default.remove_empty_text),
+    remove_empty_containers: raw
+      .remove_empty_containers
+      .unwrap_or(default.remove_empty_containers),
+  }
+}
+
+fn to_prettier_config(raw: RuntimePrettierConfig) -> Result<PrettierConfig, SvgrError> {
+  let default = PrettierConfig::default();
+  let trailing_comma = match raw.trailing_comma {
+    Some(s) => match s.as_str() {
+      "none" => TrailingComma::None,
+      "es5" => TrailingComma::Es5,
+      "all" => TrailingComma::All,
+      _ => {
+        return Err(SvgrError::Configuration(format!(
+          r#""prettierConfig.trailingComma" must be "none", "es5" or "all", got "{}""#,
+          s
+        )))
+      }
+    },
+    None => default.trailing_comma,
+  };
+  Ok(PrettierConfig {
+    print_width: raw.print_width.unwrap_or(default.print_width),
+    tab_width: raw.tab_width.unwrap_or(default.tab_width),
+    use_tabs: raw.use_tabs.unwrap_or(default.use_tabs),
+    single_quote: raw.single_quote.unwrap_or(default.single_quote),
+    semi: raw.semi.unwrap_or(default.semi),
+    trailing_comma,
+  })
+}
+
+/// Merge the runtime config under the explicit config.
+///
+/// The explicit config always takes precedence: an option of the runtime config is only applied
+/// when the same option of the explicit config still has its default value and is not listed in
+/// `explicit_options`.
+pub fn merge_runtime_config(config: Config, runtime: RuntimeConfig) -> Result<Config, SvgrError> {
+  merge(config, runtime, false)
+}
+
+/// Set the options given in the format of the runtime config files, like the ones of the command
+/// line. They are marked as explicit, so they take precedence over the runtime config file even
+/// when they have their default value.
+pub fn set_options(config: Config, options: RuntimeConfig) -> Result<Config, SvgrError> {
+  merge(config, options, true)
+}
+
+fn merge(config: Config, runtime: RuntimeConfig, explicit: bool) -> Result<Config, SvgrError> {
+  let default = Config::default();
+  let mut config = config;
+
+  macro_rules! merge {
+    ($option:ident, $field:ident, $value:expr) => {
+      if let Some(value) = $value {
+        if explicit {
+          config.$field = value;
+          config.explicit_options.insert(ConfigOption::$option);
+        } else if config.$field == default.$field
+          && !config.explicit_options.contains(&ConfigOption::$option)
+        {
+          config.$field = value;
+        }
+      }
+    };
+  }
+
+  merge!(Ref, r#ref, runtime.r#ref);
+  merge!(TitleProp, title_prop, runtime.title_prop);
+  merge!(DescProp, desc_prop, runtime.desc_prop);
+  merge!(
+    ExpandProps,
+    expand_props,
+    runtime.expand_props.map(to_expand_props).transpose()?
+  );
+  merge!(Dimensions, dimensions, runtime.dimensions);
+  merge!(
+    Icon,
+    icon,
+    runtime.icon.map(|icon| Some(match icon {
+      RuntimeIcon::Bool(b) => Icon::Bool(b),
+      RuntimeIcon::Num(n) => Icon::Num(n),
+      RuntimeIcon::Str(s) => Icon::Str(s),
+    }))
+  );
+  merge!(Native, native, runtime.native);
+  merge!(
+    NativeUnsupported,
+    native_unsupported,
+    runtime
+      .native_unsupported
+      .map(|raw| to_native_element_policy("nativeUnsupported", raw))
+      .transpose()?
+  );
+  merge!(
+    NativeComponents,
+    native_components,
+    runtime.native_components
+  );
+  merge!(
+    NativeImportSource,
+    native_import_source,
+    runtime.native_import_source
+  );
+  merge!(
+    NativeElements,
+    native_elements,
+    runtime
+      .native_elements
+      .map(|elements| {
+        elements
+          .into_iter()
+          .map(|(element, raw)| {
+            let option = format!("nativeElements.{}", element);
+            Ok((element, to_native_element_policy(&option, raw)?))
+          })
+          .collect::<Result<HashMap<_, _>, SvgrError>>()
+      })
+      .transpose()?
+  );
+  merge!(
+    SvgProps,
+    svg_props,
+    runtime.svg_props.map(|svg_props| {
+      let mut svg_props = svg_props
+        .into_iter()
+        .map(|(key, value)| SvgProp { key, value })
+        .collect::<Vec<SvgProp>>();
+      svg_props.sort_by(|a, b| a.key.cmp(&b.key));
+      svg_props
+    })
+  );
+  merge!(Typescript, typescript, runtime.typescript);
+  merge!(Memo, memo, runtime.memo);
+  merge!(
+    ReplaceAttrValues,
+    replace_attr_values,
+    runtime.replace_attr_values.map(Some)
+  );
+  merge!(
+    JsxRuntime,
+    jsx_runtime,
+    runtime.jsx_runtime.map(to_jsx_runtime).transpose()?
+  );
+  merge!(
+    JsxRuntimeImport,
+    jsx_runtime_import,
+    runtime.jsx_runtime_import.map(|raw| Some(JSXRuntimeImport {
+      source: raw.source,
+      namespace: raw.namespace,
+      default_specifier: raw.default_specifier,
+      specifiers: raw.specifiers,
+    }))
+  );
+  merge!(NamedExport, named_export, runtime.named_export);
+  merge!(
+    ExportType,
+    export_type,
+    runtime.export_type.map(to_export_type).transpose()?
+  );
+  merge!(Svgo, svgo, runtime.svgo);
+  merge!(
+    SvgoConfig,
+    svgo_config,
+    runtime.svgo_config.map(to_svgo_config)
+  );
+  merge!(Prettier, prettier, runtime.prettier);
+  merge!(Index, index, runtime.index);
+  merge!(Strict, strict, runtime.strict);
+  merge!(
+    PreserveComments,
+    preserve_comments,
+    runtime.preserve_comments
+  );
+  merge!(InlineStyles, inline_styles, runtime.inline_styles);
+  merge!(CssModules, css_modules, runtime.css_modules);
+  merge!(Target, target, runtime.target.map(to_target).transpose()?);
+  merge!(ElementName, element_name, runtime.element_name.map(Some));
+  merge!(
+    BaseClass,
+    base_class,
+    runtime.base_class.map(|raw| Some(BaseClass {
+      name: raw.name,
+      source: raw.source,
+    }))
+  );
+  merge!(
+    UniqueIds,
+    unique_ids,
+    runtime.unique_ids.map(to_unique_ids).transpose()?
+  );
+  merge!(
+    FallbackIds,
+    fallback_ids,
+    runtime.fallback_ids.map(to_fallback_ids).transpose()?
+  );
+  merge!(
+    PrettierConfig,
+    prettier_config,
+    runtime
+      .prettier_config
+      .map(to_prettier_config)
+      .transpose()?
+  );
+
+  if let Some(source) = runtime.template {
+    if explicit || config.template.is_none() {
+      config.template = Some(Arc::new(StringTemplate::new(source)));
+    }
+    if explicit {
+      config.explicit_options.insert(ConfigOption::Template);
+    }
+  }
+
+  let has_default_plugins = config
+    .plugins
+    .iter()
+    .map(|plugin| plugin.name())
+    .eq(default.plugins.iter().map(|plugin| plugin.name()));
+  if explicit || (has_default_plugins && !config.explicit_options.contains(&ConfigOption::Plugins))
+  {
+    if let Some(names) = runtime.plugins {
+      config.plugins = names
+        .iter()
+        .map(|name| {
+          get_builtin_plugin(name)
+            .ok_or_else(|| SvgrError::Configuration(format!(r#"unknown plugin "{}""#, name)))
+        })
+        .collect::<Result<_, _>>()?;
+    }
+  }
+
+  Ok(config)
+}
+
+/// Apply the runtime config to the config used to transform the file at `file_path`.
+pub fn apply_runtime_config(config: Config, file_path: Option<&str>) -> Result<Config, SvgrError> {
+  if !config.runtime_config {
+    return Ok(config);
+  }
+
+  let runtime = match (&config.config_file, file_path) {
+    (Some(config_file), _) => Some(load_config_file(config_file)?),
+    (None, Some(file_path)) => resolve_config(file_path)?,
+    (None, None) => None,
+  };
+
+  match runtime {
+    Some(runtime) => merge_runtime_config(config, runtime),
+    None => Ok(config),
+  }
+}
+
+#[cfg(test)]
+mod tests {
+  use std::{collections::HashSet, fs};
+
+  use super::*;
+
+  fn create_dir(name: &str, files: &[(&str, &str)]) -> PathBuf {
+    let dir = std::env::temp_dir().join(format!("svgr-rs-runtime-config-{}", name));
+    let _ = fs::remove_dir_all(&dir);
+    fs::create_dir_all(dir.join("icons/nested")).unwrap();
+    for (name, content) in files {
+      fs::write(dir.join(name), content).unwrap();
+    }
+    dir
+  }
+
+  #[test]
+  fn resolves_nearest_json_config() {
+    let dir = create_dir(
+      "json",
+      &[
+        (".svgrrc", r#"{ "icon": true, "expandProps": "start" }"#),
+        ("icons/svgr.config.json", r#"{ "titleProp": true }"#),
+      ],
+    );
+
+    let runtime_config = resolve_config(dir.join("icons/nested/star.svg"))
+      .unwrap()
+      .unwrap();
+    assert_eq!(
+      runtime_config,
+      RuntimeConfig {
+        title_prop: Some(true),
+        ..Default::default()
+      }
+    );
+
+    let runtime_config = resolve_config(dir.join("star.svg")).unwrap().unwrap();
+    assert_eq!(
+      runtime_config,
+      RuntimeConfig {
+        icon: Some(RuntimeIcon::Bool(true)),
+        expand_props: Some(RuntimeExpandProps::Str("start".to_string())),
+        ..Default::default()
+      }
+    );
+  }
+
+  #[test]
+  fn resolves_yaml_and_toml_config() {
+    let dir = create_dir(
+      "yaml-toml",
+      &[
+        (".svgrrc", "native: true\nsvgProps:\n  role: img\n"),
+        ("icons/svgr.config.toml", "memo = true\nicon = 24\n"),
+      ],
+    );
+
+    let runtime_config = resolve_config(dir.join("star.svg")).unwrap().unwrap();
+    assert_eq!(runtime_config.native, Some(true));
+    assert_eq!(
+      runtime_config.svg_props,
+      Some(HashMap::from([("role".to_string(), "img".to_string())]))
+    );
+
+    let runtime_config = resolve_config(dir.join("icons/star.svg")).unwrap().unwrap();
+    assert_eq!(runtime_config.memo, Some(true));
+    assert_eq!(runtime_config.icon, Some(RuntimeIcon::Num(24.0)));
+  }
+
+  #[test]
+  fn resolves_package_json_with_svgr_key_only() {
+    let dir = create_dir(
+      "package-json",
+      &[
+        (
+          "package.json",
+          r#"{ "name": "icons", "svgr": { "ref": true } }"#,
+        ),
+        ("icons/package.json", r#"{ "name": "nested" }"#),
+      ],
+    );
+
+    let runtime_config = resolve_config(dir.join("icons/star.svg")).unwrap().unwrap();
+    assert_eq!(runtime_config.r#ref, Some(true));
+  }
+
+  #[test]
+  fn fails_with_invalid_config_file() {
+    let dir = create_dir("invalid", &[("svgr.config.json", r#"{ "icon": [] }"#)]);
+
+    let err = resolve_config(dir.join("star.svg")).unwrap_err();
+    assert!(err.to_string().contains("failed to load config file"));
+  }
+
+  #[test]
+  fn explicit_config_takes_precedence() {
+    let config = merge_runtime_config(
+      Config {
+        title_prop: true,
+        expand_props: ExpandProps::None,
+        ..Default::default()
+      },
+      RuntimeConfig {
+        title_prop: Some(false),
+        expand_props: Some(RuntimeExpandProps::Str("start".to_string())),
+        desc_prop: Some(true),
+        jsx_runtime: Some("automatic".to_string()),
+        ..Default::default()
+      },
+    )
+    .unwrap();
+
+    assert!(config.title_prop);
+    assert_eq!(config.expand_props, ExpandProps::None);
+    assert!(config.desc_prop);
+    assert_eq!(config.jsx_runtime, JSXRuntime::Automatic);
+  }
+
+  #[test]
+  fn explicit_options_take_precedence_with_their_default_value() {
+    let config = set_options(
+      Default::default(),
+      RuntimeConfig {
+        dimensions: Some(true),
+        jsx_runtime: Some("classic".to_string()),
+        ..Default::default()
+      },
+    )
+    .unwrap();
+    assert_eq!(
+      config.explicit_options,
+      HashSet::from([ConfigOption::Dimensions, ConfigOption::JsxRuntime])
+    );
+
+    let config = merge_runtime_config(
+      config,
+      RuntimeConfig {
+        dimensions: Some(false),
+        jsx_runtime: Some("automatic".to_string()),
+        r#ref: Some(true),
+        ..Default::default()
+      },
+    )
+    .unwrap();
+    assert!(config.dimensions);
+    assert_eq!(config.jsx_runtime, JSXRuntime::Classic);
+    assert!(config.r#ref);
+  }
+
+  #[test]
+  fn caches_the_lookup_of_each_directory() {
+    let dir = create_dir("cache", &[(".svgrrc", "memo: true\n")]);
+
+    resolve_config(dir.join("icons/nested/star.svg")).unwrap();
+    let cache = CONFIG_FILE_CACHE.lock().unwrap();
+    for searched in [dir.join("icons/nested"), dir.join("icons"), dir.clone()] {
+      assert_eq!(
+        cache.get(&searched).map(|cached| &cached.config_file),
+        Some(&dir.join(".svgrrc"))
+      );
+    }
+  }
+
+  #[test]
+  fn invalidates_the_lookup_when_a_directory_changes() {
+    let dir = create_dir("cache-invalidation", &[(".svgrrc", "memo: true\n")]);
+    let file_path = dir.join("icons/star.svg");
+
+    let runtime_config = resolve_config(&file_path).unwrap().unwrap();
+    assert_eq!(runtime_config.memo, Some(true));
+
+    fs::write(dir.join("icons/.svgrrc"), "icon: true\n").unwrap();
+    let runtime_config = resolve_config(&file_path).unwrap().unwrap();
+    assert_eq!(runtime_config.memo, None);
+    assert_eq!(runtime_config.icon, Some(RuntimeIcon::Bool(true)));
+
+    fs::remove_file(dir.join("icons/.svgrrc")).unwrap();
+    let runtime_config = resolve_config(&file_path).unwrap().unwrap();
+    assert_eq!(runtime_config.memo, Some(true));
+  }
+
+  #[test]
+  fn does_not_cache_missing_config_files() {
+    let dir = create_dir("cache-miss", &[]);
+    let file_path = dir.join("icons/star.svg");
+
+    assert!(resolve_config(&file_path).unwrap().is_none());
+    assert!(!CONFIG_FILE_CACHE.lock().unwrap().contains_key(&dir));
+
+    fs::write(dir.join(".svgrrc"), "memo: true\n").unwrap();
+    let runtime_config = resolve_config(&file_path).unwrap().unwrap();
+    assert_eq!(runtime_config.memo, Some(true));
+  }
+
+  #[test]
+  fn serializes_the_options_which_are_set_only() {
+    let runtime_config = RuntimeConfig {
+      title_prop: Some(true),
+      prettier_config: Some(RuntimePrettierConfig {
+        semi: Some(false),
+        ..Default::default()
+      }),
+      ..Default::default()
+    };
+    assert_eq!(
+      serde_json::to_string(&runtime_config).unwrap(),
+      r#"{"titleProp":true,"prettierConfig":{"semi":false}}"#
+    );
+  }
+
+  #[test]
+  fn transforms_with_runtime_config() {
+    let dir = create_dir("transform", &[(".svgrrc.json", r#"{ "icon": true }"#)]);
+    let file_path = dir.join("icons/star.svg").to_string_lossy().to_string();
+
+    let result = crate::transform(
+      r#"<svg width="24" height="24"/>"#.to_string(),
+      Default::default(),
+      crate::State {
+        file_path: Some(file_path.clone()),
+        ..Default::default()
+      },
+    )
+    .unwrap()
+    .code;
+    assert!(result.contains(r#"<svg width="1em" height="1em" {...props}/>"#));
+
+    let result = crate::transform(
+      r#"<svg width="24" height="24"/>"#.to_string(),
+      Config {
+        runtime_config: false,
+        ..Default::default()
+      },
+      crate::State {
+        file_path: Some(file_path),
+        ..Default::default()
+      },
+    )
+    .unwrap()
+    .code;
+    assert!(result.contains(r#"<svg width={24} height={24} {...props}/>"#));
+  }
+
+  #[test]
+  fn uses_config_file_option() {
+    let dir = create_dir(
+      "config-file",
+      &[
+        (".svgrrc", "icon: true\n"),
+        ("custom.toml", "dimensions = false\n"),
+      ],
+    );
+
+    let config = apply_runtime_config(
+      Config {
+        config_file: Some(dir.join("custom.toml").to_string_lossy().to_string()),
+        ..Default::default()
+      },
+      Some(&dir.join("star.svg").to_string_lossy()),
+    )
+    .unwrap();
+    assert!(!config.dimensions);
+    assert_eq!(config.icon, None);
+  }
+
+  #[test]
+  fn fails_with_invalid_option_value() {
+    let err = merge_runtime_config(
+      Default::default(),
+      RuntimeConfig {
+        export_type: Some("both".to_string()),
+        ..Default::default()
+      },
+    )
+    .unwrap_err();
+
+    assert_eq!(
+      err.to_string(),
+      r#"invalid configuration option: "exportType" must be "named" or "default", got "both""#
+    );
+  }
+}
@@ -3,6 +3,8 @@ use std::path::Path;
 use lazy_static::lazy_static;
 use regex::Regex;
 
+use crate::SvgrError;
+
 #[derive(Debug, Clone, Default)]
 pub struct Caller {
   pub name: Option<String>,
@@ -34,9 +36,9 @@ impl Default for Config {
   }
 }
 
+/// The state with the component name derived from the file path, see `expand_state`.
 #[derive(Debug)]
 pub struct InternalConfig {
-  #[allow(dead_code)]
   pub file_path: Option<String>,
   pub component_name: String,
   pub caller: Option<Caller>,
@@ -87,37 +89,56 @@ fn pascal_case(input: &str) -> String {
   uppercase_first_letter(&result)
 }
 
-fn get_component_name(file_path: &str) -> String {
+/// The kebab-case of a PascalCase name, `SvgArrowLeft` gives `svg-arrow-left`.
+pub fn kebab_case(name: &str) -> String {
+  let chars = name.chars().collect::<Vec<char>>();
+  let mut result = String::new();
+  for (index, c) in chars.iter().enumerate() {
+    if c.is_uppercase() && index > 0 {
+      let prev = chars[index - 1];
+      let next_is_lower = chars.get(index + 1).map_or(false, |c| c.is_lowercase());
+      if !prev.is_uppercase() || next_is_lower {
+        result.push('-');
+      }
+    }
+    result.extend(c.to_lowercase());
+  }
+  result
+}
+
+fn get_component_name(file_path: &str) -> Result<String, SvgrError> {
   lazy_static! {
     static ref VALID_CHAR_REGEX_REGEX: Regex = Regex::new(r"[^a-zA-Z0-9 _-]").unwrap();
   }
 
+  let file_prefix = Path::new(file_path)
+    .file_prefix()
+    .and_then(|file_prefix| file_prefix.to_str())
+    .ok_or_else(|| SvgrError::InvalidFilePath {
+      file_path: file_path.to_string(),
+    })?;
   let file_name = VALID_CHAR_REGEX_REGEX
-    .replace_all(
-      Path::new(file_path)
-        .file_prefix()
-        .unwrap()
-        .to_str()
-        .unwrap(),
-      "",
-    )
+    .replace_all(file_prefix, "")
     .to_string();
   let pascal_case_file_name = pascal_case(&file_name);
-  format!("Svg{}", pascal_case_file_name)
+  Ok(format!("Svg{}", pascal_case_file_name))
 }
 
-pub fn expand_state(state: &Config) -> InternalConfig {
-  InternalConfig {
+/// Fill the state, the component name defaults to the PascalCase file name prefixed by `Svg`.
+///
+/// It fails when no component name is given and the file path has no file name, for example `..`.
+pub fn expand_state(state: &Config) -> Result<InternalConfig, SvgrError> {
+  Ok(InternalConfig {
     file_path: state.file_path.clone(),
     component_name: match state.component_name.clone() {
       Some(component_name) => component_name,
       None => match state.file_path.clone() {
         None => "SvgComponent".to_string(),
-        Some(path) => get_component_name(&path),
+        Some(path) => get_component_name(&path)?,
       },
     },
     caller: state.caller.clone(),
-  }
+  })
 }
 
 #[cfg(test)]
@@ -126,7 +147,7 @@ mod tests {
 
   #[test]
   fn test_1() {
-    let internal_config = expand_state(&Default::default());
+    let internal_config = expand_state(&Default::default()).unwrap();
     assert_eq!(internal_config.component_name, "SvgComponent");
   }
 
@@ -137,7 +158,7 @@ mod tests {
       component_name: None,
       caller: None,
     };
-    let internal_config = expand_state(&input);
+    let internal_config = expand_state(&input).unwrap();
     assert_eq!(internal_config.file_path.unwrap(), "hello.svg");
     assert_eq!(internal_config.component_name, "SvgHello");
   }
@@ -149,7 +170,7 @@ mod tests {
       component_name: None,
       caller: None,
     };
-    let internal_config = expand_state(&input);
+    let internal_config = expand_state(&input).unwrap();
     assert_eq!(internal_config.file_path.unwrap(), "hello-you.svg");
     assert_eq!(internal_config.component_name, "SvgHelloYou");
   }
@@ -161,7 +182,7 @@ mod tests {
       component_name: None,
       caller: None,
     };
-    let internal_config = expand_state(&input);
+    let internal_config = expand_state(&input).unwrap();
     assert_eq!(internal_config.file_path.unwrap(), "hello_you.svg");
     assert_eq!(internal_config.component_name, "SvgHelloYou");
   }
@@ -173,7 +194,7 @@ mod tests {
       component_name: None,
       caller: None,
     };
-    let internal_config = expand_state(&input);
+    let internal_config = expand_state(&input).unwrap();
     assert_eq!(internal_config.file_path.unwrap(), "1_big_svg.svg");
     assert_eq!(internal_config.component_name, "Svg1BigSvg");
   }
@@ -185,7 +206,7 @@ mod tests {
       component_name: None,
       caller: None,
     };
-    let internal_config = expand_state(&input);
+    let internal_config = expand_state(&input).unwrap();
     assert_eq!(internal_config.file_path.unwrap(), "a&b~c-d_e.svg");
     assert_eq!(internal_config.component_name, "SvgAbcDE");
   }
@@ -197,7 +218,7 @@ mod tests {
       component_name: None,
       caller: None,
     };
-    let internal_config = expand_state(&input);
+    let internal_config = expand_state(&input).unwrap();
     assert_eq!(internal_config.file_path.unwrap(), "Arrow up.svg");
     assert_eq!(internal_config.component_name, "SvgArrowUp");
   }
@@ -209,8 +230,20 @@ mod tests {
       component_name: Some("MyComponent".to_string()),
       caller: None,
     };
-    let internal_config = expand_state(&input);
+    let internal_config = expand_state(&input).unwrap();
     assert_eq!(internal_config.file_path.unwrap(), "Arrow up.svg");
     assert_eq!(internal_config.component_name, "MyComponent");
   }
+
+  #[test]
+  fn fails_without_file_name() {
+    let input = Config {
+      file_path: Some("..".to_string()),
+      component_name: None,
+      caller: None,
+    };
+    let err = expand_state(&input).unwrap_err();
+    assert_eq!(err.code(), "invalid-file-path");
+    assert_eq!(err.file_path(), Some(".."));
+  }
 }
@@ -0,0 +1,176 @@
+use std::collections::HashSet;
+
+use swc_core::{
+  common::DUMMY_SP,
+  ecma::{ast::*, visit::VisitMut},
+};
+use swc_xml::ast::{Child, Document, Element};
+
+use crate::{
+  core::config::{Config, Target},
+  inline_styles::get_class_names,
+  util::{create_ident, create_tpl_element},
+  SvgrError,
+};
+
+/// The name the CSS module is imported under.
+pub const STYLES: &str = "styles";
+
+/// The CSS of the `<style>` elements, written next to the component as a CSS module.
+pub struct CssModule {
+  pub code: String,
+
+  /// The classes defined by the CSS module, the other ones are kept as is.
+  pub classes: HashSet<String>,
+}
+
+/// Fail when the CSS modules are used by a target whose class names are not JSX attributes.
+pub fn check_target(config: &Config) -> Result<(), SvgrError> {
+  match config.target {
+    Target::React => Ok(()),
+    _ => Err(SvgrError::Configuration(
+      r#""cssModules" is only supported by the "react" target"#.to_string(),
+    )),
+  }
+}
+
+/// The file name of the CSS module of a component.
+pub fn get_file_name(component_name: &str) -> String {
+  format!("{}.module.css", component_name)
+}
+
+fn extract_element(element: &mut Element, css: &mut Vec<String>) {
+  element.children.retain_mut(|child| {
+    let Child::Element(element) = child else {
+      return true;
+    };
+    if element.tag_name != "style" {
+      extract_element(element, css);
+      return true;
+    }
+
+    let mut code = String::new();
+    for child in &element.children {
+      match child {
+        Child::Text(text) => code.push_str(&text.data),
+        Child::CdataSection(cdata) => code.push_str(&cdata.data),
+        _ => {}
+      }
+    }
+    let media = element
+      .attributes
+      .iter()
+      .find(|attr| attr.name == "media")
+      .and_then(|attr| attr.value.as_ref())
+      .map(|media| media.trim())
+      .filter(|media| !media.is_empty() && *media != "all");
+    let code = code.trim();
+    if !code.is_empty() {
+      css.push(match media {
+        Some(media) => format!("@media {} {{\n{}\n}}", media, code),
+        None => code.to_string(),
+      });
+    }
+    false
+  });
+}
+
+/// Remove the `<style>` elements of the document, returns their CSS if there is any.
+pub fn extract(document: &mut Document) -> Option<CssModule> {
+  let mut css = vec![];
+  for child in &mut document.children {
+    if let Child::Element(element) = child {
+      extract_element(element, &mut css);
+    }
+  }
+  if css.is_empty() {
+    return None;
+  }
+
+  let code = format!("{}\n", css.join("\n"));
+  let classes = get_class_names(&code);
+  Some(CssModule { code, classes })
+}
+
+/// `styles["cls-1"]`
+fn create_class_name(class: &str) -> Expr {
+  Expr::Member(MemberExpr {
+    span: DUMMY_SP,
+    obj: Box::new(Expr::Ident(create_ident(STYLES))),
+    prop: MemberProp::Computed(ComputedPropName {
+      span: DUMMY_SP,
+      expr: Box::new(Expr::Lit(Lit::Str(Str {
+        span: DUMMY_SP,
+        value: class.into(),
+        raw: None,
+      }))),
+    }),
+  })
+}
+
+/// Replace the classes defined by the CSS module in a `className` value, a template literal
+/// joins them when there are many.
+fn to_class_names(value: &str, classes: &HashSet<String>) -> Option<Expr> {
+  let names = value.split_whitespace().collect::<Vec<_>>();
+  if !names.iter().any(|name| classes.contains(*name)) {
+    return None;
+  }
+  if let [name] = names.as_slice() {
+    return Some(create_class_name(name));
+  }
+
+  let mut quasis = vec![];
+  let mut exprs = vec![];
+  let mut quasi = String::new();
+  for (i, name) in names.iter().enumerate() {
+    if i > 0 {
+      quasi.push(' ');
+    }
+    if classes.contains(*name) {
+      quasis.push(create_tpl_element(&quasi, false));
+      exprs.push(Box::new(create_class_name(name)));
+      quasi.clear();
+    } else {
+      quasi.push_str(name);
+    }
+  }
+  quasis.push(create_tpl_element(&quasi, true));
+
+  Some(Expr::Tpl(Tpl {
+    span: DUMMY_SP,
+    exprs,
+    quasis,
+  }))
+}
+
+/// Read the classes defined by the CSS module from its `styles` import, like
+/// `className={styles["cls-1"]}`.
+pub struct Visitor<'a> {
+  classes: &'a HashSet<String>,
+}
+
+impl<'a> Visitor<'a> {
+  pub fn new(classes: &'a HashSet<String>) -> Self {
+    Self { classes }
+  }
+}
+
+impl VisitMut for Visitor<'_> {
+  fn visit_mut_jsx_attr(&mut self, n: &mut JSXAttr) {
+    let JSXAttrName::Ident(name) = &n.name else {
+      return;
+    };
+    if name.sym != "className" {
+      return;
+    }
+    let Some(JSXAttrValue::Lit(Lit::Str(value))) = &n.value else {
+      return;
+    };
+    if let Some(expr) = to_class_names(&value.value, self.classes) {
+      n.value = Some(JSXAttrValue::JSXExprContainer(JSXExprContainer {
+        span: DUMMY_SP,
+        expr: JSXExpr::Expr(Box::new(expr)),
+      }));
+    }
+  }
+}
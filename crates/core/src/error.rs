@@ -1,11 +1,222 @@
+use std::fmt;
+
+use swc_core::common::{SourceMap, Span};
 use thiserror::Error;
 
+/// A position in the SVG source code, the line and the column start at 1.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Location {
+  pub line: usize,
+  pub column: usize,
+}
+
+impl fmt::Display for Location {
+  fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+    write!(f, "{}:{}", self.line, self.column)
+  }
+}
+
+/// The part of the SVG source code an error relates to.
+#[derive(Debug, Clone, PartialEq)]
+pub struct SourceSpan {
+  /// The path of the SVG file, from the state of the transformation.
+  pub file_path: Option<String>,
+
+  /// The byte offsets of the start and the end of the span in the source code.
+  pub start: usize,
+  pub end: usize,
+
+  /// The position of the start of the span.
+  pub loc: Location,
+
+  /// The line of the source code with a caret under the start of the span.
+  pub snippet: String,
+}
+
+impl SourceSpan {
+  pub(crate) fn new(cm: &SourceMap, span: Span, file_path: Option<&str>) -> Option<Self> {
+    let loc = cm.try_lookup_char_pos(span.lo).ok()?;
+    let start = (span.lo - loc.file.start_pos).0 as usize;
+    let end = (span.hi.max(span.lo) - loc.file.start_pos).0 as usize;
+    let line = loc
+      .file
+      .get_line(loc.line - 1)
+      .map(|line| line.trim_end().to_string())
+      .unwrap_or_default();
+
+    let gutter = loc.line.to_string();
+    let snippet = format!(
+      "{} | {}\n{} | {}^",
+      gutter,
+      line,
+      " ".repeat(gutter.len()),
+      " ".repeat(loc.col_display)
+    );
+
+    Some(Self {
+      file_path: file_path.map(|file_path| file_path.to_string()),
+      start,
+      end,
+      loc: Location {
+        line: loc.line,
+        column: loc.col.0 + 1,
+      },
+      snippet,
+    })
+  }
+}
+
+impl fmt::Display for SourceSpan {
+  fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+    match &self.file_path {
+      Some(file_path) => write!(f, "{}:{}", file_path, self.loc)?,
+      None => write!(f, "{}", self.loc)?,
+    }
+    write!(f, "\n{}", self.snippet)
+  }
+}
+
+/// An error the XML parser recovered from.
+#[derive(Debug, Clone, PartialEq)]
+pub struct XmlError {
+  pub message: String,
+
+  /// The location of the error, unknown for the errors reported at the end of the document.
+  pub span: Option<SourceSpan>,
+}
+
+fn format_span(span: &Option<SourceSpan>) -> String {
+  match span {
+    Some(span) => format!(" at {}", span),
+    None => String::new(),
+  }
+}
+
+fn format_file_path(file_path: &Option<String>) -> String {
+  match file_path {
+    Some(file_path) => format!(" in {}", file_path),
+    None => String::new(),
+  }
+}
+
+fn format_xml_errors(errors: &[XmlError]) -> String {
+  errors
+    .iter()
+    .map(|error| match &error.span {
+      Some(span) => format!("\n  {}: {}", span.loc, error.message),
+      None => format!("\n  {}", error.message),
+    })
+    .collect()
+}
+
 #[derive(Error, Debug)]
 pub enum SvgrError {
-  #[error("failed to parse SVG: {0}")]
-  Parse(String),
-  #[error("this is invalid SVG")]
-  InvalidSvg,
+  #[error("failed to parse SVG: {message}{}", format_span(.span))]
+  Parse {
+    message: String,
+    span: Option<SourceSpan>,
+  },
+  #[error("empty SVG input{}", format_file_path(.file_path))]
+  EmptyInput { file_path: Option<String> },
+  #[error("no root element found{}", format_file_path(.file_path))]
+  NoRootElement { file_path: Option<String> },
+  #[error("the root element must be <svg>, found <{tag_name}>{}", format_span(.span))]
+  NonSvgRoot {
+    tag_name: String,
+    span: Option<SourceSpan>,
+  },
+  #[error("invalid XML{}:{}", format_file_path(.file_path), format_xml_errors(.errors))]
+  InvalidXml {
+    file_path: Option<String>,
+    errors: Vec<XmlError>,
+  },
+  #[error(
+    "elements not supported by react-native-svg{}: {}",
+    format_file_path(.file_path),
+    .elements.join(", ")
+  )]
+  UnsupportedNativeElements {
+    file_path: Option<String>,
+    elements: Vec<String>,
+  },
+  #[error("cannot derive a component name from the file path \"{file_path}\"")]
+  InvalidFilePath { file_path: String },
   #[error("invalid configuration option: {0}")]
   Configuration(String),
+  #[error("failed to generate source map: {0}")]
+  SourceMap(String),
+  #[error("failed to generate code: {0}")]
+  Codegen(String),
+}
+
+impl SvgrError {
+  /// A stable identifier of the kind of error, for example `non-svg-root`.
+  pub fn code(&self) -> &'static str {
+    match self {
+      SvgrError::Parse { .. } => "parse-error",
+      SvgrError::EmptyInput { .. } => "empty-input",
+      SvgrError::NoRootElement { .. } => "no-root-element",
+      SvgrError::NonSvgRoot { .. } => "non-svg-root",
+      SvgrError::InvalidXml { .. } => "invalid-xml",
+      SvgrError::UnsupportedNativeElements { .. } => "unsupported-native-elements",
+      SvgrError::InvalidFilePath { .. } => "invalid-file-path",
+      SvgrError::Configuration(_) => "invalid-configuration",
+      SvgrError::SourceMap(_) => "source-map-error",
+      SvgrError::Codegen(_) => "codegen-error",
+    }
+  }
+
+  /// The location of the error in the SVG source code, for the invalid XML the one of the first error.
+  pub fn span(&self) -> Option<&SourceSpan> {
+    match self {
+      SvgrError::Parse { span, .. } | SvgrError::NonSvgRoot { span, .. } => span.as_ref(),
+      SvgrError::InvalidXml { errors, .. } => errors.iter().find_map(|error| error.span.as_ref()),
+      _ => None,
+    }
+  }
+
+  /// The path of the SVG file the error occurred in, from the state of the transformation.
+  pub fn file_path(&self) -> Option<&str> {
+    match self {
+      SvgrError::EmptyInput { file_path }
+      | SvgrError::NoRootElement { file_path }
+      | SvgrError::InvalidXml { file_path, .. }
+      | SvgrError::UnsupportedNativeElements { file_path, .. } => file_path.as_deref(),
+      SvgrError::InvalidFilePath { file_path } => Some(file_path),
+      _ => self.span().and_then(|span| span.file_path.as_deref()),
+    }
+  }
+}
+
+#[cfg(test)]
+mod tests {
+  use swc_core::common::{BytePos, FileName};
+
+  use super::*;
+
+  #[test]
+  fn creates_snippet_of_the_offending_line() {
+    let cm = SourceMap::default();
+    let fm = cm.new_source_file(
+      FileName::Anon.into(),
+      "<svg>\n  <g><path/></svg>\n".to_string(),
+    );
+    let lo = fm.start_pos + BytePos(18);
+    let span = SourceSpan::new(&cm, Span::new(lo, lo + BytePos(6)), Some("star.svg")).unwrap();
+
+    assert_eq!(span.start, 18);
+    assert_eq!(span.end, 24);
+    assert_eq!(
+      span.loc,
+      Location {
+        line: 2,
+        column: 13
+      }
+    );
+    assert_eq!(span.snippet, "2 |   <g><path/></svg>\n  |             ^");
+    assert_eq!(
+      span.to_string(),
+      "star.svg:2:13\n2 |   <g><path/></svg>\n  |             ^"
+    );
+  }
 }
@@ -1,104 +1,59 @@
-pub fn decode_xml(s: &str) -> String {
-  let bytes = s.as_bytes();
+const NAMED_ENTITIES: [(&str, char); 5] = [
+  ("gt;", '>'),
+  ("lt;", '<'),
+  ("amp;", '&'),
+  ("apos;", '\''),
+  ("quot;", '"'),
+];
+
+/// Decode the numeric entity at the start of `s`, after the "&#".
+/// Returns the decoded character and the length of the entity, or `None` when it is invalid.
+fn decode_numeric_entity(s: &str) -> Option<(char, usize)> {
+  let (digits, radix, prefix_len) = match s.strip_prefix(['x', 'X']) {
+    Some(rest) => (rest, 16, 1),
+    None => (s, 10, 0),
+  };
+  let len = digits
+    .find(|c: char| !c.is_digit(radix))
+    .unwrap_or(digits.len());
+  if len == 0 || !digits[len..].starts_with(';') {
+    return None;
+  }
 
-  let mut ret = String::with_capacity(s.len());
-  let mut cur_idx = 0;
-  let mut last_idx = 0;
+  let c = u32::from_str_radix(&digits[..len], radix)
+    .ok()
+    .and_then(char::from_u32)?;
+  Some((c, prefix_len + len + 1))
+}
 
-  while cur_idx < bytes.len() {
-    if bytes[cur_idx] != b'&' {
-      cur_idx += 1;
-      continue;
-    }
+pub fn decode_xml(s: &str) -> String {
+  let mut ret = String::with_capacity(s.len());
+  let mut rest = s;
 
-    ret.push_str(&s[last_idx..cur_idx]);
-    last_idx = cur_idx;
+  while let Some(idx) = rest.find('&') {
+    ret.push_str(&rest[..idx]);
     // Skip the "&"
-    cur_idx += 1;
-
-    // If we have a numeric entity, handle this separately.
-    if bytes[cur_idx] == b'#' {
-      // Skip the leading "&#". For hex entities, also skip the leading "x".
-      cur_idx += 1;
-
-      let mut start = cur_idx;
-      let mut radix = 10;
-
-      if bytes[start].to_ascii_lowercase() == b'x' {
-        radix = 16;
-        cur_idx += 1;
-        start += 1;
-      }
-
-      while (bytes[cur_idx] >= b'0' && bytes[cur_idx] <= b'9')
-        || (radix == 16
-          && bytes[cur_idx].to_ascii_lowercase() >= b'a'
-          && bytes[cur_idx].to_ascii_lowercase() <= b'f')
-      {
-        cur_idx += 1;
-      }
-
-      if start != cur_idx {
-        let entity = &s[start..cur_idx];
-        if bytes[cur_idx] != b';' {
-          continue;
-        }
-
-        cur_idx += 1;
-        last_idx = cur_idx;
-
-        let parsed = u32::from_str_radix(entity, radix).unwrap();
-        ret.push(char::from_u32(parsed).unwrap());
-      }
-
-      continue;
-    }
-
-    // &gt;
-    // &lt;
-    if bytes.len() - cur_idx > 4 {
-      match &s[cur_idx..cur_idx + 4] {
-        "gt;" => {
-          ret.push('>');
-          cur_idx += 3;
-          last_idx = cur_idx;
-        }
-        "lt;" => {
-          ret.push('<');
-          cur_idx += 3;
-          last_idx = cur_idx;
-        }
-        _ => {}
-      }
-    }
-
-    // &amp;
-    if bytes.len() - cur_idx > 5 && s[cur_idx..cur_idx + 4].to_string() == "amp;" {
-      ret.push('&');
-      cur_idx += 4;
-      last_idx = cur_idx;
-    }
-
-    // &apos;
-    // &quot;
-    if bytes.len() - cur_idx > 6 {
-      match s[cur_idx..cur_idx + 4].into() {
-        "apos;" => {
-          ret.push('\'');
-          cur_idx += 5;
-          last_idx = cur_idx;
-        }
-        "quot;" => {
-          ret.push('\\');
-          cur_idx += 5;
-          last_idx = cur_idx;
-        }
-        _ => {}
+    rest = &rest[idx + 1..];
+
+    let decoded = match rest.strip_prefix('#') {
+      Some(entity) => decode_numeric_entity(entity).map(|(c, len)| (c, len + 1)),
+      None => NAMED_ENTITIES
+        .iter()
+        .find(|(name, _)| rest.starts_with(name))
+        .map(|(name, c)| (*c, name.len())),
+    };
+
+    match decoded {
+      Some((c, len)) => {
+        ret.push(c);
+        rest = &rest[len..];
       }
+      // Keep the invalid entities as is
+      None => ret.push('&'),
     }
   }
 
-  ret + &s[last_idx..]
+  ret + rest
 }
 
 #[cfg(test)]
@@ -116,6 +71,24 @@ mod tests {
       ("&#x3a;", ":"),
       ("&>", "&>"),
       ("id=770&#anchor", "id=770&#anchor"),
+      ("&lt;path&gt;", "<path>"),
+      ("&apos;a&quot;", "'a\""),
+    ];
+    test_cases.into_iter().for_each(|(input, expected)| {
+      assert_eq!(decode_xml(input), expected);
+    });
+  }
+
+  #[test]
+  fn keeps_invalid_entities() {
+    let test_cases = vec![
+      ("a&", "a&"),
+      ("a&#", "a&#"),
+      ("a&#x", "a&#x"),
+      ("&#38", "&#38"),
+      ("&#xD800;", "&#xD800;"),
+      ("&#99999999999;", "&#99999999999;"),
+      ("&é;", "&é;"),
     ];
     test_cases.into_iter().for_each(|(input, expected)| {
       assert_eq!(decode_xml(input), expected);
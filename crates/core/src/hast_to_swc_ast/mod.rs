@@ -4,7 +4,10 @@ use lazy_static::lazy_static;
 use regex::{Captures, Regex};
 use swc_core::common::SyntaxContext;
 use swc_core::{
-  common::DUMMY_SP,
+  common::{
+    comments::{Comment, CommentKind, Comments},
+    Span, DUMMY_SP,
+  },
   ecma::{ast::*, atoms::JsWord},
 };
 use swc_xml::visit::{Visit, VisitWith};
@@ -12,12 +15,13 @@ use swc_xml::visit::{Visit, VisitWith};
 mod decode_xml;
 mod mappings;
 mod string_to_object_style;
-mod util;
+pub mod util;
 
 use self::decode_xml::*;
 use self::mappings::ATTR_MAPPINGS;
 use self::string_to_object_style::*;
 use self::util::*;
+use crate::core::config::Target;
 
 fn kebab_case(str: &str) -> Cow<str> {
   lazy_static! {
@@ -42,21 +46,21 @@ fn replace_spaces(s: &str) -> Cow<str> {
   SPACES_REGEX.replace_all(s, |_: &Captures| " ")
 }
 
-fn get_value(attr_name: &str, value: &JsWord) -> JSXAttrValue {
+fn get_value(attr_name: &str, value: &JsWord, span: Span) -> JSXAttrValue {
   if attr_name == "style" {
     let style = string_to_object_style(value);
 
     return JSXAttrValue::JSXExprContainer(JSXExprContainer {
-      span: DUMMY_SP,
+      span,
       expr: JSXExpr::Expr(Box::new(style)),
     });
   }
 
   if is_numeric(value) {
     return JSXAttrValue::JSXExprContainer(JSXExprContainer {
-      span: DUMMY_SP,
+      span,
       expr: JSXExpr::Expr(Box::new(Expr::Lit(Lit::Num(Number {
-        span: DUMMY_SP,
+        span,
         value: value.parse().unwrap(),
         raw: None,
       })))),
@@ -64,7 +68,7 @@ fn get_value(attr_name: &str, value: &JsWord) -> JSXAttrValue {
   }
 
   JSXAttrValue::Lit(Lit::Str(Str {
-    span: DUMMY_SP,
+    span,
     value: replace_spaces(value).into(),
     raw: None,
   }))
@@ -81,25 +85,57 @@ fn text(n: &swc_xml::ast::Text) -> Option<JSXElementChild> {
   }
 
   Some(JSXElementChild::JSXExprContainer(JSXExprContainer {
-    span: DUMMY_SP,
+    span: n.span,
     expr: JSXExpr::Expr(Box::new(Expr::Lit(Lit::Str(Str {
-      span: DUMMY_SP,
+      span: n.span,
       value: decode_xml(value).into(),
       raw: None,
     })))),
   }))
 }
 
-pub struct HastVisitor {
+/// The value of an attribute kept as in the SVG, for the targets rendering markup or SolidJS JSX.
+fn get_svg_value(attr_name: &str, value: &JsWord, span: Span, kebab_style: bool) -> JSXAttrValue {
+  if kebab_style && attr_name == "style" {
+    return JSXAttrValue::JSXExprContainer(JSXExprContainer {
+      span,
+      expr: JSXExpr::Expr(Box::new(string_to_kebab_object_style(value))),
+    });
+  }
+
+  JSXAttrValue::Lit(Lit::Str(Str {
+    span,
+    value: replace_spaces(value).into(),
+    raw: None,
+  }))
+}
+
+pub struct HastVisitor<'a> {
   jsx: Option<JSXElement>,
-  attr_mappings: &'static HashMap<&'static str, &'static str>,
+  /// The React names of the attributes, `None` to keep the SVG names.
+  attr_mappings: Option<&'static HashMap<&'static str, &'static str>>,
+  /// Whether the style is converted into an object with kebab-case keys, for SolidJS.
+  kebab_style: bool,
+  /// Where the comments of the SVG are kept, they are dropped when `None`.
+  comments: Option<&'a dyn Comments>,
 }
 
-impl HastVisitor {
-  fn new() -> Self {
+impl<'a> HastVisitor<'a> {
+  fn new(target: &Target, comments: Option<&'a dyn Comments>) -> Self {
+    let attr_mappings = match target {
+      Target::React => Some(&*ATTR_MAPPINGS),
+      Target::Vue
+      | Target::VueSfc
+      | Target::Solid
+      | Target::Svelte
+      | Target::Lit
+      | Target::WebComponent => None,
+    };
     Self {
       jsx: None,
-      attr_mappings: &ATTR_MAPPINGS,
+      attr_mappings,
+      kebab_style: *target == Target::Solid,
+      comments,
     }
   }
 
@@ -112,10 +148,18 @@ impl HastVisitor {
       .attributes
       .iter()
       .map(|attr| {
-        let value = attr.value.as_ref().map(|v| get_value(&attr.name, v));
+        let value = attr.value.as_ref().map(|v| match self.attr_mappings {
+          Some(_) => get_value(&attr.name, v, attr.span),
+          None => get_svg_value(&attr.name, v, attr.span, self.kebab_style),
+        });
+        let mut key = match self.attr_mappings {
+          Some(_) => self.get_key(&attr.name, &n.tag_name),
+          None => Ident::new(attr.name.clone(), DUMMY_SP, SyntaxContext::empty()),
+        };
+        key.span = attr.span;
         JSXAttrOrSpread::JSXAttr(JSXAttr {
-          span: DUMMY_SP,
-          name: JSXAttrName::Ident(self.get_key(&attr.name, &n.tag_name).into()),
+          span: attr.span,
+          name: JSXAttrName::Ident(key.into()),
           value,
         })
       })
@@ -123,14 +167,14 @@ impl HastVisitor {
 
     let name = JSXElementName::Ident(Ident::new(
       n.tag_name.clone(),
-      DUMMY_SP,
+      n.span,
       SyntaxContext::empty(),
     ));
     let children = self.all(&n.children);
 
     let closing = if !children.is_empty() {
       Some(JSXClosingElement {
-        span: DUMMY_SP,
+        span: n.span,
         name: name.clone(),
       })
     } else {
@@ -138,7 +182,7 @@ impl HastVisitor {
     };
 
     let opening = JSXOpeningElement {
-      span: DUMMY_SP,
+      span: n.span,
       name,
       attrs,
       self_closing: children.is_empty(),
@@ -146,7 +190,7 @@ impl HastVisitor {
     };
 
     JSXElement {
-      span: DUMMY_SP,
+      span: n.span,
       opening,
       children,
       closing,
@@ -161,11 +205,22 @@ impl HastVisitor {
           Some(JSXElementChild::JSXElement(Box::new(self.element(e))))
         }
         swc_xml::ast::Child::Text(t) => text(t),
+        swc_xml::ast::Child::Comment(c) => self.comment(c),
         _ => None,
       })
       .collect()
   }
 
+  /// An empty expression container, `{/* */}` once its comment is printed.
+  fn comment(&self, n: &swc_xml::ast::Comment) -> Option<JSXElementChild> {
+    let comments = self.comments?;
+    comments.add_leading(n.span.lo, to_block_comment(n));
+    Some(JSXElementChild::JSXExprContainer(JSXExprContainer {
+      span: n.span,
+      expr: JSXExpr::JSXEmptyExpr(JSXEmptyExpr { span: n.span }),
+    }))
+  }
+
   fn get_key(&self, attr_name: &str, tag_name: &str) -> Ident {
     let lower_case_name = attr_name.to_lowercase();
     let rc_key = {
@@ -193,7 +248,9 @@ impl HastVisitor {
       };
     }
 
-    let mapped_attr = self.attr_mappings.get(lower_case_name.as_str());
+    let mapped_attr = self
+      .attr_mappings
+      .and_then(|attr_mappings| attr_mappings.get(lower_case_name.as_str()));
     if let Some(k) = mapped_attr {
       return Ident {
         span: DUMMY_SP,
@@ -232,14 +289,56 @@ impl HastVisitor {
   }
 }
 
-impl Visit for HastVisitor {
+impl Visit for HastVisitor<'_> {
   fn visit_element(&mut self, n: &swc_xml::ast::Element) {
     self.jsx = Some(self.element(n));
   }
 }
 
-pub fn to_swc_ast(hast: swc_xml::ast::Document) -> Option<JSXElement> {
-  let mut v = HastVisitor::new();
+/// The root element of the document, if any.
+pub fn get_root_element(hast: &swc_xml::ast::Document) -> Option<&swc_xml::ast::Element> {
+  hast.children.iter().find_map(|child| match child {
+    swc_xml::ast::Child::Element(element) => Some(element),
+    _ => None,
+  })
+}
+
+/// Whether the element is an `<svg>` element, with or without namespace prefix.
+pub fn is_svg_element(element: &swc_xml::ast::Element) -> bool {
+  let tag_name = element.tag_name.as_str();
+  let local_name = tag_name.rsplit(':').next().unwrap_or(tag_name);
+  local_name.eq_ignore_ascii_case("svg")
+}
+
+/// A block comment with the text of an XML comment, `*/` can not end it early.
+fn to_block_comment(n: &swc_xml::ast::Comment) -> Comment {
+  Comment {
+    kind: CommentKind::Block,
+    span: n.span,
+    text: n.data.replace("*/", "* /").into(),
+  }
+}
+
+/// The comments of the document outside the root element, like a license header.
+pub fn get_document_comments(hast: &swc_xml::ast::Document) -> Vec<Comment> {
+  hast
+    .children
+    .iter()
+    .filter_map(|child| match child {
+      swc_xml::ast::Child::Comment(comment) => Some(to_block_comment(comment)),
+      _ => None,
+    })
+    .collect()
+}
+
+/// Convert the root element of the document into JSX, its comments are added to `comments`
+/// when it is given.
+pub fn to_swc_ast(
+  hast: swc_xml::ast::Document,
+  target: &Target,
+  comments: Option<&dyn Comments>,
+) -> Option<JSXElement> {
+  let mut v = HastVisitor::new(target, comments);
   hast.visit_with(&mut v);
   v.take_jsx()
 }
@@ -257,11 +356,11 @@ mod tests {
 
   use super::*;
 
-  fn transform(cm: Rc<SourceMap>, fm: Rc<SourceFile>, minify: bool) -> String {
+  fn transform(cm: Rc<SourceMap>, fm: Rc<SourceFile>, target: &Target, minify: bool) -> String {
     let mut errors = vec![];
     let doc = parse_file_as_document(fm.borrow(), Default::default(), &mut errors).unwrap();
 
-    let jsx = to_swc_ast(doc).unwrap();
+    let jsx = to_swc_ast(doc, target, None).unwrap();
 
     let mut buf = vec![];
 
@@ -292,7 +391,7 @@ mod tests {
     let cm = Rc::<SourceMap>::default();
     let fm = cm.load_file(&input).expect("failed to load fixture file");
 
-    let res = transform(cm, fm, false);
+    let res = transform(cm, fm, &Target::React, false);
 
     NormalizedOutput::from(res)
       .compare_to_file(jsx_path)
@@ -300,10 +399,14 @@ mod tests {
   }
 
   fn code_test(input: &str, expected: &str) {
+    target_code_test(input, &Target::React, expected);
+  }
+
+  fn target_code_test(input: &str, target: &Target, expected: &str) {
     let cm = Rc::<SourceMap>::default();
     let fm = cm.new_source_file(FileName::Anon.into(), input.to_string());
 
-    let res = transform(cm, fm, true);
+    let res = transform(cm, fm, target, true);
 
     assert_eq!(res, expected)
   }
@@ -353,6 +456,14 @@ mod tests {
     );
   }
 
+  #[test]
+  fn keeps_non_ascii_digits_as_strings() {
+    code_test(
+      r#"<svg width="١٢"><path style="width:١px"/></svg>"#,
+      r#"<svg width="١٢"><path style={{width:"١px"}}/></svg>;"#,
+    );
+  }
+
   #[test]
   fn transforms_class() {
     code_test(
@@ -360,4 +471,13 @@ mod tests {
       r#"<svg><path className="icon"/></svg>;"#,
     );
   }
+
+  #[test]
+  fn keeps_svg_attributes_and_kebab_case_style_for_solid() {
+    target_code_test(
+      r#"<svg class="icon"><path stroke-width="2" style="--index: 1; Font-Size: 24px;"/></svg>"#,
+      &Target::Solid,
+      r#"<svg class="icon"><path stroke-width="2"style={{"--index":"1","font-size":"24px"}}/></svg>;"#,
+    );
+  }
 }
@@ -38,7 +38,7 @@ pub fn format_key(key: &str) -> PropName {
 
 fn is_convertible_pixel_value(s: &str) -> bool {
   lazy_static! {
-    static ref PX_REGEX: Regex = Regex::new(r#"^\d+px$"#).unwrap();
+    static ref PX_REGEX: Regex = Regex::new(r#"^[0-9]+px$"#).unwrap();
   }
   PX_REGEX.is_match(s)
 }
@@ -69,6 +69,24 @@ pub fn format_value(value: &str) -> Expr {
 }
 
 pub fn string_to_object_style(raw_style: &str) -> Expr {
+  to_object_style(raw_style, |key, value| {
+    (format_key(key), format_value(value))
+  })
+}
+
+/// Convert the style into an object with the CSS property names as keys and string values,
+/// as expected by SolidJS.
+pub fn string_to_kebab_object_style(raw_style: &str) -> Expr {
+  to_object_style(raw_style, |key, value| {
+    let key = match key.starts_with("--") {
+      true => key.to_string(),
+      false => key.to_lowercase(),
+    };
+    (PropName::Str(key.into()), Expr::Lit(Lit::Str(value.into())))
+  })
+}
+
+fn to_object_style(raw_style: &str, format: impl Fn(&str, &str) -> (PropName, Expr)) -> Expr {
   let entries = raw_style.split(';');
 
   let properties = entries
@@ -82,8 +100,7 @@ pub fn string_to_object_style(raw_style: &str) -> Expr {
       let first_colon = style.find(':');
       match first_colon {
         Some(i) => {
-          let value = format_value(style[(i + 1)..].trim());
-          let key = format_key(style[..i].trim());
+          let (key, value) = format(style[..i].trim(), style[(i + 1)..].trim());
 
           Some(PropOrSpread::Prop(Box::new(Prop::KeyValue(KeyValueProp {
             key,
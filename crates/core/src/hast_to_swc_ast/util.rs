@@ -3,7 +3,7 @@ use regex::Regex;
 
 pub fn is_numeric(s: &str) -> bool {
   lazy_static! {
-    static ref NUMERIC_REGEX: Regex = Regex::new(r#"^(\-|\+)?\d+(\.\d+)?$"#).unwrap();
+    static ref NUMERIC_REGEX: Regex = Regex::new(r#"^(\-|\+)?[0-9]+(\.[0-9]+)?$"#).unwrap();
   }
   NUMERIC_REGEX.is_match(s)
 }
@@ -0,0 +1,242 @@
+use std::{fmt, path::Path};
+
+use swc_core::{common::DUMMY_SP, ecma::ast::*};
+
+use crate::{
+  core::config::ExportType, print_module, util::create_ident, Config, SvgrError, Target,
+};
+
+/// A generated component file, re-exported by the index file.
+#[derive(Debug, Clone)]
+pub struct IndexFile {
+  /// The path of the generated file, relative to the directory of the index file.
+  pub path: String,
+
+  /// The name of the component, usually derived from the SVG file path by `expand_state`.
+  pub component_name: String,
+}
+
+/// A template used to create the index file re-exporting the generated components.
+///
+/// It is implemented for closures, so a callback can be used as a custom template.
+///
+/// # Examples
+///
+/// ```rust
+/// use std::sync::Arc;
+///
+/// use svgr_rs::{Config, IndexFile, SvgrError};
+///
+/// let config = Config {
+///   index_template: Some(Arc::new(|files: &[IndexFile], _config: &Config| {
+///     Ok::<String, SvgrError>(
+///       files
+///         .iter()
+///         .map(|file| format!("export * from './{}';\n", file.path))
+///         .collect(),
+///     )
+///   })),
+///   ..Default::default()
+/// };
+/// ```
+pub trait IndexTemplate: Send + Sync {
+  fn render(&self, files: &[IndexFile], config: &Config) -> Result<String, SvgrError>;
+}
+
+impl<F> IndexTemplate for F
+where
+  F: Fn(&[IndexFile], &Config) -> Result<String, SvgrError> + Send + Sync,
+{
+  fn render(&self, files: &[IndexFile], config: &Config) -> Result<String, SvgrError> {
+    self(files, config)
+  }
+}
+
+impl fmt::Debug for dyn IndexTemplate {
+  fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+    f.write_str("IndexTemplate")
+  }
+}
+
+/// The index template used when no custom index template is specified,
+/// it re-exports the default or the named export of each file under its component name.
+pub struct DefaultIndexTemplate;
+
+/// The import path of a file: relative to the index, with forward slashes and without the
+/// extension of the JavaScript files, the other ones like `.vue` must be imported with their extension.
+fn get_import_path(path: &str) -> String {
+  let path = Path::new(path);
+  let is_script = matches!(
+    path.extension().and_then(|ext| ext.to_str()),
+    Some("js" | "jsx" | "ts" | "tsx" | "mjs" | "cjs" | "mts" | "cts")
+  );
+  let path = if is_script {
+    path.with_extension("")
+  } else {
+    path.to_path_buf()
+  };
+  let path = path.to_string_lossy().replace('\\', "/");
+  if path.starts_with("./") || path.starts_with("../") {
+    path
+  } else {
+    format!("./{}", path)
+  }
+}
+
+impl IndexTemplate for DefaultIndexTemplate {
+  fn render(&self, files: &[IndexFile], config: &Config) -> Result<String, SvgrError> {
+    // The single-file and Svelte components only have a default export.
+    let export_name = match (&config.target, &config.export_type) {
+      (Target::VueSfc | Target::Svelte, _) | (_, ExportType::Default) => "default",
+      (_, ExportType::Named) => config.named_export.as_str(),
+    };
+
+    let body = files
+      .iter()
+      .map(|file| {
+        ModuleItem::ModuleDecl(ModuleDecl::ExportNamed(NamedExport {
+          span: DUMMY_SP,
+          specifiers: vec![ExportSpecifier::Named(ExportNamedSpecifier {
+            span: DUMMY_SP,
+            orig: ModuleExportName::Ident(create_ident(export_name)),
+            exported: Some(ModuleExportName::Ident(create_ident(&file.component_name))),
+            is_type_only: false,
+          })],
+          src: Some(Box::new(Str {
+            span: DUMMY_SP,
+            value: get_import_path(&file.path).into(),
+            raw: None,
+          })),
+          type_only: false,
+          with: None,
+        }))
+      })
+      .collect();
+
+    let module = Module {
+      span: DUMMY_SP,
+      body,
+      shebang: None,
+    };
+    print_module(Default::default(), &module, config, None)
+  }
+}
+
+/// Create the code of the index file re-exporting the generated components,
+/// with the custom index template of the config if any.
+///
+/// # Examples
+///
+/// ```rust
+/// use svgr_rs::{create_index, IndexFile};
+///
+/// let code = create_index(
+///   &[IndexFile {
+///     path: "Star.js".to_string(),
+///     component_name: "SvgStar".to_string(),
+///   }],
+///   &Default::default(),
+/// )
+/// .unwrap();
+/// assert_eq!(code, "export { default as SvgStar } from \"./Star\";\n");
+/// ```
+pub fn create_index(files: &[IndexFile], config: &Config) -> Result<String, SvgrError> {
+  match &config.index_template {
+    Some(template) => template.render(files, config),
+    None => DefaultIndexTemplate.render(files, config),
+  }
+}
+
+#[cfg(test)]
+mod tests {
+  use std::sync::Arc;
+
+  use super::*;
+
+  fn files() -> Vec<IndexFile> {
+    vec![
+      IndexFile {
+        path: "ArrowLeft.js".to_string(),
+        component_name: "SvgArrowLeft".to_string(),
+      },
+      IndexFile {
+        path: "nested/Star.tsx".to_string(),
+        component_name: "SvgStar".to_string(),
+      },
+    ]
+  }
+
+  #[test]
+  fn re_exports_default_exports() {
+    assert_eq!(
+      create_index(&files(), &Default::default()).unwrap(),
+      r#"export { default as SvgArrowLeft } from "./ArrowLeft";
+export { default as SvgStar } from "./nested/Star";
+"#
+    );
+  }
+
+  #[test]
+  fn re_exports_named_exports() {
+    let config = Config {
+      export_type: ExportType::Named,
+      named_export: "Icon".to_string(),
+      ..Default::default()
+    };
+    assert_eq!(
+      create_index(&files(), &config).unwrap(),
+      r#"export { Icon as SvgArrowLeft } from "./ArrowLeft";
+export { Icon as SvgStar } from "./nested/Star";
+"#
+    );
+  }
+
+  #[test]
+  fn re_exports_single_file_components_with_extension() {
+    let config = Config {
+      target: Target::VueSfc,
+      export_type: ExportType::Named,
+      ..Default::default()
+    };
+    let files = [IndexFile {
+      path: "Star.vue".to_string(),
+      component_name: "SvgStar".to_string(),
+    }];
+    assert_eq!(
+      create_index(&files, &config).unwrap(),
+      "export { default as SvgStar } from \"./Star.vue\";\n"
+    );
+  }
+
+  #[test]
+  fn formats_with_prettier() {
+    let config = Config {
+      prettier: true,
+      ..Default::default()
+    };
+    assert_eq!(
+      create_index(&files()[..1], &config).unwrap(),
+      "export { default as SvgArrowLeft } from \"./ArrowLeft\";\n"
+    );
+  }
+
+  #[test]
+  fn supports_custom_index_template() {
+    let config = Config {
+      index_template: Some(Arc::new(|files: &[IndexFile], _: &Config| {
+        Ok(
+          files
+            .iter()
+            .map(|file| file.component_name.clone())
+            .collect::<Vec<String>>()
+            .join(","),
+        )
+      })),
+      ..Default::default()
+    };
+    assert_eq!(
+      create_index(&files(), &config).unwrap(),
+      "SvgArrowLeft,SvgStar"
+    );
+  }
+}
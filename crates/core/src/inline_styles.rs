@@ -0,0 +1,638 @@
+use std::{borrow::Cow, collections::HashSet};
+
+use lazy_static::lazy_static;
+use regex::Regex;
+use swc_core::common::DUMMY_SP;
+use swc_xml::ast::{Child, Document, Element, Text};
+
+use crate::util::{create_attr, get_attr};
+
+// From https://www.w3.org/TR/SVG11/propidx.html, without `transform` whose CSS syntax differs.
+const PRESENTATION_ATTRS: [&str; 58] = [
+  "alignment-baseline",
+  "baseline-shift",
+  "clip-path",
+  "clip-rule",
+  "clip",
+  "color-interpolation-filters",
+  "color-interpolation",
+  "color-profile",
+  "color-rendering",
+  "color",
+  "cursor",
+  "direction",
+  "display",
+  "dominant-baseline",
+  "enable-background",
+  "fill-opacity",
+  "fill-rule",
+  "fill",
+  "filter",
+  "flood-color",
+  "flood-opacity",
+  "font-family",
+  "font-size-adjust",
+  "font-size",
+  "font-stretch",
+  "font-style",
+  "font-variant",
+  "font-weight",
+  "glyph-orientation-horizontal",
+  "glyph-orientation-vertical",
+  "image-rendering",
+  "kerning",
+  "letter-spacing",
+  "lighting-color",
+  "marker-end",
+  "marker-mid",
+  "marker-start",
+  "mask",
+  "opacity",
+  "overflow",
+  "pointer-events",
+  "shape-rendering",
+  "stop-color",
+  "stop-opacity",
+  "stroke-dasharray",
+  "stroke-dashoffset",
+  "stroke-linecap",
+  "stroke-linejoin",
+  "stroke-miterlimit",
+  "stroke-opacity",
+  "stroke-width",
+  "stroke",
+  "text-anchor",
+  "text-decoration",
+  "text-rendering",
+  "unicode-bidi",
+  "visibility",
+  "word-spacing",
+];
+
+#[derive(Debug, Clone, PartialEq)]
+struct Declaration {
+  name: String,
+  value: String,
+  important: bool,
+}
+
+/// A compound selector like `path.cls-1`, `*` has no tag name.
+#[derive(Debug, Default)]
+struct Compound {
+  tag_name: Option<String>,
+  ids: Vec<String>,
+  classes: Vec<String>,
+}
+
+impl Compound {
+  fn matches(&self, element: &Element) -> bool {
+    if let Some(tag_name) = &self.tag_name {
+      if element.tag_name != *tag_name {
+        return false;
+      }
+    }
+    let id = get_attr(element, "id");
+    let classes = get_attr(element, "class").unwrap_or_default();
+    self.ids.iter().all(|name| id == Some(name.as_str()))
+      && self
+        .classes
+        .iter()
+        .all(|name| classes.split_whitespace().any(|class| class == name))
+  }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq)]
+enum Combinator {
+  Descendant,
+  Child,
+}
+
+/// A complex selector, its compounds go from the subject to the left-most one, each with the
+/// combinator to the next one.
+#[derive(Debug)]
+struct Selector {
+  compounds: Vec<(Compound, Combinator)>,
+}
+
+impl Selector {
+  /// The number of ids, classes and tag names, compared in this order.
+  fn specificity(&self) -> (usize, usize, usize) {
+    self
+      .compounds
+      .iter()
+      .fold((0, 0, 0), |(ids, classes, tag_names), (compound, _)| {
+        (
+          ids + compound.ids.len(),
+          classes + compound.classes.len(),
+          tag_names + usize::from(compound.tag_name.is_some()),
+        )
+      })
+  }
+
+  fn matches(&self, element: &Element, ancestors: &[&Element]) -> bool {
+    matches_compounds(&self.compounds, element, ancestors)
+  }
+}
+
+fn matches_compounds(
+  compounds: &[(Compound, Combinator)],
+  element: &Element,
+  ancestors: &[&Element],
+) -> bool {
+  let Some(((compound, combinator), rest)) = compounds.split_first() else {
+    return true;
+  };
+  if !compound.matches(element) {
+    return false;
+  }
+  if rest.is_empty() {
+    return true;
+  }
+  match combinator {
+    Combinator::Child => match ancestors.split_last() {
+      Some((parent, ancestors)) => matches_compounds(rest, parent, ancestors),
+      None => false,
+    },
+    Combinator::Descendant => (0..ancestors.len())
+      .rev()
+      .any(|i| matches_compounds(rest, ancestors[i], &ancestors[..i])),
+  }
+}
+
+struct Rule {
+  selectors: Vec<Selector>,
+  declarations: Vec<Declaration>,
+}
+
+/// The rules which can be inlined, and the CSS of the ones which can not, like at-rules and the
+/// rules with pseudo-classes or attribute selectors.
+#[derive(Default)]
+struct Stylesheet {
+  rules: Vec<Rule>,
+  rest: Vec<String>,
+}
+
+fn remove_comments(css: &str) -> Cow<str> {
+  lazy_static! {
+    static ref COMMENT_REGEX: Regex = Regex::new(r"(?s)/\*.*?\*/").unwrap();
+  }
+  COMMENT_REGEX.replace_all(css, "")
+}
+
+/// The class names used by the selectors of the CSS.
+pub(crate) fn get_class_names(css: &str) -> HashSet<String> {
+  lazy_static! {
+    static ref CLASS_REGEX: Regex = Regex::new(r"\.(-?[_a-zA-Z][_a-zA-Z0-9-]*)").unwrap();
+  }
+  CLASS_REGEX
+    .captures_iter(&remove_comments(css))
+    .map(|caps| caps[1].to_string())
+    .collect()
+}
+
+fn parse_stylesheet(css: &str) -> Stylesheet {
+  let css = remove_comments(css);
+  let mut stylesheet = Stylesheet::default();
+  let mut css = css.trim_start();
+  while !css.is_empty() {
+    let semi = css.find(';');
+    let brace = css.find('{');
+    let end = match (semi, brace) {
+      (Some(semi), Some(brace)) if css.starts_with('@') && semi < brace => semi + 1,
+      (Some(semi), None) if css.starts_with('@') => semi + 1,
+      (_, Some(brace)) => find_block_end(css, brace),
+      _ => css.len(),
+    };
+
+    let rule = &css[..end];
+    let parsed = match brace {
+      Some(brace) if brace < end && !css.starts_with('@') => {
+        parse_selectors(&rule[..brace]).map(|selectors| Rule {
+          selectors,
+          declarations: parse_declarations(rule[brace + 1..].trim_end_matches('}')),
+        })
+      }
+      _ => None,
+    };
+    match parsed {
+      Some(rule) => stylesheet.rules.push(rule),
+      None => stylesheet.rest.push(rule.trim().to_string()),
+    }
+    css = css[end..].trim_start();
+  }
+  stylesheet
+}
+
+/// The index after the `}` closing the block opened at `start`, or the end of the CSS.
+fn find_block_end(css: &str, start: usize) -> usize {
+  let mut depth = 0;
+  for (i, c) in css[start..].char_indices() {
+    match c {
+      '{' => depth += 1,
+      '}' => {
+        depth -= 1;
+        if depth == 0 {
+          return start + i + 1;
+        }
+      }
+      _ => {}
+    }
+  }
+  css.len()
+}
+
+fn parse_selectors(prelude: &str) -> Option<Vec<Selector>> {
+  prelude.split(',').map(parse_selector).collect()
+}
+
+fn parse_selector(selector: &str) -> Option<Selector> {
+  let selector = selector.replace('>', " > ");
+  let mut compounds = vec![];
+  let mut combinator = None;
+  for token in selector.split_whitespace() {
+    if token == ">" {
+      if combinator.is_some() || compounds.is_empty() {
+        return None;
+      }
+      combinator = Some(Combinator::Child);
+      continue;
+    }
+    let compound = parse_compound(token)?;
+    compounds.push((
+      compound,
+      combinator.take().unwrap_or(Combinator::Descendant),
+    ));
+  }
+  if compounds.is_empty() || combinator.is_some() {
+    return None;
+  }
+
+  // Each compound has the combinator to its left neighbour, the subject comes first.
+  compounds.reverse();
+  Some(Selector { compounds })
+}
+
+fn parse_compound(token: &str) -> Option<Compound> {
+  let is_name_char = |c: char| c.is_ascii_alphanumeric() || c == '-' || c == '_';
+  let name_end = |s: &str| s.find(|c| !is_name_char(c)).unwrap_or(s.len());
+
+  let mut compound = Compound::default();
+  let mut rest = match token.strip_prefix('*') {
+    Some(rest) => rest,
+    None => {
+      let end = name_end(token);
+      if end > 0 {
+        compound.tag_name = Some(token[..end].to_string());
+      }
+      &token[end..]
+    }
+  };
+  while let Some(kind) = rest.chars().next() {
+    if kind != '.' && kind != '#' {
+      return None;
+    }
+    let end = name_end(&rest[1..]) + 1;
+    if end == 1 {
+      return None;
+    }
+    let name = rest[1..end].to_string();
+    if kind == '.' {
+      compound.classes.push(name);
+    } else {
+      compound.ids.push(name);
+    }
+    rest = &rest[end..];
+  }
+  Some(compound)
+}
+
+fn parse_declarations(block: &str) -> Vec<Declaration> {
+  split_declarations(block)
+    .into_iter()
+    .filter_map(|declaration| {
+      let (name, value) = declaration.split_once(':')?;
+      let name = name.trim();
+      let value = value.trim();
+      let (value, important) = match value.strip_suffix("!important") {
+        Some(value) => (value.trim_end(), true),
+        None => (value, false),
+      };
+      if name.is_empty() || value.is_empty() {
+        return None;
+      }
+      Some(Declaration {
+        name: name.to_string(),
+        value: value.to_string(),
+        important,
+      })
+    })
+    .collect()
+}
+
+/// Split the declarations on the semicolons outside of strings and parentheses, like in
+/// `url(data:image/png;base64,...)`.
+fn split_declarations(block: &str) -> Vec<&str> {
+  let mut declarations = vec![];
+  let mut quote = None;
+  let mut depth = 0usize;
+  let mut start = 0;
+  for (i, c) in block.char_indices() {
+    match (quote, c) {
+      (Some(q), c) if c == q => quote = None,
+      (Some(_), _) => {}
+      (None, '"' | '\'') => quote = Some(c),
+      (None, '(') => depth += 1,
+      (None, ')') => depth = depth.saturating_sub(1),
+      (None, ';') if depth == 0 => {
+        declarations.push(&block[start..i]);
+        start = i + 1;
+      }
+      _ => {}
+    }
+  }
+  declarations.push(&block[start..]);
+  declarations
+}
+
+fn format_declarations(declarations: &[Declaration]) -> String {
+  declarations
+    .iter()
+    .map(|declaration| {
+      let important = if declaration.important {
+        " !important"
+      } else {
+        ""
+      };
+      format!("{}:{}{}", declaration.name, declaration.value, important)
+    })
+    .collect::<Vec<_>>()
+    .join(";")
+}
+
+fn set_attr(element: &mut Element, name: &str, value: &str) {
+  match element
+    .attributes
+    .iter_mut()
+    .find(|attr| attr.name == *name)
+  {
+    Some(attr) => {
+      attr.value = Some(value.into());
+      attr.raw_value = None;
+    }
+    None => element.attributes.push(create_attr(name, value)),
+  }
+}
+
+/// Whether the `<style>` element applies to every media, only those are inlined.
+fn is_inlinable_style(element: &Element) -> bool {
+  element.tag_name == "style"
+    && get_attr(element, "media").map_or(true, |media| media.trim() == "all")
+    && get_attr(element, "type").map_or(true, |t| t.is_empty() || t == "text/css")
+}
+
+/// Collect the CSS of the inlinable `<style>` elements, and separately the one of the other
+/// `<style>` elements whose classes are kept.
+fn collect_css(element: &Element, css: &mut String, other_css: &mut String) {
+  if element.tag_name == "style" {
+    let css = match is_inlinable_style(element) {
+      true => css,
+      false => other_css,
+    };
+    for child in &element.children {
+      match child {
+        Child::Text(text) => css.push_str(&text.data),
+        Child::CdataSection(cdata) => css.push_str(&cdata.data),
+        _ => {}
+      }
+    }
+    css.push('\n');
+    return;
+  }
+  for child in &element.children {
+    if let Child::Element(element) = child {
+      collect_css(element, css, other_css);
+    }
+  }
+}
+
+/// The declarations applying to each element in document order, once cascaded.
+fn cascade<'a>(
+  element: &'a Element,
+  ancestors: &mut Vec<&'a Element>,
+  rules: &[Rule],
+  out: &mut Vec<Vec<Declaration>>,
+) {
+  let mut matched = vec![];
+  for (rule_index, rule) in rules.iter().enumerate() {
+    let specificity = rule
+      .selectors
+      .iter()
+      .filter(|selector| selector.matches(element, ancestors))
+      .map(|selector| selector.specificity())
+      .max();
+    if let Some(specificity) = specificity {
+      for declaration in &rule.declarations {
+        matched.push((
+          (declaration.important, specificity, rule_index),
+          declaration,
+        ));
+      }
+    }
+  }
+  matched.sort_by_key(|(order, _)| *order);
+
+  let mut declarations: Vec<Declaration> = vec![];
+  for (_, declaration) in matched {
+    match declarations.iter_mut().find(|d| d.name == declaration.name) {
+      Some(existing) => *existing = declaration.clone(),
+      None => declarations.push(declaration.clone()),
+    }
+  }
+  out.push(declarations);
+
+  ancestors.push(element);
+  for child in &element.children {
+    if let Child::Element(child) = child {
+      cascade(child, ancestors, rules, out);
+    }
+  }
+  ancestors.pop();
+}
+
+/// Write the declarations onto the element, the declarations of its `style` attribute win
+/// unless the rule ones are `!important`.
+fn apply_declarations(element: &mut Element, declarations: Vec<Declaration>) {
+  let mut style = get_attr(element, "style")
+    .map(parse_declarations)
+    .unwrap_or_default();
+  let mut inlined = vec![];
+  let mut style_changed = false;
+  for declaration in declarations {
+    if let Some(existing) = style.iter_mut().find(|d| d.name == declaration.name) {
+      if declaration.important && !existing.important {
+        existing.value = declaration.value;
+        style_changed = true;
+      }
+    } else if PRESENTATION_ATTRS.contains(&declaration.name.as_str()) {
+      set_attr(element, &declaration.name, &declaration.value);
+    } else {
+      inlined.push(Declaration {
+        important: false,
+        ..declaration
+      });
+    }
+  }
+  if style_changed || !inlined.is_empty() {
+    inlined.extend(style);
+    set_attr(element, "style", &format_declarations(&inlined));
+  }
+}
+
+struct Inliner<'a> {
+  declarations: std::vec::IntoIter<Vec<Declaration>>,
+  removed_classes: &'a HashSet<String>,
+  rest: Option<String>,
+}
+
+impl Inliner<'_> {
+  fn visit_element(&mut self, element: &mut Element) {
+    if let Some(declarations) = self.declarations.next() {
+      apply_declarations(element, declarations);
+    }
+
+    if let Some(class) = get_attr(element, "class") {
+      let classes = class
+        .split_whitespace()
+        .filter(|class| !self.removed_classes.contains(*class))
+        .collect::<Vec<_>>()
+        .join(" ");
+      if classes.is_empty() {
+        element.attributes.retain(|attr| attr.name != "class");
+      } else if classes != class {
+        set_attr(element, "class", &classes);
+      }
+    }
+
+    let mut i = 0;
+    while i < element.children.len() {
+      if let Child::Element(child) = &mut element.children[i] {
+        if is_inlinable_style(child) {
+          self.declarations.next();
+          // The first `<style>` keeps the CSS which could not be inlined.
+          match self.rest.take() {
+            Some(rest) => {
+              child.children = vec![Child::Text(Text {
+                span: DUMMY_SP,
+                data: rest.into(),
+                raw: None,
+              })];
+              i += 1;
+            }
+            None => {
+              element.children.remove(i);
+            }
+          }
+          continue;
+        }
+        self.visit_element(child);
+      }
+      i += 1;
+    }
+  }
+}
+
+/// Inline the rules of the `<style>` elements into the matching elements, as presentation
+/// attributes or `style` declarations following the specificity of their selectors. The
+/// `<style>` elements are removed with the classes which are no longer used, only the rules
+/// which can not be inlined, like the ones with pseudo-classes or in at-rules, are kept.
+pub fn inline(document: &mut Document) {
+  let mut css = String::new();
+  let mut other_css = String::new();
+  for child in &document.children {
+    if let Child::Element(element) = child {
+      collect_css(element, &mut css, &mut other_css);
+    }
+  }
+  if css.is_empty() {
+    return;
+  }
+  let stylesheet = parse_stylesheet(&css);
+
+  let mut declarations = vec![];
+  for child in &document.children {
+    if let Child::Element(element) = child {
+      cascade(element, &mut vec![], &stylesheet.rules, &mut declarations);
+    }
+  }
+
+  let rest = stylesheet.rest.join("\n");
+  let mut kept_classes = get_class_names(&rest);
+  kept_classes.extend(get_class_names(&other_css));
+  let removed_classes = stylesheet
+    .rules
+    .iter()
+    .flat_map(|rule| &rule.selectors)
+    .flat_map(|selector| &selector.compounds)
+    .flat_map(|(compound, _)| compound.classes.iter().cloned())
+    .filter(|class| !kept_classes.contains(class))
+    .collect::<HashSet<_>>();
+
+  let mut inliner = Inliner {
+    declarations: declarations.into_iter(),
+    removed_classes: &removed_classes,
+    rest: (!rest.is_empty()).then_some(rest),
+  };
+  for child in &mut document.children {
+    if let Child::Element(element) = child {
+      inliner.visit_element(element);
+    }
+  }
+}
+
+#[cfg(test)]
+mod tests {
+  use super::*;
+  use crate::svgo::tests::code_test;
+
+  #[test]
+  fn inlines_rules_as_presentation_attributes() {
+    code_test(
+      r#"<svg><style>.cls-1{fill:#f00;stroke-width:2}</style><path class="cls-1" d="M0 0"/></svg>"#,
+      inline,
+      r##"<svg><path d="M0 0" fill="#f00" stroke-width="2"/></svg>"##,
+    );
+  }
+
+  #[test]
+  fn follows_selector_specificity() {
+    code_test(
+      r#"<svg><style><![CDATA[
+        #a { fill: blue }
+        g > .b { fill: green }
+        .b { fill: red; cursor: pointer }
+        path { fill: black !important; transform: scale(2) }
+      ]]></style><g><path id="a" class="b"/><path class="b" style="fill:white"/></g><circle class="b"/></svg>"#,
+      inline,
+      r#"<svg><g><path id="a" fill="black" cursor="pointer" style="transform:scale(2)"/><path style="transform:scale(2);fill:black" cursor="pointer"/></g><circle fill="red" cursor="pointer"/></svg>"#,
+    );
+  }
+
+  #[test]
+  fn keeps_rules_which_can_not_be_inlined() {
+    code_test(
+      r#"<svg><style>.a{fill:red}.b:hover{fill:blue}@media (min-width: 10px){.a{fill:green}}</style><path class="a b"/></svg>"#,
+      inline,
+      r#"<svg><style>.b:hover{fill:blue}
+@media (min-width: 10px){.a{fill:green}}</style><path class="a b" fill="red"/></svg>"#,
+    );
+  }
+
+  #[test]
+  fn keeps_classes_of_style_elements_which_are_not_inlined() {
+    code_test(
+      r#"<svg><style>.a{fill:red}</style><style media="print">.a{fill:blue}</style><path class="a"/></svg>"#,
+      inline,
+      r#"<svg><style media="print">.a{fill:blue}</style><path class="a" fill="red"/></svg>"#,
+    );
+  }
+}
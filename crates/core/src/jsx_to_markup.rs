@@ -0,0 +1,673 @@
+use std::{cell::RefCell, collections::BTreeSet, rc::Rc};
+
+use swc_core::{
+  common::{comments::Comments, SourceMap, DUMMY_SP},
+  ecma::{
+    ast::*,
+    codegen::{text_writer::JsWriter, Emitter},
+  },
+};
+
+use crate::{print_module, PluginContext, SvgrError};
+
+/// The template syntax the JSX is rendered in.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum Syntax {
+  /// A Vue template, the expressions are bound with `:` and interpolated with `{{ }}`.
+  Vue,
+  /// A Svelte component, the expressions are written in `{}` and rendered conditionally with `{#if}`.
+  Svelte,
+  /// A Lit template literal, the expressions are bound with `${}` and escaped by Lit.
+  Lit,
+  /// An HTML template literal, the expressions are escaped with the `escapeHtml` helper.
+  Html,
+}
+
+/// Print a JavaScript expression, as it is written in the attributes and interpolations of the markup.
+fn print_expr(cm: &Rc<SourceMap>, expr: &Expr) -> Result<String, SvgrError> {
+  let module = Module {
+    span: DUMMY_SP,
+    body: vec![ModuleItem::Stmt(Stmt::Expr(ExprStmt {
+      span: DUMMY_SP,
+      expr: Box::new(expr.clone()),
+    }))],
+    shebang: None,
+  };
+
+  let mut buf = vec![];
+  let mut emitter = Emitter {
+    cfg: Default::default(),
+    cm: cm.clone(),
+    comments: None,
+    wr: JsWriter::new(cm.clone(), "\n", &mut buf, None),
+  };
+  emitter
+    .emit_module(&module)
+    .map_err(|e| SvgrError::Codegen(e.to_string()))?;
+
+  let code = String::from_utf8_lossy(&buf);
+  Ok(code.trim_end().trim_end_matches(';').to_string())
+}
+
+fn escape_text(text: &str, syntax: Syntax) -> String {
+  let text = text.replace('&', "&amp;").replace('<', "&lt;");
+  escape_syntax(text, syntax)
+}
+
+fn escape_attr(value: &str, syntax: Syntax) -> String {
+  let value = value.replace('&', "&amp;").replace('"', "&quot;");
+  escape_syntax(value, syntax)
+}
+
+/// Escape the braces starting the expressions of Svelte, and the characters ending or
+/// interpolating a template literal.
+fn escape_syntax(value: String, syntax: Syntax) -> String {
+  match syntax {
+    Syntax::Vue => value,
+    Syntax::Svelte => value.replace('{', "&#123;").replace('}', "&#125;"),
+    Syntax::Lit | Syntax::Html => value
+      .replace('\\', "\\\\")
+      .replace('`', "\\`")
+      .replace("${", "\\${"),
+  }
+}
+
+/// Quote an expression in an attribute, with single quotes when it contains double quotes.
+fn quote_expr(code: &str) -> String {
+  if code.contains('"') && !code.contains('\'') {
+    format!("'{}'", code)
+  } else {
+    format!("\"{}\"", escape_attr(code, Syntax::Vue))
+  }
+}
+
+fn element_name(name: &JSXElementName) -> String {
+  match name {
+    JSXElementName::Ident(ident) => ident.sym.to_string(),
+    JSXElementName::JSXNamespacedName(name) => format!("{}:{}", name.ns.sym, name.name.sym),
+    JSXElementName::JSXMemberExpr(member) => {
+      let mut names = vec![member.prop.sym.to_string()];
+      let mut obj = &member.obj;
+      loop {
+        match obj {
+          JSXObject::JSXMemberExpr(member) => {
+            names.push(member.prop.sym.to_string());
+            obj = &member.obj;
+          }
+          JSXObject::Ident(ident) => {
+            names.push(ident.sym.to_string());
+            break;
+          }
+        }
+      }
+      names.reverse();
+      names.join(".")
+    }
+  }
+}
+
+fn attr_name(name: &JSXAttrName) -> String {
+  match name {
+    JSXAttrName::Ident(ident) => ident.sym.to_string(),
+    JSXAttrName::JSXNamespacedName(name) => format!("{}:{}", name.ns.sym, name.name.sym),
+  }
+}
+
+fn unwrap_paren(expr: &Expr) -> &Expr {
+  match expr {
+    Expr::Paren(paren) => unwrap_paren(&paren.expr),
+    _ => expr,
+  }
+}
+
+/// Whether an expression renders nothing, like `null` in a conditional rendering.
+fn is_empty_expr(expr: &Expr) -> bool {
+  match unwrap_paren(expr) {
+    Expr::Lit(Lit::Null(_)) | Expr::Lit(Lit::Bool(Bool { value: false, .. })) => true,
+    Expr::Ident(ident) => ident.sym == "undefined",
+    _ => false,
+  }
+}
+
+/// The branches of a conditional rendering, `a ? <b/> : c ? <d/> : null` has the tests `a` and `c`.
+fn get_branches(expr: &Expr) -> Option<Vec<(Option<&Expr>, &Expr)>> {
+  let mut branches = vec![];
+  let mut expr = unwrap_paren(expr);
+  loop {
+    match expr {
+      Expr::Cond(cond) => {
+        branches.push((Some(cond.test.as_ref()), cond.cons.as_ref()));
+        expr = unwrap_paren(&cond.alt);
+      }
+      Expr::Bin(BinExpr {
+        op: op!("&&"),
+        left,
+        right,
+        ..
+      }) if branches.is_empty() => {
+        branches.push((Some(left.as_ref()), right.as_ref()));
+        return Some(branches);
+      }
+      _ if branches.is_empty() => return None,
+      _ => {
+        branches.push((None, expr));
+        while matches!(branches.last(), Some((_, expr)) if is_empty_expr(expr)) {
+          branches.pop();
+        }
+        return Some(branches);
+      }
+    }
+  }
+}
+
+struct Printer<'a> {
+  cm: &'a Rc<SourceMap>,
+  syntax: Syntax,
+  out: String,
+  /// The helpers used by the template literals, like `ifDefined` or `escapeHtml`.
+  helpers: &'a RefCell<BTreeSet<&'static str>>,
+  /// The comments of the empty expressions, printed as markup comments.
+  comments: Option<&'a dyn Comments>,
+}
+
+impl Printer<'_> {
+  /// The markup comments of an empty expression, `None` when it has none.
+  fn comment(&self, empty: &JSXEmptyExpr) -> Option<String> {
+    if empty.span.is_dummy() {
+      return None;
+    }
+    let comments = self.comments?.get_leading(empty.span.lo)?;
+    let markup = comments
+      .iter()
+      .map(|comment| match self.syntax {
+        Syntax::Lit | Syntax::Html => {
+          format!(
+            "<!--{}-->",
+            escape_syntax(comment.text.to_string(), self.syntax)
+          )
+        }
+        _ => format!("<!--{}-->", comment.text),
+      })
+      .collect::<String>();
+    Some(markup)
+  }
+
+  fn write_indent(&mut self, depth: usize) {
+    self.out.push_str(&"  ".repeat(depth));
+  }
+
+  fn attr(&self, attr: &JSXAttrOrSpread) -> Result<String, SvgrError> {
+    let attr = match attr {
+      JSXAttrOrSpread::JSXAttr(attr) => attr,
+      JSXAttrOrSpread::SpreadElement(spread) => {
+        let code = print_expr(self.cm, &spread.expr)?;
+        return match self.syntax {
+          Syntax::Vue => Ok(format!("v-bind={}", quote_expr(&code))),
+          Syntax::Svelte => Ok(format!("{{...{}}}", code)),
+          Syntax::Lit | Syntax::Html => Err(SvgrError::Codegen(format!(
+            "the spread attribute {{...{}}} is not supported by custom elements",
+            code
+          ))),
+        };
+      }
+    };
+
+    let name = attr_name(&attr.name);
+    let expr = match &attr.value {
+      None => return Ok(name),
+      Some(JSXAttrValue::Lit(Lit::Str(str))) => {
+        return Ok(format!(
+          "{}=\"{}\"",
+          name,
+          escape_attr(&str.value, self.syntax)
+        ))
+      }
+      Some(JSXAttrValue::Lit(lit)) => Expr::Lit(lit.clone()),
+      Some(JSXAttrValue::JSXExprContainer(JSXExprContainer {
+        expr: JSXExpr::Expr(expr),
+        ..
+      })) => match unwrap_paren(expr) {
+        Expr::Lit(Lit::Str(str)) => {
+          return Ok(format!(
+            "{}=\"{}\"",
+            name,
+            escape_attr(&str.value, self.syntax)
+          ))
+        }
+        Expr::Lit(Lit::Num(num)) => return Ok(format!("{}=\"{}\"", name, num.value)),
+        expr => expr.clone(),
+      },
+      Some(JSXAttrValue::JSXExprContainer(_)) => return Ok(name),
+      Some(JSXAttrValue::JSXElement(element)) => Expr::JSXElement(element.clone()),
+      Some(JSXAttrValue::JSXFragment(fragment)) => Expr::JSXFragment(fragment.clone()),
+    };
+
+    let code = print_expr(self.cm, &expr)?;
+    Ok(match self.syntax {
+      Syntax::Vue => format!(":{}={}", name, quote_expr(&code)),
+      Syntax::Svelte => format!("{}={{{}}}", name, code),
+      // The attributes are omitted when their value is `undefined`, like in JSX.
+      Syntax::Lit => format!("{}=${{{}({})}}", name, self.helper("ifDefined"), code),
+      Syntax::Html => format!(
+        "${{{} == null ? \"\" : `{}=\"${{{}({})}}\"`}}",
+        code,
+        name,
+        self.helper("escapeHtml"),
+        code
+      ),
+    })
+  }
+
+  /// The name of a helper, recorded to be imported or declared with the template literal.
+  fn helper(&self, name: &'static str) -> &'static str {
+    self.helpers.borrow_mut().insert(name);
+    name
+  }
+
+  fn interpolation(&self, expr: &Expr) -> Result<String, SvgrError> {
+    let code = print_expr(self.cm, expr)?;
+    Ok(match self.syntax {
+      Syntax::Vue => format!("{{{{ {} }}}}", code),
+      Syntax::Svelte => format!("{{{}}}", code),
+      Syntax::Lit => format!("${{{}}}", code),
+      Syntax::Html => format!("${{{}({})}}", self.helper("escapeHtml"), code),
+    })
+  }
+
+  fn text(&self, text: &str) -> Result<String, SvgrError> {
+    match self.syntax {
+      // Escape the braces of the text which would be parsed as an interpolation.
+      Syntax::Vue if text.contains("{{") => {
+        Ok(escape_text(text, self.syntax).replace('{', "&#123;"))
+      }
+      _ => Ok(escape_text(text, self.syntax)),
+    }
+  }
+
+  /// The markup of a child rendered on a single line, `None` for the elements and the conditional renderings.
+  fn inline_child(&self, child: &JSXElementChild) -> Result<Option<String>, SvgrError> {
+    match child {
+      JSXElementChild::JSXText(text) => {
+        let text = text
+          .value
+          .split_whitespace()
+          .collect::<Vec<&str>>()
+          .join(" ");
+        Ok(Some(self.text(&text)?))
+      }
+      JSXElementChild::JSXExprContainer(JSXExprContainer {
+        expr: JSXExpr::Expr(expr),
+        ..
+      }) => match unwrap_paren(expr) {
+        Expr::Lit(Lit::Str(str)) => Ok(Some(self.text(&str.value)?)),
+        Expr::JSXElement(_) | Expr::JSXFragment(_) => Ok(None),
+        expr if is_empty_expr(expr) || get_branches(expr).is_some() => Ok(None),
+        expr => Ok(Some(self.interpolation(expr)?)),
+      },
+      JSXElementChild::JSXSpreadChild(spread) => Ok(Some(self.interpolation(&spread.expr)?)),
+      JSXElementChild::JSXExprContainer(JSXExprContainer {
+        expr: JSXExpr::JSXEmptyExpr(empty),
+        ..
+      }) => Ok(self.comment(empty)),
+      _ => Ok(None),
+    }
+  }
+
+  /// The content of a `<style>` or `<script>` child of a Svelte component, rendered with
+  /// `{@html}` so the braces of the CSS are neither escaped nor parsed as expressions.
+  fn raw_text_child(&self, child: &JSXElementChild) -> Result<Option<String>, SvgrError> {
+    let expr = match child {
+      JSXElementChild::JSXText(text) => Expr::Lit(Lit::Str(text.value.as_str().into())),
+      JSXElementChild::JSXExprContainer(JSXExprContainer {
+        expr: JSXExpr::Expr(expr),
+        ..
+      }) => *expr.clone(),
+      _ => return Ok(None),
+    };
+    Ok(Some(format!("{{@html {}}}", print_expr(self.cm, &expr)?)))
+  }
+
+  fn is_blank(&self, child: &JSXElementChild) -> bool {
+    match child {
+      JSXElementChild::JSXText(text) => text.value.trim().is_empty(),
+      JSXElementChild::JSXExprContainer(JSXExprContainer { expr, .. }) => match expr {
+        JSXExpr::JSXEmptyExpr(empty) => self.comment(empty).is_none(),
+        JSXExpr::Expr(expr) => is_empty_expr(expr),
+      },
+      _ => false,
+    }
+  }
+
+  fn element(
+    &mut self,
+    element: &JSXElement,
+    directive: Option<&str>,
+    depth: usize,
+  ) -> Result<(), SvgrError> {
+    let name = element_name(&element.opening.name);
+
+    self.write_indent(depth);
+    self.out.push('<');
+    self.out.push_str(&name);
+    if let Some(directive) = directive {
+      self.out.push(' ');
+      self.out.push_str(directive);
+    }
+    for attr in &element.opening.attrs {
+      let attr = self.attr(attr)?;
+      self.out.push(' ');
+      self.out.push_str(&attr);
+    }
+
+    self.children(&element.children, &name, depth)
+  }
+
+  /// Print the children and the closing tag of an element.
+  fn children(
+    &mut self,
+    children: &[JSXElementChild],
+    name: &str,
+    depth: usize,
+  ) -> Result<(), SvgrError> {
+    let children = children
+      .iter()
+      .filter(|child| !self.is_blank(child))
+      .collect::<Vec<&JSXElementChild>>();
+
+    if children.is_empty() {
+      self.out.push_str(" />\n");
+      return Ok(());
+    }
+
+    let raw_text = self.syntax == Syntax::Svelte && matches!(name, "style" | "script");
+    let inline = children
+      .iter()
+      .map(|child| match raw_text {
+        true => self.raw_text_child(child),
+        false => self.inline_child(child),
+      })
+      .collect::<Result<Option<Vec<String>>, SvgrError>>()?;
+    if let Some(inline) = inline {
+      self.out.push('>');
+      self.out.push_str(&inline.concat());
+      self.out.push_str(&format!("</{}>\n", name));
+      return Ok(());
+    }
+
+    self.out.push_str(">\n");
+    for child in children {
+      self.child(child, depth + 1)?;
+    }
+    self.write_indent(depth);
+    self.out.push_str(&format!("</{}>\n", name));
+    Ok(())
+  }
+
+  fn child(&mut self, child: &JSXElementChild, depth: usize) -> Result<(), SvgrError> {
+    match child {
+      JSXElementChild::JSXElement(element) => self.element(element, None, depth),
+      JSXElementChild::JSXFragment(fragment) => {
+        for child in &fragment.children {
+          if !self.is_blank(child) {
+            self.child(child, depth)?;
+          }
+        }
+        Ok(())
+      }
+      JSXElementChild::JSXExprContainer(JSXExprContainer {
+        expr: JSXExpr::Expr(expr),
+        ..
+      }) => self.expr(expr, depth),
+      _ => {
+        if let Some(inline) = self.inline_child(child)? {
+          self.write_indent(depth);
+          self.out.push_str(&inline);
+          self.out.push('\n');
+        }
+        Ok(())
+      }
+    }
+  }
+
+  fn expr(&mut self, expr: &Expr, depth: usize) -> Result<(), SvgrError> {
+    match unwrap_paren(expr) {
+      Expr::JSXElement(element) => self.element(element, None, depth),
+      Expr::JSXFragment(fragment) => {
+        for child in &fragment.children {
+          if !self.is_blank(child) {
+            self.child(child, depth)?;
+          }
+        }
+        Ok(())
+      }
+      expr if is_empty_expr(expr) => Ok(()),
+      expr => match get_branches(expr) {
+        Some(branches) => self.branches(&branches, depth),
+        None => {
+          let inline = match expr {
+            Expr::Lit(Lit::Str(str)) => self.text(&str.value)?,
+            expr => self.interpolation(expr)?,
+          };
+          self.write_indent(depth);
+          self.out.push_str(&inline);
+          self.out.push('\n');
+          Ok(())
+        }
+      },
+    }
+  }
+
+  /// Print a conditional rendering, each Vue branch with its directive.
+  fn branches(
+    &mut self,
+    branches: &[(Option<&Expr>, &Expr)],
+    depth: usize,
+  ) -> Result<(), SvgrError> {
+    match self.syntax {
+      Syntax::Svelte => return self.if_block(branches, depth),
+      Syntax::Lit | Syntax::Html => return self.conditional_template(branches, depth),
+      Syntax::Vue => {}
+    }
+
+    for (index, (test, expr)) in branches.iter().enumerate() {
+      let directive = match test {
+        Some(test) if index == 0 => format!("v-if={}", quote_expr(&print_expr(self.cm, test)?)),
+        Some(test) => format!("v-else-if={}", quote_expr(&print_expr(self.cm, test)?)),
+        None => "v-else".to_string(),
+      };
+
+      match unwrap_paren(expr) {
+        Expr::JSXElement(element) => self.element(element, Some(&directive), depth)?,
+        expr => {
+          self.write_indent(depth);
+          self.out.push_str(&format!("<template {}", directive));
+          if is_empty_expr(expr) {
+            self.out.push_str("></template>\n");
+            continue;
+          }
+          let child = JSXElementChild::JSXExprContainer(JSXExprContainer {
+            span: DUMMY_SP,
+            expr: JSXExpr::Expr(Box::new(expr.clone())),
+          });
+          self.children(&[child], "template", depth)?;
+        }
+      }
+    }
+    Ok(())
+  }
+
+  /// Print a conditional rendering as a Svelte `{#if}` block.
+  fn if_block(
+    &mut self,
+    branches: &[(Option<&Expr>, &Expr)],
+    depth: usize,
+  ) -> Result<(), SvgrError> {
+    for (index, (test, expr)) in branches.iter().enumerate() {
+      let tag = match test {
+        Some(test) if index == 0 => format!("{{#if {}}}", print_expr(self.cm, test)?),
+        Some(test) => format!("{{:else if {}}}", print_expr(self.cm, test)?),
+        None => "{:else}".to_string(),
+      };
+      self.write_indent(depth);
+      self.out.push_str(&tag);
+      self.out.push('\n');
+      self.expr(expr, depth + 1)?;
+    }
+    self.write_indent(depth);
+    self.out.push_str("{/if}\n");
+    Ok(())
+  }
+
+  /// Print a conditional rendering as a conditional expression interpolated in the template
+  /// literal, the branches are nested template literals.
+  fn conditional_template(
+    &mut self,
+    branches: &[(Option<&Expr>, &Expr)],
+    depth: usize,
+  ) -> Result<(), SvgrError> {
+    // The SVG fragments must be rendered with the `svg` tag of Lit.
+    let tag = match self.syntax {
+      Syntax::Lit => self.helper("svg"),
+      _ => "",
+    };
+
+    let mut code = String::new();
+    for (test, expr) in branches {
+      let mut printer = Printer {
+        cm: self.cm,
+        syntax: self.syntax,
+        out: String::new(),
+        helpers: self.helpers,
+        comments: self.comments,
+      };
+      printer.expr(expr, 0)?;
+      let template = format!("{}`{}`", tag, printer.out.trim());
+      match test {
+        Some(test) => code.push_str(&format!("{} ? {} : ", print_expr(self.cm, test)?, template)),
+        None => code.push_str(&template),
+      }
+    }
+    if matches!(branches.last(), Some((Some(_), _))) {
+      code.push_str(match self.syntax {
+        Syntax::Lit => self.helper("nothing"),
+        _ => "\"\"",
+      });
+    }
+
+    self.write_indent(depth);
+    self.out.push_str(&format!("${{{}}}\n", code));
+    Ok(())
+  }
+}
+
+/// Render a JSX element in the template syntax of a framework, indented by `depth` levels.
+///
+/// The expressions are kept as JavaScript, and the conditional expressions rendering elements
+/// become conditional renderings of the framework.
+pub fn to_markup(
+  cm: &Rc<SourceMap>,
+  jsx: &JSXElement,
+  syntax: Syntax,
+  depth: usize,
+  comments: Option<&dyn Comments>,
+) -> Result<String, SvgrError> {
+  let helpers = RefCell::default();
+  let mut printer = Printer {
+    cm,
+    syntax,
+    out: String::new(),
+    helpers: &helpers,
+    comments,
+  };
+  printer.element(jsx, None, depth)?;
+  Ok(printer.out)
+}
+
+/// Render a JSX element as the source of a template literal, `html` tagged for Lit.
+///
+/// The helpers used by the template literal are added to `helpers`.
+pub fn to_template_literal(
+  cm: &Rc<SourceMap>,
+  jsx: &JSXElement,
+  syntax: Syntax,
+  helpers: &mut BTreeSet<&'static str>,
+  comments: Option<&dyn Comments>,
+) -> Result<String, SvgrError> {
+  let used_helpers = RefCell::default();
+  let mut printer = Printer {
+    cm,
+    syntax,
+    out: String::new(),
+    helpers: &used_helpers,
+    comments,
+  };
+  printer.element(jsx, None, 0)?;
+  let out = printer.out;
+  helpers.extend(used_helpers.into_inner());
+
+  let tag = match syntax {
+    Syntax::Lit => "html",
+    _ => "",
+  };
+  Ok(format!("{}`{}`", tag, out.trim_end()))
+}
+
+fn get_jsx(item: &ModuleItem) -> Option<&JSXElement> {
+  match item {
+    ModuleItem::Stmt(Stmt::Expr(ExprStmt { expr, .. })) => match expr.as_ref() {
+      Expr::JSXElement(element) => Some(element),
+      _ => None,
+    },
+    _ => None,
+  }
+}
+
+/// Print a component written in markup, like a Vue single-file component or a Svelte component.
+///
+/// The module is the content of the `<script>` block followed by the JSX rendered in the markup.
+/// The comments leading the module are printed at the top of the component, as markup comments.
+pub fn print_component(
+  cm: Rc<SourceMap>,
+  m: &Module,
+  syntax: Syntax,
+  ctx: &PluginContext,
+) -> Result<String, SvgrError> {
+  let (config, comments) = (ctx.config, ctx.comments);
+  let mut script = m.clone();
+  script.span = DUMMY_SP;
+  let index = script.body.iter().rposition(|item| get_jsx(item).is_some());
+  let Some(jsx) = index.and_then(|index| get_jsx(&script.body.remove(index)).cloned()) else {
+    return Err(SvgrError::Codegen(
+      "the module of the component has no JSX element to render".to_string(),
+    ));
+  };
+
+  let mut code = String::new();
+  if !m.span.is_dummy() {
+    for comment in comments.get_leading(m.span.lo).unwrap_or_default() {
+      code.push_str(&format!("<!--{}-->\n", comment.text));
+    }
+  }
+  if !script.body.is_empty() {
+    let attrs = match (syntax, config.typescript) {
+      (Syntax::Vue, true) => " setup lang=\"ts\"",
+      (Syntax::Vue, false) => " setup",
+      (_, true) => " lang=\"ts\"",
+      (_, false) => "",
+    };
+    code.push_str(&format!(
+      "<script{}>\n{}</script>\n\n",
+      attrs,
+      print_module(cm.clone(), &script, config, Some(ctx))?
+    ));
+  }
+  match syntax {
+    Syntax::Vue => {
+      code.push_str("<template>\n");
+      code.push_str(&to_markup(&cm, &jsx, syntax, 1, Some(comments))?);
+      code.push_str("</template>\n");
+    }
+    _ => code.push_str(&to_markup(&cm, &jsx, syntax, 0, Some(comments))?),
+  }
+  Ok(code)
+}
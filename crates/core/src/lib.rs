@@ -1,34 +1,76 @@
 #![feature(path_file_prefix)]
 #![deny(clippy::all)]
 
-use std::rc::Rc;
+use std::{cell::RefCell, rc::Rc};
 
+use rayon::prelude::*;
 use swc_core::{
-  common::{comments::SingleThreadedComments, FileName, SourceMap},
+  common::{
+    comments::{Comments, SingleThreadedComments},
+    BytePos, FileName, LineCol, SourceMap, Span, Spanned, SyntaxContext,
+  },
   ecma::{
-    codegen::{text_writer::JsWriter, Emitter},
-    visit::{as_folder, FoldWith},
+    ast::{Expr, Ident, JSXExpr, Module},
+    codegen::{
+      text_writer::{JsWriter, WriteJs},
+      Emitter,
+    },
+    visit::{VisitMut, VisitMutWith},
   },
 };
-use swc_xml::parser::parse_file_as_document;
+use swc_xml::{
+  ast::{Document, Element},
+  parser::parse_file_as_document,
+};
 
 mod add_jsx_attribute;
 mod core;
+mod css_modules;
 mod error;
 mod hast_to_swc_ast;
+mod index_template;
+mod inline_styles;
+mod jsx_to_markup;
+mod output;
+mod plugin;
+mod prettier;
 mod remove_jsx_attribute;
 mod replace_jsx_attribute;
+mod source_map;
+mod sprite;
 mod svg_dynamic_title;
 mod svg_em_dimensions;
+mod svgo;
+mod transform_custom_element;
 mod transform_react_native_svg;
+mod transform_solid;
 mod transform_svg_component;
+mod transform_vue;
+mod unique_ids;
+mod util;
 
-pub use error::SvgrError;
+pub use error::{Location, SourceSpan, SvgrError, XmlError};
+pub use index_template::{create_index, DefaultIndexTemplate, IndexFile, IndexTemplate};
+pub use output::{OutputFile, TransformOutput, Warning};
+pub use plugin::{default_plugins, get_builtin_plugin, Plugin, PluginContext};
+pub use sprite::{transform_sprite, SpriteOutput};
 
 pub use self::core::config::{
-  Config, ExpandProps, ExportType, Icon, JSXRuntime, JSXRuntimeImport, SvgProp,
+  BaseClass, Config, ConfigOption, ExpandProps, ExportType, FallbackIds, Icon, JSXRuntime,
+  JSXRuntimeImport, NativeElementPolicy, PrettierConfig, SvgProp, SvgoConfig, Target,
+  TrailingComma, UniqueIds,
+};
+pub use self::core::runtime_config::{
+  load_config_file, merge_runtime_config, resolve_config, set_options, RuntimeBaseClass,
+  RuntimeConfig, RuntimeExpandProps, RuntimeIcon, RuntimeJSXRuntimeImport, RuntimePrettierConfig,
+  RuntimeSvgoConfig,
+};
+pub use self::core::state::{
+  expand_state, Caller, Config as State, InternalConfig as InternalState,
+};
+pub use self::transform_svg_component::{
+  DefaultTemplate, StringTemplate, Template, TemplateVariables,
 };
-pub use self::core::state::{Caller, Config as State};
 
 /// Transform SVG into React components.
 ///
@@ -49,81 +91,1281 @@ pub use self::core::state::{Caller, Config as State};
 ///   r#"<svg></svg>"#.to_string(),
 ///   Default::default(),
 ///   Default::default(),
-/// );
+/// )
+/// .unwrap();
+/// assert_eq!(result.component_name, "SvgComponent");
+/// assert_eq!(result.exports, vec!["default"]);
 /// ```
-pub fn transform(code: String, config: Config, state: State) -> Result<String, SvgrError> {
-  let state = core::state::expand_state(&state);
+pub fn transform(code: String, config: Config, state: State) -> Result<TransformOutput, SvgrError> {
+  let state = core::state::expand_state(&state)?;
+  let config = core::runtime_config::apply_runtime_config(config, state.file_path.as_deref())?;
 
   let cm = Rc::<SourceMap>::default();
-  let fm = cm.new_source_file(FileName::Anon.into(), code);
+  let (mut document, xml_errors) = parse_document(&cm, code, &config, &state)?;
 
-  let mut errors = vec![];
-  let document = parse_file_as_document(fm.as_ref(), Default::default(), &mut errors)
-    .map_err(|e| SvgrError::Parse(e.message().to_string()))?;
+  let css_module = match config.css_modules {
+    true => {
+      css_modules::check_target(&config)?;
+      css_modules::extract(&mut document)
+    }
+    false => None,
+  };
+  let css_module_path = css_module
+    .as_ref()
+    .map(|_| css_modules::get_file_name(&state.component_name));
 
-  let jsx_element = hast_to_swc_ast::to_swc_ast(document);
-  if jsx_element.is_none() {
-    return Err(SvgrError::InvalidSvg);
-  }
-  let jsx_element = jsx_element.unwrap();
+  let comments = SingleThreadedComments::default();
+  let warnings = RefCell::new(
+    xml_errors
+      .into_iter()
+      .map(|error| Warning {
+        code: "xml-parse-error".to_string(),
+        message: error.message,
+        loc: error.span.map(|span| span.loc),
+      })
+      .collect(),
+  );
+  let ctx = PluginContext {
+    config: &config,
+    component_name: &state.component_name,
+    file_path: state.file_path.as_deref(),
+    comments: &comments,
+    warnings: &warnings,
+  };
 
-  let m = transform_svg_component::transform(jsx_element, &config, &state)?;
+  for plugin in &config.plugins {
+    plugin.transform_document(&mut document, &ctx)?;
+  }
 
-  let m = m.fold_with(&mut as_folder(remove_jsx_attribute::Visitor::new(&config)));
-  let m = m.fold_with(&mut as_folder(add_jsx_attribute::Visitor::new(&config)));
+  get_svg_root(&cm, &document, &state)?;
 
-  let icon = match config.icon {
-    Some(core::config::Icon::Bool(b)) => b,
-    None => false,
-    _ => true,
+  let (header, svg_comments) = match config.preserve_comments {
+    true => (
+      hast_to_swc_ast::get_document_comments(&document),
+      Some(&comments as &dyn Comments),
+    ),
+    false => (vec![], None),
   };
-  let m = if icon && config.dimensions {
-    m.fold_with(&mut as_folder(svg_em_dimensions::Visitor::new(&config)))
-  } else {
-    m
+  let Some(mut jsx_element) = hast_to_swc_ast::to_swc_ast(document, &config.target, svg_comments)
+  else {
+    return Err(SvgrError::NoRootElement {
+      file_path: state.file_path,
+    });
   };
 
-  let replace_attr_values = config.replace_attr_values.is_some();
-  let m = if replace_attr_values {
-    m.fold_with(&mut as_folder(replace_jsx_attribute::Visitor::new(&config)))
-  } else {
-    m
+  if let Some(css_module) = &css_module {
+    jsx_element.visit_mut_with(&mut css_modules::Visitor::new(&css_module.classes));
+  }
+
+  for plugin in &config.plugins {
+    plugin.transform_jsx(&mut jsx_element, &ctx)?;
+  }
+
+  let css_module_source = css_module_path.as_ref().map(|path| format!("./{}", path));
+  let mut m =
+    transform_svg_component::transform(jsx_element, &config, &state, css_module_source.as_deref())?;
+
+  for plugin in &config.plugins {
+    plugin.transform_module(&mut m, &ctx)?;
+  }
+
+  // The JSX is kept until the plugins are applied, then rendered in the syntax of the target.
+  match config.target {
+    Target::Vue => m.visit_mut_with(&mut transform_vue::Visitor),
+    Target::Solid => m.visit_mut_with(&mut transform_solid::Visitor::new(&config)),
+    Target::Lit => {
+      transform_custom_element::render(&cm, &mut m, &config, jsx_to_markup::Syntax::Lit, &comments)?
+    }
+    Target::WebComponent => transform_custom_element::render(
+      &cm,
+      &mut m,
+      &config,
+      jsx_to_markup::Syntax::Html,
+      &comments,
+    )?,
+    Target::React | Target::VueSfc | Target::Svelte => {}
+  }
+
+  // The comments outside the SVG are printed before the module, from the position of the first one.
+  if let Some(first) = header.first() {
+    m.span = Span::new(first.span.lo, first.span.lo);
+    comments.add_leading_comments(first.span.lo, header);
+  }
+
+  let markup_syntax = match config.target {
+    Target::VueSfc => Some(jsx_to_markup::Syntax::Vue),
+    Target::Svelte => Some(jsx_to_markup::Syntax::Svelte),
+    Target::React | Target::Vue | Target::Solid | Target::Lit | Target::WebComponent => None,
   };
 
-  let m = if config.title_prop {
-    m.fold_with(&mut as_folder(svg_dynamic_title::Visitor::new(
-      "title".to_string(),
-    )))
+  let (code, map) = if let Some(syntax) = markup_syntax {
+    if config.source_maps {
+      ctx.warn(Warning {
+        code: "source-map-unsupported".to_string(),
+        message: "source maps are not supported for components written in markup, no source map is generated"
+          .to_string(),
+        loc: None,
+      });
+    }
+    (jsx_to_markup::print_component(cm, &m, syntax, &ctx)?, None)
+  } else if config.source_maps && !config.prettier {
+    let mut mappings = vec![];
+    let code = emit_module(cm.clone(), &m, Some(&mut mappings), Some(&comments))?;
+    (code, Some(source_map::build_source_map(&cm, &mappings)?))
   } else {
-    m
+    if config.source_maps {
+      ctx.warn(Warning {
+        code: "source-map-unsupported".to_string(),
+        message: "source maps are not supported with prettier, no source map is generated"
+          .to_string(),
+        loc: None,
+      });
+    }
+    (print_module(cm, &m, &config, Some(&ctx))?, None)
   };
 
-  let m = if config.desc_prop {
-    m.fold_with(&mut as_folder(svg_dynamic_title::Visitor::new(
-      "desc".to_string(),
-    )))
-  } else {
-    m
+  Ok(TransformOutput {
+    code,
+    map,
+    component_name: state.component_name,
+    exports: match markup_syntax {
+      Some(_) => vec!["default".to_string()],
+      None => output::get_exports(&m),
+    },
+    imports: output::get_imports(&m),
+    warnings: warnings.into_inner(),
+    files: match (css_module, css_module_path) {
+      (Some(css_module), Some(path)) => vec![OutputFile {
+        path,
+        code: css_module.code,
+      }],
+      _ => vec![],
+    },
+    module: if config.ast { Some(m) } else { None },
+  })
+}
+
+/// Transform many SVG into React components in parallel, on the global thread pool.
+///
+/// Each input is the SVG source code and its state, the results are returned in the order of the inputs.
+/// A file failing to transform does not prevent the other ones from being transformed.
+///
+/// # Examples
+///
+/// ```rust
+/// use svgr_rs::{transform_many, State};
+///
+/// let results = transform_many(
+///   vec![
+///     (r#"<svg></svg>"#.to_string(), State::default()),
+///     (r#"<svg><path/></svg>"#.to_string(), State::default()),
+///   ],
+///   &Default::default(),
+/// );
+/// assert_eq!(results.len(), 2);
+/// ```
+pub fn transform_many(
+  inputs: Vec<(String, State)>,
+  config: &Config,
+) -> Vec<Result<TransformOutput, SvgrError>> {
+  inputs
+    .into_par_iter()
+    .map(|(code, state)| transform(code, config.clone(), state))
+    .collect()
+}
+
+/// Parse the SVG source code, the errors the XML parser recovered from are returned
+/// unless `strict` is enabled. The document is optimized when `svgo` is enabled.
+fn parse_document(
+  cm: &Rc<SourceMap>,
+  code: String,
+  config: &Config,
+  state: &InternalState,
+) -> Result<(Document, Vec<XmlError>), SvgrError> {
+  let file_name = match &state.file_path {
+    Some(file_path) => FileName::Real(file_path.into()),
+    None => FileName::Anon,
   };
+  if code.trim().is_empty() {
+    return Err(SvgrError::EmptyInput {
+      file_path: state.file_path.clone(),
+    });
+  }
+  let fm = cm.new_source_file(file_name.into(), code);
+  let file_path = state.file_path.as_deref();
 
-  let m = if config.native {
-    let comments = SingleThreadedComments::default();
-    m.fold_with(&mut as_folder(transform_react_native_svg::Visitor::new(
-      &comments,
-    )))
+  if let Some(offset) = find_unterminated_tag_name(&fm.src) {
+    let lo = fm.start_pos + BytePos(offset as u32);
+    return Err(SvgrError::Parse {
+      message: "Eof in tag".to_string(),
+      span: SourceSpan::new(cm, Span::new(lo, fm.end_pos), file_path),
+    });
+  }
+
+  if let Some((start, end)) = find_invalid_character_reference(&fm.src) {
+    let lo = fm.start_pos + BytePos(start as u32);
+    let hi = fm.start_pos + BytePos(end as u32);
+    return Err(SvgrError::Parse {
+      message: "Invalid character reference".to_string(),
+      span: SourceSpan::new(cm, Span::new(lo, hi), file_path),
+    });
+  }
+
+  let mut errors = vec![];
+  let mut document =
+    parse_file_as_document(fm.as_ref(), Default::default(), &mut errors).map_err(|error| {
+      let message = error.message().to_string();
+      let (span, _) = *error.into_inner();
+      SvgrError::Parse {
+        message,
+        span: SourceSpan::new(cm, span, file_path),
+      }
+    })?;
+
+  let xml_errors = errors
+    .into_iter()
+    .map(|error| {
+      let message = error.message().to_string();
+      let (span, _) = *error.into_inner();
+      XmlError {
+        message,
+        span: SourceSpan::new(cm, span, file_path),
+      }
+    })
+    .collect::<Vec<XmlError>>();
+  if config.strict && !xml_errors.is_empty() {
+    return Err(SvgrError::InvalidXml {
+      file_path: state.file_path.clone(),
+      errors: xml_errors,
+    });
+  }
+
+  if config.inline_styles {
+    inline_styles::inline(&mut document);
+  }
+  if config.svgo {
+    svgo::optimize(&mut document, &config.svgo_config);
+  }
+
+  Ok((document, xml_errors))
+}
+
+/// The root `<svg>` element of the document.
+fn get_svg_root<'a>(
+  cm: &Rc<SourceMap>,
+  document: &'a Document,
+  state: &InternalState,
+) -> Result<&'a Element, SvgrError> {
+  match hast_to_swc_ast::get_root_element(document) {
+    None => Err(SvgrError::NoRootElement {
+      file_path: state.file_path.clone(),
+    }),
+    Some(root) if !hast_to_swc_ast::is_svg_element(root) => Err(SvgrError::NonSvgRoot {
+      tag_name: root.tag_name.to_string(),
+      span: SourceSpan::new(cm, root.span, state.file_path.as_deref()),
+    }),
+    Some(root) => Ok(root),
+  }
+}
+
+/// The offset of the `<` of a tag whose name is cut by the end of the input, like `<svg` or `</svg`.
+///
+/// The XML parser never leaves the tag name state at the end of the input and loops forever,
+/// such inputs are rejected before being parsed.
+fn find_unterminated_tag_name(code: &str) -> Option<usize> {
+  let name_start = code
+    .rfind(|c: char| c.is_whitespace() || c == '/' || c == '>' || c == '<')
+    .map(|index| index + 1)?;
+  if name_start == code.len() {
+    return None;
+  }
+  let before_name = &code[..name_start];
+  if before_name.ends_with("</") {
+    Some(name_start - 2)
+  } else if before_name.ends_with('<') {
+    Some(name_start - 1)
   } else {
-    m
+    None
+  }
+}
+
+/// The range of a numeric character reference above the last Unicode code point, like
+/// `&#xFFFFFFFF;`. The comments and CDATA sections are skipped since they are not decoded.
+///
+/// The XML parser reaches an `unreachable!()` on such references, they are rejected before being
+/// parsed.
+fn find_invalid_character_reference(code: &str) -> Option<(usize, usize)> {
+  let bytes = code.as_bytes();
+  let skip_to = |from: usize, end: &[u8]| {
+    bytes[from..]
+      .windows(end.len())
+      .position(|window| window == end)
+      .map_or(bytes.len(), |position| from + position + end.len())
   };
+  let mut index = 0;
+  while index < bytes.len() {
+    let rest = &bytes[index..];
+    if rest.starts_with(b"<!--") {
+      index = skip_to(index + 4, b"-->");
+      continue;
+    }
+    if rest.starts_with(b"<![CDATA[") {
+      index = skip_to(index + 9, b"]]>");
+      continue;
+    }
+    if !rest.starts_with(b"&#") {
+      index += 1;
+      continue;
+    }
+
+    let (radix, digits_start) = match bytes.get(index + 2) {
+      Some(b'x' | b'X') => (16, index + 3),
+      _ => (10, index + 2),
+    };
+    let digits_len = bytes[digits_start..]
+      .iter()
+      .take_while(|byte| match radix {
+        16 => byte.is_ascii_hexdigit(),
+        _ => byte.is_ascii_digit(),
+      })
+      .count();
+    let digits_end = digits_start + digits_len;
+    if digits_len > 0 {
+      let value = u32::from_str_radix(&code[digits_start..digits_end], radix).ok();
+      if value.map_or(true, |value| value > char::MAX as u32) {
+        let end = match bytes.get(digits_end) {
+          Some(b';') => digits_end + 1,
+          _ => digits_end,
+        };
+        return Some((index, end));
+      }
+    }
+    index = digits_end;
+  }
+  None
+}
+
+/// Generate the code of a module, with the built-in pretty printer when `prettier` is enabled.
+/// The comments are read from the context, which is also warned about the nodes the printer
+/// does not format.
+fn print_module(
+  cm: Rc<SourceMap>,
+  m: &Module,
+  config: &Config,
+  ctx: Option<&PluginContext>,
+) -> Result<String, SvgrError> {
+  let comments = ctx.map(|ctx| ctx.comments);
+  if config.prettier {
+    let formatted = prettier::format(m, &config.prettier_config, comments);
+    if let (true, Some(ctx)) = (formatted.unformatted, ctx) {
+      ctx.warn(Warning {
+        code: "prettier-unformatted-code".to_string(),
+        message: "some syntax is not supported by the built-in pretty printer, it is printed as is without following the prettier config"
+          .to_string(),
+        loc: None,
+      });
+    }
+    return Ok(formatted.code);
+  }
+
+  emit_module(cm, m, None, comments)
+}
+
+/// Attach the comments of the empty JSX expressions to an empty identifier,
+/// the code generator only prints the comments of the expressions.
+struct JsxComments<'a> {
+  comments: &'a dyn Comments,
+}
+
+impl VisitMut for JsxComments<'_> {
+  fn visit_mut_jsx_expr(&mut self, n: &mut JSXExpr) {
+    if let JSXExpr::JSXEmptyExpr(empty) = n {
+      if !empty.span.is_dummy() && self.comments.has_leading(empty.span.lo) {
+        *n = JSXExpr::Expr(Box::new(Expr::Ident(Ident::new(
+          "".into(),
+          empty.span,
+          SyntaxContext::empty(),
+        ))));
+      }
+    }
+  }
+}
 
+/// Generate the code of a module with the swc code generator,
+/// collecting the source map mappings when a sink is given.
+fn emit_module(
+  cm: Rc<SourceMap>,
+  m: &Module,
+  srcmap: Option<&mut Vec<(BytePos, LineCol)>>,
+  comments: Option<&dyn Comments>,
+) -> Result<String, SvgrError> {
   let mut buf = vec![];
 
+  let with_jsx_comments;
+  let m = match comments {
+    Some(comments) => {
+      let mut m = m.clone();
+      m.visit_mut_with(&mut JsxComments { comments });
+      with_jsx_comments = m;
+      &with_jsx_comments
+    }
+    None => m,
+  };
+
   let mut emitter = Emitter {
     cfg: Default::default(),
     cm: cm.clone(),
-    comments: None,
-    wr: JsWriter::new(cm, "\n", &mut buf, None),
+    comments,
+    wr: JsWriter::new(cm, "\n", &mut buf, srcmap),
   };
-  emitter.emit_module(&m).unwrap();
+  // The code generator prints the first item on the line of the comments leading the module,
+  // they are printed on their own lines instead.
+  let header = match comments {
+    Some(comments) if !m.span.is_dummy() => comments.take_leading(m.span.lo).unwrap_or_default(),
+    _ => vec![],
+  };
+  for comment in header {
+    emitter
+      .wr
+      .write_comment(&format!("/*{}*/", comment.text))
+      .and_then(|_| emitter.wr.write_line())
+      .map_err(|e| SvgrError::Codegen(e.to_string()))?;
+  }
+  // The code generator also prints the comments trailing an item on its line, followed by a
+  // space, they are printed on their own lines after the item instead.
+  for item in &m.body {
+    let trailing = match comments {
+      Some(comments) if !item.span().is_dummy() => {
+        comments.take_trailing(item.span().hi).unwrap_or_default()
+      }
+      _ => vec![],
+    };
+    emitter
+      .emit_module_item(item)
+      .map_err(|e| SvgrError::Codegen(e.to_string()))?;
+    for comment in trailing {
+      emitter
+        .wr
+        .write_comment(&format!("/*{}*/", comment.text))
+        .and_then(|_| emitter.wr.write_line())
+        .map_err(|e| SvgrError::Codegen(e.to_string()))?;
+    }
+  }
+  emitter
+    .wr
+    .commit_pending_semi()
+    .map_err(|e| SvgrError::Codegen(e.to_string()))?;
 
   Ok(String::from_utf8_lossy(&buf).to_string())
 }
+
+#[cfg(test)]
+mod tests {
+  use super::*;
+
+  #[test]
+  fn transform_many_keeps_input_order() {
+    let inputs = (0..20)
+      .map(|i| {
+        let state = State {
+          component_name: Some(format!("Icon{}", i)),
+          ..Default::default()
+        };
+        let code = if i == 7 {
+          String::new()
+        } else {
+          "<svg/>".to_string()
+        };
+        (code, state)
+      })
+      .collect();
+
+    let results = transform_many(inputs, &Default::default());
+
+    assert_eq!(results.len(), 20);
+    for (i, result) in results.into_iter().enumerate() {
+      if i == 7 {
+        assert!(matches!(result, Err(SvgrError::EmptyInput { .. })));
+      } else {
+        assert_eq!(result.unwrap().component_name, format!("Icon{}", i));
+      }
+    }
+  }
+
+  #[test]
+  fn returns_component_name_exports_and_imports() {
+    let result = transform(
+      "<svg/>".to_string(),
+      Config {
+        export_type: ExportType::Named,
+        ..Default::default()
+      },
+      State {
+        file_path: Some("icons/arrow-left.svg".to_string()),
+        component_name: None,
+        caller: None,
+      },
+    )
+    .unwrap();
+
+    assert_eq!(result.component_name, "SvgArrowLeft");
+    assert_eq!(result.exports, vec!["ReactComponent"]);
+    assert_eq!(result.imports, vec!["react"]);
+    assert!(result.warnings.is_empty());
+    assert!(result.module.is_none());
+  }
+
+  #[test]
+  fn returns_dropped_react_native_elements_as_warnings() {
+    let result = transform(
+      "<svg><filter/><path/><style/></svg>".to_string(),
+      Config {
+        native: true,
+        ast: true,
+        ..Default::default()
+      },
+      Default::default(),
+    )
+    .unwrap();
+
+    assert_eq!(
+      result.warnings,
+      vec![
+        Warning {
+          code: "react-native-unsupported-element".to_string(),
+          message: "<style> is not supported by react-native-svg and has been dropped".to_string(),
+          loc: None,
+        },
+        Warning {
+          code: "react-native-unsupported-element".to_string(),
+          message: "<filter> is not supported by react-native-svg and has been dropped".to_string(),
+          loc: None,
+        },
+      ]
+    );
+    assert_eq!(result.imports, vec!["react", "react-native-svg"]);
+    assert!(result.code.contains(
+      "from \"react-native-svg\";\n/* SVGR has dropped some elements not supported by react-native-svg: style, filter */\n"
+    ));
+    assert_eq!(result.module.unwrap().body.len(), 4);
+  }
+
+  #[test]
+  fn maps_react_native_elements_from_config() {
+    let result = transform(
+      "<svg><filter/><style/></svg>".to_string(),
+      Config {
+        native: true,
+        native_components: std::collections::HashMap::from([(
+          "filter".to_string(),
+          "Filter".to_string(),
+        )]),
+        native_import_source: "react-native-svg-web".to_string(),
+        native_elements: std::collections::HashMap::from([(
+          "style".to_string(),
+          NativeElementPolicy::Keep,
+        )]),
+        ..Default::default()
+      },
+      Default::default(),
+    )
+    .unwrap();
+
+    assert!(result.warnings.is_empty());
+    assert_eq!(result.imports, vec!["react", "react-native-svg-web"]);
+    assert!(result
+      .code
+      .contains(r#"import Svg, { Filter } from "react-native-svg-web";"#));
+    assert!(result
+      .code
+      .contains("<Svg {...props}><Filter/><style/></Svg>"));
+  }
+
+  #[test]
+  fn fails_on_dropped_react_native_elements_when_configured() {
+    let err = transform(
+      "<svg><filter/><path/></svg>".to_string(),
+      Config {
+        native: true,
+        native_unsupported: NativeElementPolicy::Error,
+        ..Default::default()
+      },
+      Default::default(),
+    )
+    .unwrap_err();
+
+    assert_eq!(err.code(), "unsupported-native-elements");
+    assert_eq!(
+      err.to_string(),
+      "elements not supported by react-native-svg: filter"
+    );
+  }
+
+  #[test]
+  fn generates_source_maps() {
+    let result = transform(
+      "<svg>\n  <path d=\"M0 0\"/>\n</svg>".to_string(),
+      Config {
+        source_maps: true,
+        template: Some(std::sync::Arc::new(StringTemplate::new(
+          "%%imports%%\nexport const %%componentName%% = (%%props%%) => %%jsx%%;".to_string(),
+        ))),
+        ..Default::default()
+      },
+      State {
+        file_path: Some("icons/star.svg".to_string()),
+        component_name: None,
+        caller: None,
+      },
+    )
+    .unwrap();
+
+    let json = result.map.unwrap();
+    let map: serde_json::Value = serde_json::from_str(&json).unwrap();
+    assert_eq!(map["version"], 3);
+    assert_eq!(map["sources"], serde_json::json!(["icons/star.svg"]));
+    assert_eq!(
+      map["sourcesContent"],
+      serde_json::json!(["<svg>\n  <path d=\"M0 0\"/>\n</svg>"])
+    );
+    assert!(!map["mappings"].as_str().unwrap().is_empty());
+
+    let map = sourcemap::SourceMap::from_slice(json.as_bytes()).unwrap();
+    let path = map
+      .tokens()
+      .find(|token| token.get_src() == (1, 2))
+      .expect("the <path> element is mapped");
+    assert_eq!(
+      result
+        .code
+        .lines()
+        .nth(path.get_dst_line() as usize)
+        .unwrap()[path.get_dst_col() as usize..]
+        .split_whitespace()
+        .next(),
+      Some("path")
+    );
+  }
+
+  #[test]
+  fn does_not_generate_source_maps_with_prettier() {
+    let result = transform(
+      "<svg/>".to_string(),
+      Config {
+        source_maps: true,
+        prettier: true,
+        ..Default::default()
+      },
+      Default::default(),
+    )
+    .unwrap();
+
+    assert!(result.map.is_none());
+    assert_eq!(result.warnings[0].code, "source-map-unsupported");
+  }
+
+  #[test]
+  fn reports_recovered_xml_errors_as_warnings() {
+    let result = transform(
+      "<svg>\n  <g><path/></svg>".to_string(),
+      Default::default(),
+      Default::default(),
+    )
+    .unwrap();
+
+    assert_eq!(
+      result.warnings,
+      vec![Warning {
+        code: "xml-parse-error".to_string(),
+        message: "Opening and ending tag mismatch".to_string(),
+        loc: Some(Location {
+          line: 2,
+          column: 13
+        }),
+      }]
+    );
+  }
+
+  #[test]
+  fn fails_on_recovered_xml_errors_in_strict_mode() {
+    let err = transform(
+      "<svg><path></g></svg>".to_string(),
+      Config {
+        strict: true,
+        ..Default::default()
+      },
+      Default::default(),
+    )
+    .unwrap_err();
+
+    assert_eq!(
+      err.to_string(),
+      "invalid XML:\n  1:12: Opening and ending tag mismatch\n  1:16: Opening and ending tag mismatch"
+    );
+    assert_eq!(err.code(), "invalid-xml");
+    assert_eq!(
+      err.span().unwrap().loc,
+      Location {
+        line: 1,
+        column: 12
+      }
+    );
+  }
+
+  #[test]
+  fn renders_vue_components_with_h() {
+    let result = transform(
+      "<svg viewBox=\"0 0 24 24\"><path stroke-width=\"2\" d=\"M0 0\"/></svg>".to_string(),
+      Config {
+        target: Target::Vue,
+        ..Default::default()
+      },
+      Default::default(),
+    )
+    .unwrap();
+
+    assert_eq!(result.exports, vec!["default"]);
+    assert_eq!(result.imports, vec!["vue"]);
+    assert!(result.code.contains("defineComponent({"));
+    assert!(result.code.contains("\"stroke-width\": \"2\""));
+    assert!(!result.code.contains("<svg"));
+  }
+
+  #[test]
+  fn renders_vue_single_file_components_in_template() {
+    let result = transform(
+      "<svg viewBox=\"0 0 24 24\"><path stroke-width=\"2\" d=\"M0 0\"/></svg>".to_string(),
+      Config {
+        target: Target::VueSfc,
+        title_prop: true,
+        ..Default::default()
+      },
+      Default::default(),
+    )
+    .unwrap();
+
+    assert_eq!(result.exports, vec!["default"]);
+    assert_eq!(
+      result.code,
+      r#"<script setup>
+defineProps({
+    title: String,
+    titleId: String
+});
+</script>
+
+<template>
+  <svg viewBox="0 0 24 24" :aria-labelledby="titleId">
+    <title v-if="title" :id="titleId">{{ title }}</title>
+    <path stroke-width="2" d="M0 0" />
+  </svg>
+</template>
+"#
+    );
+  }
+
+  #[test]
+  fn escapes_the_interpolations_of_vue_single_file_components() {
+    let result = transform(
+      "<svg><text>{{ a }} }}</text></svg>".to_string(),
+      Config {
+        target: Target::VueSfc,
+        ..Default::default()
+      },
+      Default::default(),
+    )
+    .unwrap();
+
+    assert!(result.code.contains("<text>&#123;&#123; a }} }}</text>"));
+  }
+
+  #[test]
+  fn renders_svelte_components_in_markup() {
+    let result = transform(
+      "<svg viewBox=\"0 0 24 24\"><title>{a}</title><path d=\"M0 0\"/></svg>".to_string(),
+      Config {
+        target: Target::Svelte,
+        typescript: true,
+        title_prop: true,
+        svg_props: vec![SvgProp {
+          key: "focusable".to_string(),
+          value: "false".to_string(),
+        }],
+        ..Default::default()
+      },
+      Default::default(),
+    )
+    .unwrap();
+
+    assert_eq!(result.exports, vec!["default"]);
+    assert_eq!(
+      result.code,
+      r#"<script lang="ts">
+export let title: string | undefined = undefined;
+export let titleId: string | undefined = undefined;
+</script>
+
+<svg viewBox="0 0 24 24" focusable="false" aria-labelledby={titleId} {...$$restProps}>
+  {#if title === undefined}
+    <title id={titleId}>&#123;a&#125;</title>
+  {:else if title}
+    <title id={titleId}>{title}</title>
+  {/if}
+  <path d="M0 0" />
+</svg>
+"#
+    );
+  }
+
+  #[test]
+  fn reads_solid_props_without_destructuring() {
+    let result = transform(
+      "<svg class=\"icon\" style=\"fill: red\"><path stroke-width=\"2\"/></svg>".to_string(),
+      Config {
+        target: Target::Solid,
+        title_prop: true,
+        native: true,
+        ..Default::default()
+      },
+      Default::default(),
+    )
+    .unwrap();
+
+    assert_eq!(result.imports, vec!["solid-js"]);
+    assert_eq!(
+      result.code,
+      r#"import { splitProps } from "solid-js";
+const SvgComponent = (props)=>{
+    const [local, others] = splitProps(props, [
+        "title",
+        "titleId"
+    ]);
+    return <svg class="icon" style={{
+        "fill": "red"
+    }} aria-labelledby={local.titleId} {...others}>{local.title ? <title id={local.titleId}>{local.title}</title> : null}<path stroke-width="2"/></svg>;
+};
+export default SvgComponent;
+"#
+    );
+  }
+
+  #[test]
+  fn renders_lit_elements_with_tagged_templates() {
+    let result = transform(
+      "<svg viewBox=\"0 0 24 24\"><path d=\"M0 0\"/></svg>".to_string(),
+      Config {
+        target: Target::Lit,
+        title_prop: true,
+        element_name: Some("star-icon".to_string()),
+        ..Default::default()
+      },
+      Default::default(),
+    )
+    .unwrap();
+
+    assert_eq!(
+      result.code,
+      r#"import { LitElement, html, svg, nothing } from "lit";
+import { ifDefined } from "lit/directives/if-defined.js";
+class SvgComponent extends LitElement {
+    static properties = {
+        title: {},
+        titleId: {
+            attribute: "title-id"
+        }
+    };
+    render() {
+        const { title, titleId } = this;
+        return html`<svg viewBox="0 0 24 24" aria-labelledby=${ifDefined(titleId)}>
+  ${title ? svg`<title id=${ifDefined(titleId)}>${title}</title>` : nothing}
+  <path d="M0 0" />
+</svg>`;
+    }
+}
+if (!customElements.get("star-icon")) {
+    customElements.define("star-icon", SvgComponent);
+}
+export default SvgComponent;
+"#
+    );
+  }
+
+  #[test]
+  fn prefixes_ids_with_an_id_generated_at_runtime() {
+    let result = transform(
+      r##"<svg><linearGradient id="a"/><path fill="url(#a)"/><use href="#a"/></svg>"##.to_string(),
+      Config {
+        unique_ids: UniqueIds::UseId,
+        jsx_runtime: JSXRuntime::Automatic,
+        ..Default::default()
+      },
+      Default::default(),
+    )
+    .unwrap();
+
+    assert_eq!(
+      result.code,
+      r##"import { useId } from "react";
+const SvgComponent = (props)=>{
+    const uniqueId = useId();
+    return <svg {...props}><linearGradient id={`${uniqueId}-a`}/><path fill={`url(#${uniqueId}-a)`}/><use href={`#${uniqueId}-a`}/></svg>;
+};
+export default SvgComponent;
+"##
+    );
+  }
+
+  #[test]
+  fn keeps_the_static_prefix_of_ids_selected_in_style_elements() {
+    let result = transform(
+      r##"<svg><style>#a{fill:red}.c{mask:url(#b)}</style><path id="a"/><mask id="b"/><path fill="url(#a)" class="c"/></svg>"##.to_string(),
+      Config {
+        unique_ids: UniqueIds::UseId,
+        jsx_runtime: JSXRuntime::Automatic,
+        ..Default::default()
+      },
+      State {
+        file_path: Some("icons/star.svg".to_string()),
+        component_name: None,
+        caller: None,
+      },
+    )
+    .unwrap();
+
+    let prefix = unique_ids::get_prefix(Some("icons/star.svg"), "SvgStar");
+    assert_eq!(
+      result.code,
+      format!(
+        r##"import {{ useId }} from "react";
+const SvgStar = (props)=>{{
+    const uniqueId = useId();
+    return <svg {{...props}}><style>{{`#{prefix}-a{{fill:red}}.c{{mask:url(#${{uniqueId}}-b)}}`}}</style><path id="{prefix}-a"/><mask id={{`${{uniqueId}}-b`}}/><path fill="url(#{prefix}-a)" className="c"/></svg>;
+}};
+export default SvgStar;
+"##
+      )
+    );
+  }
+
+  #[test]
+  fn generates_fallback_title_id() {
+    let result = transform(
+      "<svg><path/></svg>".to_string(),
+      Config {
+        title_prop: true,
+        fallback_ids: FallbackIds::UseId,
+        ..Default::default()
+      },
+      Default::default(),
+    )
+    .unwrap();
+
+    assert_eq!(
+      result.code,
+      r#"import * as React from "react";
+const SvgComponent = ({ title, titleId: titleIdProp, ...props })=>{
+    const fallbackId = React.useId();
+    const titleId = titleIdProp ?? `${fallbackId}-title`;
+    return <svg aria-labelledby={title ? titleId : undefined} {...props}>{title ? <title id={titleId}>{title}</title> : null}<path/></svg>;
+};
+export default SvgComponent;
+"#
+    );
+  }
+
+  #[test]
+  fn generates_fallback_desc_id_from_a_counter_with_solid() {
+    let result = transform(
+      "<svg><path/></svg>".to_string(),
+      Config {
+        desc_prop: true,
+        expand_props: ExpandProps::None,
+        target: Target::Solid,
+        fallback_ids: FallbackIds::Counter,
+        ..Default::default()
+      },
+      Default::default(),
+    )
+    .unwrap();
+
+    assert_eq!(
+      result.code,
+      r#"import { mergeProps } from "solid-js";
+let idCounter = 0;
+const SvgComponent = (props)=>{
+    const fallbackId = `svgr-svg-component-${++idCounter}`;
+    const merged = mergeProps({
+        descId: `${fallbackId}-desc`
+    }, props);
+    return <svg aria-describedby={merged.desc ? merged.descId : undefined}>{merged.desc ? <desc id={merged.descId}>{merged.desc}</desc> : null}<path/></svg>;
+};
+export default SvgComponent;
+"#
+    );
+  }
+
+  #[test]
+  fn prefixes_the_counter_ids_with_the_file() {
+    let transform_file = |file_path: &str| {
+      transform(
+        "<svg><path/></svg>".to_string(),
+        Config {
+          title_prop: true,
+          fallback_ids: FallbackIds::Counter,
+          ..Default::default()
+        },
+        State {
+          file_path: Some(file_path.to_string()),
+          component_name: None,
+          caller: None,
+        },
+      )
+      .unwrap()
+      .code
+    };
+
+    let star = transform_file("icons/star.svg");
+    assert!(star.contains("let idCounter = 0;"));
+    assert!(star.contains(&format!(
+      "React.useState(()=>`svgr-{}-${{++idCounter}}`)",
+      unique_ids::get_prefix(Some("icons/star.svg"), "SvgStar")
+    )));
+    assert_ne!(star, transform_file("icons/heart.svg"));
+    assert_ne!(star, transform_file("icons/outline/star.svg"));
+  }
+
+  #[test]
+  fn inlines_styles_for_react_native() {
+    let result = transform(
+      "<svg><style>.a{fill:red;mix-blend-mode:multiply}</style><path class=\"a\"/></svg>"
+        .to_string(),
+      Config {
+        native: true,
+        inline_styles: true,
+        ..Default::default()
+      },
+      Default::default(),
+    )
+    .unwrap();
+
+    assert!(result.warnings.is_empty());
+    assert!(result.code.contains("<Path fill=\"red\" style={{"));
+    assert!(result.code.contains("mixBlendMode: \"multiply\""));
+  }
+
+  #[test]
+  fn extracts_styles_into_a_css_module() {
+    let result = transform(
+      r#"<svg><style media="print">.a{fill:red}</style><g class="a b"><path class="b"/></g></svg>"#
+        .to_string(),
+      Config {
+        css_modules: true,
+        ..Default::default()
+      },
+      State {
+        component_name: Some("Star".to_string()),
+        ..Default::default()
+      },
+    )
+    .unwrap();
+
+    assert_eq!(
+      result.files,
+      vec![OutputFile {
+        path: "Star.module.css".to_string(),
+        code: "@media print {\n.a{fill:red}\n}\n".to_string(),
+      }]
+    );
+    assert_eq!(result.imports, vec!["react", "./Star.module.css"]);
+    assert!(result
+      .code
+      .contains(r#"<g className={`${styles["a"]} b`}><path className="b"/></g>"#));
+
+    let err = transform(
+      "<svg/>".to_string(),
+      Config {
+        css_modules: true,
+        target: Target::Vue,
+        ..Default::default()
+      },
+      Default::default(),
+    )
+    .unwrap_err();
+    assert_eq!(err.code(), "invalid-configuration");
+  }
+
+  #[test]
+  fn preserves_comments() {
+    let code = r#"<!-- Icon by Jane, CC BY 4.0 -->
+<svg><!-- arrow --><path/></svg>"#;
+    let config = Config {
+      preserve_comments: true,
+      expand_props: ExpandProps::None,
+      ..Default::default()
+    };
+
+    let result = transform(code.to_string(), config.clone(), Default::default()).unwrap();
+    assert_eq!(
+      result.code,
+      r#"/* Icon by Jane, CC BY 4.0 */
+import * as React from "react";
+const SvgComponent = ()=><svg>{/* arrow */ }<path/></svg>;
+export default SvgComponent;
+"#
+    );
+
+    let result = transform(
+      code.to_string(),
+      Config {
+        target: Target::Svelte,
+        ..config
+      },
+      Default::default(),
+    )
+    .unwrap();
+    assert_eq!(
+      result.code,
+      r#"<!-- Icon by Jane, CC BY 4.0 -->
+<svg>
+  <!-- arrow -->
+  <path />
+</svg>
+"#
+    );
+  }
+
+  #[test]
+  fn renders_svelte_style_elements_as_html() {
+    let result = transform(
+      "<svg><style>.a{fill:red}\n.b &gt; .c{fill:blue}</style><text>{a}</text></svg>".to_string(),
+      Config {
+        target: Target::Svelte,
+        ..Default::default()
+      },
+      Default::default(),
+    )
+    .unwrap();
+    assert_eq!(
+      result.code,
+      r#"<svg {...$$restProps}>
+  <style>{@html ".a{fill:red}\n.b > .c{fill:blue}"}</style>
+  <text>&#123;a&#125;</text>
+</svg>
+"#
+    );
+  }
+
+  #[test]
+  fn escapes_web_component_attributes() {
+    let result = transform(
+      "<svg viewBox=\"0 0 24 24\"><title>`${a}`</title></svg>".to_string(),
+      Config {
+        target: Target::WebComponent,
+        ..Default::default()
+      },
+      Default::default(),
+    )
+    .unwrap();
+
+    assert_eq!(
+      result.code,
+      r#"class SvgComponent extends HTMLElement {
+    constructor(){
+        super();
+        this.attachShadow({
+            mode: "open"
+        });
+    }
+    connectedCallback() {
+        this.render();
+    }
+    render() {
+        this.shadowRoot.innerHTML = `<svg viewBox="0 0 24 24">
+  <title>\`\${a}\`</title>
+</svg>`;
+    }
+}
+if (!customElements.get("svg-component")) {
+    customElements.define("svg-component", SvgComponent);
+}
+export default SvgComponent;
+"#
+    );
+  }
+
+  fn transform_file(code: &str) -> Result<TransformOutput, SvgrError> {
+    transform(
+      code.to_string(),
+      Config {
+        runtime_config: false,
+        ..Default::default()
+      },
+      State {
+        file_path: Some("icons/star.svg".to_string()),
+        component_name: None,
+        caller: None,
+      },
+    )
+  }
+
+  #[test]
+  fn fails_with_empty_input() {
+    let err = transform_file("  \n").unwrap_err();
+    assert_eq!(err.code(), "empty-input");
+    assert_eq!(err.file_path(), Some("icons/star.svg"));
+    assert_eq!(err.to_string(), "empty SVG input in icons/star.svg");
+  }
+
+  #[test]
+  fn fails_without_root_element() {
+    let err = transform_file("<!-- star -->").unwrap_err();
+    assert_eq!(err.code(), "no-root-element");
+    assert_eq!(err.to_string(), "no root element found in icons/star.svg");
+  }
+
+  #[test]
+  fn fails_with_non_svg_root_element() {
+    let err = transform_file("<?xml version=\"1.0\"?>\n<div><path/></div>").unwrap_err();
+    assert_eq!(err.code(), "non-svg-root");
+
+    let span = err.span().unwrap();
+    assert_eq!(span.file_path.as_deref(), Some("icons/star.svg"));
+    assert_eq!(span.loc, Location { line: 2, column: 1 });
+    assert_eq!(span.start, 22);
+    assert_eq!(
+      err.to_string(),
+      "the root element must be <svg>, found <div> at icons/star.svg:2:1\n2 | <div><path/></div>\n  | ^"
+    );
+  }
+
+  #[test]
+  fn fails_with_unterminated_tag_name() {
+    let err = transform_file("<svg>\n  <path/>\n</svg").unwrap_err();
+    assert_eq!(err.code(), "parse-error");
+    assert_eq!(err.span().unwrap().loc, Location { line: 3, column: 1 });
+
+    let err = transform_file("<svg").unwrap_err();
+    assert_eq!(err.code(), "parse-error");
+  }
+
+  #[test]
+  fn fails_with_out_of_range_character_references() {
+    let err = transform_file("<svg a=\"&#xFFFFFFFF;\"/>").unwrap_err();
+    assert_eq!(err.code(), "parse-error");
+    let span = err.span().unwrap();
+    assert_eq!((span.start, span.end), (8, 20));
+
+    let err = transform_file("<svg><text>&#99999999999;</text></svg>").unwrap_err();
+    assert_eq!(err.code(), "parse-error");
+    assert_eq!(
+      err.span().unwrap().loc,
+      Location {
+        line: 1,
+        column: 12
+      }
+    );
+
+    let result = transform_file("<svg><!-- &#99999999999; --><text>&#x1F600;&#xD800;</text></svg>");
+    assert!(result.is_ok());
+  }
+}
@@ -0,0 +1,139 @@
+use swc_core::ecma::ast::*;
+
+use crate::Location;
+
+/// A problem found while transforming the SVG that did not prevent the component from being generated.
+#[derive(Debug, Clone, PartialEq)]
+pub struct Warning {
+  /// A stable identifier of the kind of warning, for example `react-native-unsupported-element`.
+  pub code: String,
+
+  /// A human-readable description of the warning.
+  pub message: String,
+
+  /// The position in the SVG source code the warning relates to, if any.
+  pub loc: Option<Location>,
+}
+
+/// A file generated with the component, like the CSS module of `css_modules`.
+#[derive(Debug, Clone, PartialEq)]
+pub struct OutputFile {
+  /// The path of the file, relative to the directory of the component.
+  pub path: String,
+
+  /// The content of the file.
+  pub code: String,
+}
+
+/// The result of a transformation.
+#[derive(Debug, Clone)]
+pub struct TransformOutput {
+  /// The generated code of the component.
+  pub code: String,
+
+  /// The v3 source map of the generated code as JSON, only generated when the `source_maps` option is enabled.
+  pub map: Option<String>,
+
+  /// The name of the component, either given in the state or derived from the file path.
+  pub component_name: String,
+
+  /// The names exported by the generated module, `default` for a default export.
+  pub exports: Vec<String>,
+
+  /// The sources imported by the generated module, in order.
+  pub imports: Vec<String>,
+
+  /// The warnings reported while transforming the SVG.
+  pub warnings: Vec<Warning>,
+
+  /// The files generated with the component, to write next to it.
+  pub files: Vec<OutputFile>,
+
+  /// The final module the code is generated from, only kept when the `ast` option is enabled.
+  pub module: Option<Module>,
+}
+
+fn export_name(name: &ModuleExportName) -> String {
+  match name {
+    ModuleExportName::Ident(ident) => ident.sym.to_string(),
+    ModuleExportName::Str(str) => str.value.to_string(),
+  }
+}
+
+fn collect_pat_names(pat: &Pat, names: &mut Vec<String>) {
+  match pat {
+    Pat::Ident(ident) => names.push(ident.id.sym.to_string()),
+    Pat::Array(array) => {
+      for elem in array.elems.iter().flatten() {
+        collect_pat_names(elem, names);
+      }
+    }
+    Pat::Object(object) => {
+      for prop in &object.props {
+        match prop {
+          ObjectPatProp::KeyValue(key_value) => collect_pat_names(&key_value.value, names),
+          ObjectPatProp::Assign(assign) => names.push(assign.key.sym.to_string()),
+          ObjectPatProp::Rest(rest) => collect_pat_names(&rest.arg, names),
+        }
+      }
+    }
+    Pat::Rest(rest) => collect_pat_names(&rest.arg, names),
+    Pat::Assign(assign) => collect_pat_names(&assign.left, names),
+    _ => {}
+  }
+}
+
+/// The names exported by a module, in order.
+pub fn get_exports(module: &Module) -> Vec<String> {
+  let mut exports = vec![];
+
+  for item in &module.body {
+    let ModuleItem::ModuleDecl(decl) = item else {
+      continue;
+    };
+    match decl {
+      ModuleDecl::ExportDefaultDecl(_) | ModuleDecl::ExportDefaultExpr(_) => {
+        exports.push("default".to_string());
+      }
+      ModuleDecl::ExportNamed(named) => {
+        for specifier in &named.specifiers {
+          match specifier {
+            ExportSpecifier::Named(named) => {
+              exports.push(export_name(named.exported.as_ref().unwrap_or(&named.orig)));
+            }
+            ExportSpecifier::Default(default) => exports.push(default.exported.sym.to_string()),
+            ExportSpecifier::Namespace(namespace) => exports.push(export_name(&namespace.name)),
+          }
+        }
+      }
+      ModuleDecl::ExportDecl(export) => match &export.decl {
+        Decl::Class(class) => exports.push(class.ident.sym.to_string()),
+        Decl::Fn(function) => exports.push(function.ident.sym.to_string()),
+        Decl::Var(var) => {
+          for decl in &var.decls {
+            collect_pat_names(&decl.name, &mut exports);
+          }
+        }
+        Decl::TsInterface(interface) => exports.push(interface.id.sym.to_string()),
+        Decl::TsTypeAlias(alias) => exports.push(alias.id.sym.to_string()),
+        Decl::TsEnum(ts_enum) => exports.push(ts_enum.id.sym.to_string()),
+        _ => {}
+      },
+      _ => {}
+    }
+  }
+
+  exports
+}
+
+/// The sources imported by a module, in order.
+pub fn get_imports(module: &Module) -> Vec<String> {
+  module
+    .body
+    .iter()
+    .filter_map(|item| match item {
+      ModuleItem::ModuleDecl(ModuleDecl::Import(import)) => Some(import.src.value.to_string()),
+      _ => None,
+    })
+    .collect()
+}
@@ -0,0 +1,382 @@
+use std::{cell::RefCell, fmt, sync::Arc};
+
+use swc_core::{
+  common::comments::Comments,
+  ecma::{
+    ast::{JSXElement, Module},
+    visit::VisitMutWith,
+  },
+};
+
+use crate::{
+  add_jsx_attribute, core, remove_jsx_attribute, replace_jsx_attribute, svg_dynamic_title,
+  svg_em_dimensions, transform_react_native_svg, unique_ids, Config, FallbackIds, SvgrError,
+  Target, Warning,
+};
+
+/// The context given to each plugin hook.
+pub struct PluginContext<'a> {
+  /// The options used to transform the SVG.
+  pub config: &'a Config,
+
+  /// The name of the component that will be used in the generated component.
+  pub component_name: &'a str,
+
+  /// The path of the file that is transformed.
+  pub file_path: Option<&'a str>,
+
+  /// The comments attached to the generated module.
+  pub comments: &'a dyn Comments,
+
+  /// The warnings reported so far, returned in the output of the transformation.
+  pub warnings: &'a RefCell<Vec<Warning>>,
+}
+
+impl PluginContext<'_> {
+  /// Report a warning, it does not stop the transformation.
+  pub fn warn(&self, warning: Warning) {
+    self.warnings.borrow_mut().push(warning);
+  }
+}
+
+/// A plugin hooking into the transformation pipeline.
+///
+/// Every hook is optional, they are called in this order:
+///
+/// * `transform_document`: with the parsed SVG document, before it is converted into JSX
+/// * `transform_jsx`: with the JSX element of the SVG, before the component is created
+/// * `transform_module`: with the module of the component, before the code is generated
+pub trait Plugin: Send + Sync {
+  /// The name used to identify the plugin, for example to remove it from the default plugins.
+  fn name(&self) -> &str;
+
+  fn transform_document(
+    &self,
+    _document: &mut swc_xml::ast::Document,
+    _ctx: &PluginContext,
+  ) -> Result<(), SvgrError> {
+    Ok(())
+  }
+
+  fn transform_jsx(&self, _jsx: &mut JSXElement, _ctx: &PluginContext) -> Result<(), SvgrError> {
+    Ok(())
+  }
+
+  fn transform_module(&self, _module: &mut Module, _ctx: &PluginContext) -> Result<(), SvgrError> {
+    Ok(())
+  }
+}
+
+impl fmt::Debug for dyn Plugin {
+  fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+    f.debug_tuple("Plugin").field(&self.name()).finish()
+  }
+}
+
+struct RemoveJsxAttribute;
+
+impl Plugin for RemoveJsxAttribute {
+  fn name(&self) -> &str {
+    "remove-jsx-attribute"
+  }
+
+  fn transform_module(&self, module: &mut Module, ctx: &PluginContext) -> Result<(), SvgrError> {
+    module.visit_mut_with(&mut remove_jsx_attribute::Visitor::new(ctx.config));
+    Ok(())
+  }
+}
+
+struct AddJsxAttribute;
+
+impl Plugin for AddJsxAttribute {
+  fn name(&self) -> &str {
+    "add-jsx-attribute"
+  }
+
+  fn transform_module(&self, module: &mut Module, ctx: &PluginContext) -> Result<(), SvgrError> {
+    module.visit_mut_with(&mut add_jsx_attribute::Visitor::new(ctx.config));
+    Ok(())
+  }
+}
+
+struct SvgEmDimensions;
+
+impl Plugin for SvgEmDimensions {
+  fn name(&self) -> &str {
+    "svg-em-dimensions"
+  }
+
+  fn transform_module(&self, module: &mut Module, ctx: &PluginContext) -> Result<(), SvgrError> {
+    let icon = match ctx.config.icon {
+      Some(core::config::Icon::Bool(b)) => b,
+      None => false,
+      _ => true,
+    };
+    if icon && ctx.config.dimensions {
+      module.visit_mut_with(&mut svg_em_dimensions::Visitor::new(ctx.config));
+    }
+    Ok(())
+  }
+}
+
+struct ReplaceJsxAttributeValue;
+
+impl Plugin for ReplaceJsxAttributeValue {
+  fn name(&self) -> &str {
+    "replace-jsx-attribute-value"
+  }
+
+  fn transform_module(&self, module: &mut Module, ctx: &PluginContext) -> Result<(), SvgrError> {
+    if ctx.config.replace_attr_values.is_some() {
+      module.visit_mut_with(&mut replace_jsx_attribute::Visitor::new(ctx.config));
+    }
+    Ok(())
+  }
+}
+
+struct SvgDynamicTitle;
+
+impl Plugin for SvgDynamicTitle {
+  fn name(&self) -> &str {
+    "svg-dynamic-title"
+  }
+
+  fn transform_module(&self, module: &mut Module, ctx: &PluginContext) -> Result<(), SvgrError> {
+    if ctx.config.title_prop {
+      let fallback_id = ctx.config.fallback_ids != FallbackIds::None;
+      module.visit_mut_with(&mut svg_dynamic_title::Visitor::new(
+        "title".to_string(),
+        fallback_id,
+      ));
+    }
+    Ok(())
+  }
+}
+
+struct SvgDynamicDesc;
+
+impl Plugin for SvgDynamicDesc {
+  fn name(&self) -> &str {
+    "svg-dynamic-desc"
+  }
+
+  fn transform_module(&self, module: &mut Module, ctx: &PluginContext) -> Result<(), SvgrError> {
+    if ctx.config.desc_prop {
+      let fallback_id = ctx.config.fallback_ids != FallbackIds::None;
+      module.visit_mut_with(&mut svg_dynamic_title::Visitor::new(
+        "desc".to_string(),
+        fallback_id,
+      ));
+    }
+    Ok(())
+  }
+}
+
+struct UniqueIds;
+
+impl Plugin for UniqueIds {
+  fn name(&self) -> &str {
+    "unique-ids"
+  }
+
+  fn transform_module(&self, module: &mut Module, ctx: &PluginContext) -> Result<(), SvgrError> {
+    unique_ids::transform(module, ctx.config, ctx.component_name, ctx.file_path)
+  }
+}
+
+struct TransformReactNativeSvg;
+
+impl Plugin for TransformReactNativeSvg {
+  fn name(&self) -> &str {
+    "transform-react-native-svg"
+  }
+
+  fn transform_module(&self, module: &mut Module, ctx: &PluginContext) -> Result<(), SvgrError> {
+    if ctx.config.native && ctx.config.target == Target::React {
+      let mut visitor = transform_react_native_svg::Visitor::new(ctx.config, ctx.comments);
+      module.visit_mut_with(&mut visitor);
+      let elements = visitor.rejected_components();
+      if !elements.is_empty() {
+        return Err(SvgrError::UnsupportedNativeElements {
+          file_path: ctx.file_path.map(|file_path| file_path.to_string()),
+          elements,
+        });
+      }
+      for element in visitor.unsupported_components() {
+        ctx.warn(Warning {
+          code: "react-native-unsupported-element".to_string(),
+          message: format!(
+            "<{}> is not supported by react-native-svg and has been dropped",
+            element
+          ),
+          loc: None,
+        });
+      }
+    }
+    Ok(())
+  }
+}
+
+const BUILTIN_PLUGIN_NAMES: [&str; 8] = [
+  "remove-jsx-attribute",
+  "add-jsx-attribute",
+  "svg-em-dimensions",
+  "replace-jsx-attribute-value",
+  "svg-dynamic-title",
+  "svg-dynamic-desc",
+  "unique-ids",
+  "transform-react-native-svg",
+];
+
+/// Get a built-in plugin by its name.
+pub fn get_builtin_plugin(name: &str) -> Option<Arc<dyn Plugin>> {
+  let plugin: Arc<dyn Plugin> = match name {
+    "remove-jsx-attribute" => Arc::new(RemoveJsxAttribute),
+    "add-jsx-attribute" => Arc::new(AddJsxAttribute),
+    "svg-em-dimensions" => Arc::new(SvgEmDimensions),
+    "replace-jsx-attribute-value" => Arc::new(ReplaceJsxAttributeValue),
+    "svg-dynamic-title" => Arc::new(SvgDynamicTitle),
+    "svg-dynamic-desc" => Arc::new(SvgDynamicDesc),
+    "unique-ids" => Arc::new(UniqueIds),
+    "transform-react-native-svg" => Arc::new(TransformReactNativeSvg),
+    _ => return None,
+  };
+  Some(plugin)
+}
+
+/// The built-in plugins, in the order they are applied by default.
+pub fn default_plugins() -> Vec<Arc<dyn Plugin>> {
+  BUILTIN_PLUGIN_NAMES
+    .iter()
+    .filter_map(|name| get_builtin_plugin(name))
+    .collect()
+}
+
+#[cfg(test)]
+mod tests {
+  use super::*;
+  use crate::{transform, State};
+
+  struct UppercaseIds;
+
+  impl Plugin for UppercaseIds {
+    fn name(&self) -> &str {
+      "uppercase-ids"
+    }
+
+    fn transform_document(
+      &self,
+      document: &mut swc_xml::ast::Document,
+      _ctx: &PluginContext,
+    ) -> Result<(), SvgrError> {
+      struct Visitor;
+
+      impl swc_xml::visit::VisitMut for Visitor {
+        fn visit_mut_attribute(&mut self, n: &mut swc_xml::ast::Attribute) {
+          if n.name == *"id" {
+            n.value = n.value.as_ref().map(|v| v.to_uppercase().into());
+          }
+        }
+      }
+
+      swc_xml::visit::VisitMutWith::visit_mut_with(document, &mut Visitor);
+      Ok(())
+    }
+  }
+
+  struct RejectComponent;
+
+  impl Plugin for RejectComponent {
+    fn name(&self) -> &str {
+      "reject-component"
+    }
+
+    fn transform_jsx(&self, _jsx: &mut JSXElement, ctx: &PluginContext) -> Result<(), SvgrError> {
+      Err(SvgrError::Configuration(format!(
+        "{} is not allowed",
+        ctx.component_name
+      )))
+    }
+  }
+
+  #[test]
+  fn default_plugins_are_built_in_plugins() {
+    let names = default_plugins()
+      .iter()
+      .map(|plugin| plugin.name().to_string())
+      .collect::<Vec<String>>();
+    assert_eq!(names, BUILTIN_PLUGIN_NAMES);
+  }
+
+  #[test]
+  fn runs_custom_document_plugins() {
+    let mut plugins = default_plugins();
+    plugins.push(Arc::new(UppercaseIds));
+
+    let result = transform(
+      r#"<svg width="10"><path id="a"/></svg>"#.to_string(),
+      Config {
+        plugins,
+        ..Default::default()
+      },
+      Default::default(),
+    )
+    .unwrap()
+    .code;
+
+    assert_eq!(
+      result,
+      r#"import * as React from "react";
+const SvgComponent = (props)=><svg width={10} {...props}><path id="A"/></svg>;
+export default SvgComponent;
+"#
+    );
+  }
+
+  #[test]
+  fn drops_plugins_not_in_the_list() {
+    let plugins = default_plugins()
+      .into_iter()
+      .filter(|plugin| plugin.name() != "add-jsx-attribute")
+      .collect();
+
+    let result = transform(
+      r#"<svg width="10"><path/></svg>"#.to_string(),
+      Config {
+        plugins,
+        ..Default::default()
+      },
+      Default::default(),
+    )
+    .unwrap()
+    .code;
+
+    assert_eq!(
+      result,
+      r#"import * as React from "react";
+const SvgComponent = (props)=><svg width={10}><path/></svg>;
+export default SvgComponent;
+"#
+    );
+  }
+
+  #[test]
+  fn plugins_can_fail_the_transformation() {
+    let result = transform(
+      r#"<svg/>"#.to_string(),
+      Config {
+        plugins: vec![Arc::new(RejectComponent)],
+        ..Default::default()
+      },
+      State {
+        component_name: Some("Forbidden".to_string()),
+        ..Default::default()
+      },
+    );
+
+    assert_eq!(
+      result.unwrap_err().to_string(),
+      "invalid configuration option: Forbidden is not allowed"
+    );
+  }
+}
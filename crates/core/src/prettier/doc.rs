@@ -0,0 +1,450 @@
+/// The intermediate representation of the printed code, following the algorithm of
+/// ["A prettier printer"](https://homepages.inf.ed.ac.uk/wadler/papers/prettier/prettier.pdf) used by Prettier.
+#[derive(Debug, Clone)]
+pub enum Doc {
+  Text(String),
+  Concat(Vec<Doc>),
+  /// Printed on a single line if it fits in the print width, otherwise all its direct lines are broken.
+  Group {
+    contents: Box<Doc>,
+    should_break: bool,
+  },
+  Indent(Box<Doc>),
+  /// A line break when the enclosing group is broken, otherwise a space or nothing when `soft`.
+  Line {
+    soft: bool,
+    hard: bool,
+  },
+  /// A line break without indentation, which breaks the enclosing groups, like in template literals.
+  LiteralLine,
+  IfBreak {
+    break_contents: Box<Doc>,
+    flat_contents: Box<Doc>,
+  },
+  /// Deferred until the next line break, and not counted when measuring the line, like trailing comments.
+  LineSuffix(Box<Doc>),
+  /// Printed flat if it fits, otherwise the first of the other states whose first line fits,
+  /// or the last one broken. Like Prettier, used to hug the last argument of a call.
+  ConditionalGroup(Vec<Doc>),
+}
+
+pub fn nil() -> Doc {
+  Doc::Concat(vec![])
+}
+
+pub fn text(s: impl Into<String>) -> Doc {
+  Doc::Text(s.into())
+}
+
+pub fn concat(docs: Vec<Doc>) -> Doc {
+  Doc::Concat(docs)
+}
+
+pub fn group(doc: Doc) -> Doc {
+  Doc::Group {
+    contents: Box::new(doc),
+    should_break: false,
+  }
+}
+
+pub fn group_with_break(doc: Doc, should_break: bool) -> Doc {
+  Doc::Group {
+    contents: Box::new(doc),
+    should_break,
+  }
+}
+
+pub fn indent(doc: Doc) -> Doc {
+  Doc::Indent(Box::new(doc))
+}
+
+pub fn line() -> Doc {
+  Doc::Line {
+    soft: false,
+    hard: false,
+  }
+}
+
+pub fn softline() -> Doc {
+  Doc::Line {
+    soft: true,
+    hard: false,
+  }
+}
+
+pub fn hardline() -> Doc {
+  Doc::Line {
+    soft: false,
+    hard: true,
+  }
+}
+
+pub fn literalline() -> Doc {
+  Doc::LiteralLine
+}
+
+pub fn if_break(break_contents: Doc, flat_contents: Doc) -> Doc {
+  Doc::IfBreak {
+    break_contents: Box::new(break_contents),
+    flat_contents: Box::new(flat_contents),
+  }
+}
+
+pub fn line_suffix(doc: Doc) -> Doc {
+  Doc::LineSuffix(Box::new(doc))
+}
+
+pub fn conditional_group(states: Vec<Doc>) -> Doc {
+  Doc::ConditionalGroup(states)
+}
+
+/// Whether the doc contains a hard line or a group which is already broken.
+pub fn will_break(doc: &Doc) -> bool {
+  match doc {
+    Doc::Text(_) | Doc::LineSuffix(_) => false,
+    Doc::Concat(docs) => docs.iter().any(will_break),
+    Doc::Group {
+      contents,
+      should_break,
+    } => *should_break || will_break(contents),
+    Doc::Indent(doc) => will_break(doc),
+    Doc::Line { hard, .. } => *hard,
+    Doc::LiteralLine => true,
+    Doc::IfBreak {
+      break_contents,
+      flat_contents,
+    } => will_break(break_contents) || will_break(flat_contents),
+    Doc::ConditionalGroup(states) => states.first().is_some_and(will_break),
+  }
+}
+
+pub fn join(separator: Doc, docs: Vec<Doc>) -> Doc {
+  let mut parts = Vec::with_capacity(docs.len() * 2);
+  for (i, doc) in docs.into_iter().enumerate() {
+    if i > 0 {
+      parts.push(separator.clone());
+    }
+    parts.push(doc);
+  }
+  Doc::Concat(parts)
+}
+
+/// Break the groups containing a hard line, returns whether the doc contains a hard line.
+fn propagate_breaks(doc: &mut Doc) -> bool {
+  match doc {
+    Doc::Text(_) => false,
+    Doc::Concat(docs) => docs
+      .iter_mut()
+      .fold(false, |acc, doc| propagate_breaks(doc) || acc),
+    Doc::Group {
+      contents,
+      should_break,
+    } => {
+      let has_hardline = propagate_breaks(contents);
+      *should_break |= has_hardline;
+      *should_break
+    }
+    Doc::Indent(doc) => propagate_breaks(doc),
+    Doc::Line { hard, .. } => *hard,
+    Doc::LiteralLine => true,
+    Doc::IfBreak {
+      break_contents,
+      flat_contents,
+    } => {
+      let break_has_hardline = propagate_breaks(break_contents);
+      propagate_breaks(flat_contents) || break_has_hardline
+    }
+    Doc::LineSuffix(_) => false,
+    // The other states are expected to break, only the first one breaks the enclosing groups.
+    Doc::ConditionalGroup(states) => states
+      .iter_mut()
+      .enumerate()
+      .fold(false, |acc, (i, state)| {
+        propagate_breaks(state) && i == 0 || acc
+      }),
+  }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq)]
+enum Mode {
+  Break,
+  Flat,
+}
+
+type Command<'a> = (usize, Mode, &'a Doc);
+
+/// Whether the next command fits in the remaining width, until the first line break of the rest commands.
+fn fits(next: Command, rest: &[Command], width: isize) -> bool {
+  let mut width = width;
+  let mut rest_index = rest.len();
+  let mut stack = vec![(next.1, next.2)];
+
+  loop {
+    let (mode, doc) = match stack.pop() {
+      Some(command) => command,
+      None => {
+        if rest_index == 0 {
+          return true;
+        }
+        rest_index -= 1;
+        (rest[rest_index].1, rest[rest_index].2)
+      }
+    };
+
+    match doc {
+      Doc::Text(s) => {
+        width -= s.chars().count() as isize;
+        if width < 0 {
+          return false;
+        }
+      }
+      Doc::Concat(docs) => {
+        for doc in docs.iter().rev() {
+          stack.push((mode, doc));
+        }
+      }
+      Doc::Group {
+        contents,
+        should_break,
+      } => {
+        let mode = if *should_break { Mode::Break } else { mode };
+        stack.push((mode, contents));
+      }
+      Doc::Indent(doc) => stack.push((mode, doc)),
+      Doc::Line { soft, hard } => {
+        if mode == Mode::Break || *hard {
+          return true;
+        }
+        if !soft {
+          width -= 1;
+        }
+      }
+      Doc::LiteralLine => return true,
+      Doc::IfBreak {
+        break_contents,
+        flat_contents,
+      } => {
+        let doc = if mode == Mode::Break {
+          break_contents
+        } else {
+          flat_contents
+        };
+        stack.push((mode, doc));
+      }
+      Doc::LineSuffix(_) => {}
+      Doc::ConditionalGroup(states) => {
+        let state = match mode {
+          Mode::Break => states.last(),
+          Mode::Flat => states.first(),
+        };
+        if let Some(state) = state {
+          stack.push((mode, state));
+        }
+      }
+    }
+  }
+}
+
+/// Print the doc, breaking the groups which do not fit in `print_width`.
+pub fn print(mut doc: Doc, print_width: usize, indentation: &str) -> String {
+  propagate_breaks(&mut doc);
+
+  let indentation_width = indentation.chars().count();
+  let mut out = String::new();
+  let mut pos = 0;
+  let mut commands: Vec<Command> = vec![(0, Mode::Break, &doc)];
+  let mut line_suffixes: Vec<Command> = vec![];
+
+  loop {
+    let Some((level, mode, doc)) = commands.pop() else {
+      if line_suffixes.is_empty() {
+        break;
+      }
+      commands.extend(line_suffixes.drain(..).rev());
+      continue;
+    };
+    match doc {
+      Doc::Text(s) => {
+        out.push_str(s);
+        pos += s.chars().count();
+      }
+      Doc::Concat(docs) => {
+        for doc in docs.iter().rev() {
+          commands.push((level, mode, doc));
+        }
+      }
+      Doc::Group {
+        contents,
+        should_break,
+      } => {
+        if mode == Mode::Flat && !should_break {
+          commands.push((level, Mode::Flat, contents));
+        } else {
+          let next = (level, Mode::Flat, contents.as_ref());
+          let width = print_width as isize - pos as isize;
+          if !should_break && fits(next, &commands, width) {
+            commands.push(next);
+          } else {
+            commands.push((level, Mode::Break, contents));
+          }
+        }
+      }
+      Doc::Indent(doc) => commands.push((level + 1, mode, doc)),
+      Doc::Line { soft, hard } => {
+        if mode == Mode::Flat && !hard {
+          if !soft {
+            out.push(' ');
+            pos += 1;
+          }
+        } else if !line_suffixes.is_empty() {
+          commands.push((level, mode, doc));
+          commands.extend(line_suffixes.drain(..).rev());
+        } else {
+          out.truncate(out.trim_end_matches([' ', '\t']).len());
+          out.push('\n');
+          out.push_str(&indentation.repeat(level));
+          pos = indentation_width * level;
+        }
+      }
+      Doc::LiteralLine => {
+        if !line_suffixes.is_empty() {
+          commands.push((level, mode, doc));
+          commands.extend(line_suffixes.drain(..).rev());
+        } else {
+          out.push('\n');
+          pos = 0;
+        }
+      }
+      Doc::IfBreak {
+        break_contents,
+        flat_contents,
+      } => {
+        let doc = if mode == Mode::Break {
+          break_contents
+        } else {
+          flat_contents
+        };
+        commands.push((level, mode, doc));
+      }
+      Doc::LineSuffix(doc) => line_suffixes.push((level, mode, doc)),
+      Doc::ConditionalGroup(states) => {
+        let Some((last, _)) = states.split_last() else {
+          continue;
+        };
+        let width = print_width as isize - pos as isize;
+        let state = match mode {
+          Mode::Flat => Some(&states[0]),
+          Mode::Break => states
+            .iter()
+            .find(|state| fits((level, Mode::Flat, state), &commands, width)),
+        };
+        match state {
+          Some(state) => commands.push((level, Mode::Flat, state)),
+          None => commands.push((level, Mode::Break, last)),
+        }
+      }
+    }
+  }
+
+  out
+}
+
+#[cfg(test)]
+mod tests {
+  use super::*;
+
+  fn list(items: &[&str]) -> Doc {
+    group(concat(vec![
+      text("["),
+      indent(concat(vec![
+        softline(),
+        join(
+          concat(vec![text(","), line()]),
+          items.iter().map(|item| text(*item)).collect(),
+        ),
+      ])),
+      if_break(text(","), nil()),
+      softline(),
+      text("]"),
+    ]))
+  }
+
+  #[test]
+  fn prints_group_on_one_line_when_it_fits() {
+    assert_eq!(print(list(&["a", "b", "c"]), 80, "  "), "[a, b, c]");
+  }
+
+  #[test]
+  fn breaks_group_when_it_does_not_fit() {
+    assert_eq!(
+      print(list(&["aaaa", "bbbb", "cccc"]), 10, "  "),
+      "[\n  aaaa,\n  bbbb,\n  cccc,\n]"
+    );
+  }
+
+  #[test]
+  fn breaks_group_containing_hardline() {
+    let doc = group(concat(vec![
+      text("{"),
+      indent(concat(vec![hardline(), text("a")])),
+      line(),
+      text("}"),
+    ]));
+    assert_eq!(print(doc, 80, "\t"), "{\n\ta\n}");
+  }
+
+  #[test]
+  fn measures_rest_commands_until_first_line_break() {
+    let doc = concat(vec![list(&["a", "b"]), text(" + long tail"), hardline()]);
+    assert_eq!(print(doc, 12, "  "), "[\n  a,\n  b,\n] + long tail\n");
+  }
+
+  #[test]
+  fn defers_line_suffix_to_the_next_line_break() {
+    let doc = concat(vec![
+      list(&["a", "b"]),
+      line_suffix(text(" // long comment")),
+      text(";"),
+      hardline(),
+    ]);
+    assert_eq!(print(doc, 10, "  "), "[a, b]; // long comment\n");
+  }
+
+  #[test]
+  fn prints_literal_lines_without_indentation() {
+    let doc = concat(vec![
+      text("{"),
+      indent(concat(vec![
+        hardline(),
+        text("`a "),
+        literalline(),
+        text("  b`"),
+      ])),
+      hardline(),
+      text("}"),
+    ]);
+    assert_eq!(print(doc, 80, "  "), "{\n  `a \n  b`\n}");
+  }
+
+  #[test]
+  fn prints_first_conditional_state_which_fits() {
+    let hugged = concat(vec![
+      text("f(a, "),
+      group_with_break(list(&["b", "c"]), true),
+      text(")"),
+    ]);
+    let expanded = concat(vec![
+      text("f("),
+      indent(concat(vec![hardline(), text("a")])),
+      text(")"),
+    ]);
+    let doc = conditional_group(vec![
+      concat(vec![text("f(a, "), list(&["b", "c"]), text(")")]),
+      hugged,
+      expanded,
+    ]);
+    assert_eq!(print(doc.clone(), 80, "  "), "f(a, [b, c])");
+    assert_eq!(print(doc.clone(), 8, "  "), "f(a, [\n  b,\n  c,\n])");
+    assert_eq!(print(doc, 4, "  "), "f(\n  a)");
+  }
+}
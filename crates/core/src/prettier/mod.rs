@@ -0,0 +1,1737 @@
+use std::cell::Cell;
+
+use swc_core::{
+  common::{
+    comments::{Comment, Comments},
+    BytePos, Spanned,
+  },
+  ecma::{
+    ast::*,
+    codegen::{to_code, Node},
+  },
+};
+
+use crate::core::config::{PrettierConfig, TrailingComma};
+
+mod doc;
+
+use self::doc::*;
+
+/// Format the module of the component, following the output of Prettier.
+///
+/// Only the syntax used by the generated components is laid out natively,
+/// other nodes, which can come from custom templates or plugins, are printed as is.
+/// The comments of the module and of the empty JSX expressions are read from `comments`.
+pub fn format(
+  module: &Module,
+  config: &PrettierConfig,
+  comments: Option<&dyn Comments>,
+) -> Formatted {
+  let printer = Printer {
+    config,
+    comments,
+    unformatted: Cell::new(false),
+  };
+  let doc = printer.module(module);
+  Formatted {
+    code: doc::print(doc, config.print_width, &get_indentation(config)),
+    unformatted: printer.unformatted.get(),
+  }
+}
+
+fn get_indentation(config: &PrettierConfig) -> String {
+  if config.use_tabs {
+    "\t".to_string()
+  } else {
+    " ".repeat(config.tab_width)
+  }
+}
+
+/// The code of a formatted module.
+pub struct Formatted {
+  pub code: String,
+
+  /// Whether some nodes were printed as is, without following the config.
+  pub unformatted: bool,
+}
+
+/// The kinds of module items, a blank line is printed between items of different kinds.
+#[derive(PartialEq)]
+enum ItemKind {
+  Import,
+  Export,
+  Type,
+  Other,
+}
+
+fn get_item_kind(item: &ModuleItem) -> ItemKind {
+  match item {
+    ModuleItem::ModuleDecl(ModuleDecl::Import(_)) => ItemKind::Import,
+    ModuleItem::ModuleDecl(_) => ItemKind::Export,
+    ModuleItem::Stmt(Stmt::Decl(Decl::TsInterface(_) | Decl::TsTypeAlias(_))) => ItemKind::Type,
+    ModuleItem::Stmt(_) => ItemKind::Other,
+  }
+}
+
+fn is_jsx(expr: &Expr) -> bool {
+  matches!(expr, Expr::JSXElement(_) | Expr::JSXFragment(_))
+}
+
+fn is_huggable(expr: &Expr) -> bool {
+  matches!(
+    expr,
+    Expr::Arrow(_) | Expr::Fn(_) | Expr::Object(_) | Expr::Array(_)
+  )
+}
+
+fn is_simple_arg(expr: &Expr) -> bool {
+  matches!(expr, Expr::Ident(_) | Expr::Lit(_) | Expr::This(_))
+}
+
+/// Whether the function is printed by the printer, with a body and without type parameters.
+fn is_printable_function(n: &Function) -> bool {
+  n.body.is_some() && n.type_params.is_none() && !n.is_generator
+}
+
+/// Whether the class is printed by the printer, without decorators or TypeScript syntax.
+fn is_printable_class(n: &Class) -> bool {
+  n.decorators.is_empty()
+    && !n.is_abstract
+    && n.type_params.is_none()
+    && n.super_type_params.is_none()
+    && n.implements.is_empty()
+}
+
+fn is_string_attr(attr: &JSXAttrOrSpread) -> bool {
+  match attr {
+    JSXAttrOrSpread::JSXAttr(JSXAttr {
+      value: Some(JSXAttrValue::Lit(Lit::Str(s))),
+      ..
+    }) => !s.value.contains('\n'),
+    _ => false,
+  }
+}
+
+/// Whether a statement starting with the expression would continue the previous statement
+/// when the semicolons are omitted, like when it starts with `[`, `(` or a template literal.
+fn needs_asi_protection(expr: &Expr) -> bool {
+  match expr {
+    Expr::Array(_)
+    | Expr::Paren(_)
+    | Expr::Tpl(_)
+    | Expr::JSXElement(_)
+    | Expr::JSXFragment(_)
+    | Expr::Lit(Lit::Regex(_)) => true,
+    Expr::Arrow(n) => !n.is_async,
+    Expr::Unary(n) => matches!(n.op, UnaryOp::Plus | UnaryOp::Minus),
+    Expr::Update(n) => !n.prefix && needs_asi_protection(&n.arg),
+    Expr::Member(n) => needs_asi_protection(&n.obj),
+    Expr::Call(CallExpr {
+      callee: Callee::Expr(callee),
+      ..
+    }) => needs_asi_protection(callee),
+    Expr::TaggedTpl(n) => needs_asi_protection(&n.tag),
+    Expr::Bin(n) => needs_asi_protection(&n.left),
+    Expr::Cond(n) => needs_asi_protection(&n.test),
+    Expr::Seq(n) => n
+      .exprs
+      .first()
+      .is_some_and(|expr| needs_asi_protection(expr)),
+    Expr::Assign(n) => match &n.left {
+      AssignTarget::Simple(SimpleAssignTarget::Member(member)) => needs_asi_protection(&member.obj),
+      AssignTarget::Pat(AssignTargetPat::Array(_)) => true,
+      _ => false,
+    },
+    Expr::TsAs(TsAsExpr { expr, .. })
+    | Expr::TsSatisfies(TsSatisfiesExpr { expr, .. })
+    | Expr::TsConstAssertion(TsConstAssertion { expr, .. })
+    | Expr::TsNonNull(TsNonNullExpr { expr, .. }) => needs_asi_protection(expr),
+    _ => false,
+  }
+}
+
+/// Clean the whitespaces of JSX text like JSX does: lines are trimmed and the empty ones are removed.
+fn clean_jsx_text(value: &str) -> String {
+  let lines: Vec<&str> = value.split('\n').collect();
+  let last = lines.len() - 1;
+  let mut cleaned = String::new();
+
+  for (i, line) in lines.iter().enumerate() {
+    let mut line = line.replace('\t', " ");
+    if i != 0 {
+      line = line.trim_start().to_string();
+    }
+    if i != last {
+      line = line.trim_end().to_string();
+    }
+    if line.is_empty() {
+      continue;
+    }
+    if !cleaned.is_empty() && i != 0 {
+      cleaned.push(' ');
+    }
+    cleaned.push_str(&line);
+  }
+
+  cleaned
+}
+
+enum JsxChild {
+  Doc(Doc),
+  Whitespace,
+}
+
+struct Printer<'a> {
+  config: &'a PrettierConfig,
+  comments: Option<&'a dyn Comments>,
+  /// Whether a node was printed as is by the swc code generator, ignoring the config.
+  unformatted: Cell<bool>,
+}
+
+impl<'a> Printer<'a> {
+  /// Print a node with the swc code generator, for the syntax not handled by the printer.
+  /// Its layout only follows the config when it is printed on a single line.
+  fn fallback(&self, node: &impl Node) -> Doc {
+    let code = to_code(node);
+    let code = code.trim_end();
+    if code.contains('\n') || (!self.config.semi && code.ends_with(';')) {
+      self.unformatted.set(true);
+    }
+    join(hardline(), code.lines().map(text).collect())
+  }
+
+  /// The block comments leading a position, each one on its own line.
+  fn leading_comments(&self, pos: BytePos) -> Vec<Doc> {
+    if pos.is_dummy() {
+      return vec![];
+    }
+    let comments = self.comments.and_then(|comments| comments.get_leading(pos));
+    Self::block_comments(comments.unwrap_or_default())
+  }
+
+  fn trailing_comments(&self, pos: BytePos) -> Vec<Doc> {
+    if pos.is_dummy() {
+      return vec![];
+    }
+    let comments = self
+      .comments
+      .and_then(|comments| comments.get_trailing(pos));
+    Self::block_comments(comments.unwrap_or_default())
+  }
+
+  fn block_comments(comments: Vec<Comment>) -> Vec<Doc> {
+    comments
+      .iter()
+      .map(|comment| {
+        let code = format!("/*{}*/", comment.text);
+        join(hardline(), code.lines().map(text).collect())
+      })
+      .collect()
+  }
+
+  fn semi(&self) -> Doc {
+    if self.config.semi {
+      text(";")
+    } else {
+      nil()
+    }
+  }
+
+  fn trailing_comma(&self, es5: bool) -> Doc {
+    let print = match self.config.trailing_comma {
+      TrailingComma::All => true,
+      TrailingComma::Es5 => es5,
+      TrailingComma::None => false,
+    };
+    if print {
+      if_break(text(","), nil())
+    } else {
+      nil()
+    }
+  }
+
+  /// A comma-separated list in brackets, on one line or with each item on its own line.
+  fn list(
+    &self,
+    open: &str,
+    items: Vec<Doc>,
+    close: &str,
+    trailing_comma: Doc,
+    space: bool,
+  ) -> Doc {
+    if items.is_empty() {
+      return text(format!("{}{}", open, close));
+    }
+    let line_doc = if space { line() } else { softline() };
+    group(concat(vec![
+      text(open),
+      indent(concat(vec![
+        line_doc.clone(),
+        join(concat(vec![text(","), line()]), items),
+      ])),
+      trailing_comma,
+      line_doc,
+      text(close),
+    ]))
+  }
+
+  fn string(&self, value: &str) -> Doc {
+    let (preferred, alternate) = if self.config.single_quote {
+      ('\'', '"')
+    } else {
+      ('"', '\'')
+    };
+    let quote = if value.matches(preferred).count() > value.matches(alternate).count() {
+      alternate
+    } else {
+      preferred
+    };
+
+    let mut s = String::with_capacity(value.len() + 2);
+    s.push(quote);
+    for c in value.chars() {
+      match c {
+        '\\' => s.push_str("\\\\"),
+        '\n' => s.push_str("\\n"),
+        '\r' => s.push_str("\\r"),
+        '\t' => s.push_str("\\t"),
+        '\u{2028}' => s.push_str("\\u2028"),
+        '\u{2029}' => s.push_str("\\u2029"),
+        c if c == quote => {
+          s.push('\\');
+          s.push(c);
+        }
+        c => s.push(c),
+      }
+    }
+    s.push(quote);
+    text(s)
+  }
+
+  fn module(&self, module: &Module) -> Doc {
+    let mut parts = vec![];
+    let mut previous_kind = None;
+
+    for comment in self.leading_comments(module.span.lo) {
+      parts.push(comment);
+      parts.push(hardline());
+    }
+
+    for item in &module.body {
+      let kind = get_item_kind(item);
+      if let Some(previous_kind) = previous_kind {
+        parts.push(hardline());
+        if previous_kind != kind {
+          parts.push(hardline());
+        }
+      }
+      parts.push(self.module_item(item));
+      for comment in self.trailing_comments(item.span().hi) {
+        parts.push(line_suffix(concat(vec![text(" "), comment])));
+      }
+      previous_kind = Some(kind);
+    }
+
+    if !parts.is_empty() {
+      parts.push(hardline());
+    }
+    concat(parts)
+  }
+
+  fn module_item(&self, item: &ModuleItem) -> Doc {
+    match item {
+      ModuleItem::ModuleDecl(ModuleDecl::Import(n)) => self.import_decl(n),
+      ModuleItem::ModuleDecl(ModuleDecl::ExportDefaultExpr(n)) => concat(vec![
+        text("export default "),
+        self.expr(&n.expr),
+        self.semi(),
+      ]),
+      ModuleItem::ModuleDecl(ModuleDecl::ExportNamed(n)) => self.named_export(n),
+      ModuleItem::ModuleDecl(ModuleDecl::ExportDecl(n)) => {
+        concat(vec![text("export "), self.decl(&n.decl)])
+      }
+      ModuleItem::ModuleDecl(n) => self.fallback(n),
+      ModuleItem::Stmt(n) => self.stmt(n),
+    }
+  }
+
+  fn module_export_name(&self, n: &ModuleExportName) -> Doc {
+    match n {
+      ModuleExportName::Ident(ident) => text(ident.sym.as_str()),
+      ModuleExportName::Str(s) => self.string(&s.value),
+    }
+  }
+
+  fn import_decl(&self, n: &ImportDecl) -> Doc {
+    if n.with.is_some() {
+      return self.fallback(n);
+    }
+
+    let mut parts = vec![text("import ")];
+    if n.type_only {
+      parts.push(text("type "));
+    }
+
+    let mut clauses = vec![];
+    let mut named = vec![];
+    for specifier in &n.specifiers {
+      match specifier {
+        ImportSpecifier::Default(s) => clauses.push(text(s.local.sym.as_str())),
+        ImportSpecifier::Namespace(s) => clauses.push(text(format!("* as {}", s.local.sym))),
+        ImportSpecifier::Named(s) => {
+          let mut specifier = vec![];
+          if s.is_type_only {
+            specifier.push(text("type "));
+          }
+          if let Some(imported) = &s.imported {
+            specifier.push(self.module_export_name(imported));
+            specifier.push(text(" as "));
+          }
+          specifier.push(text(s.local.sym.as_str()));
+          named.push(concat(specifier));
+        }
+      }
+    }
+    if !named.is_empty() {
+      // A single named specifier is only broken when there is also a default or namespace specifier.
+      if named.len() == 1 && clauses.is_empty() {
+        clauses.push(concat(vec![text("{ "), named.remove(0), text(" }")]));
+      } else {
+        clauses.push(self.list("{", named, "}", self.trailing_comma(true), true));
+      }
+    }
+
+    if !clauses.is_empty() {
+      parts.push(join(text(", "), clauses));
+      parts.push(text(" from "));
+    }
+    parts.push(self.string(&n.src.value));
+    parts.push(self.semi());
+    concat(parts)
+  }
+
+  fn named_export(&self, n: &NamedExport) -> Doc {
+    let mut specifiers = vec![];
+    for specifier in &n.specifiers {
+      match specifier {
+        ExportSpecifier::Named(s) => {
+          let mut specifier = vec![];
+          if s.is_type_only {
+            specifier.push(text("type "));
+          }
+          specifier.push(self.module_export_name(&s.orig));
+          if let Some(exported) = &s.exported {
+            specifier.push(text(" as "));
+            specifier.push(self.module_export_name(exported));
+          }
+          specifiers.push(concat(specifier));
+        }
+        _ => return self.fallback(n),
+      }
+    }
+
+    let mut parts = vec![text("export ")];
+    if n.type_only {
+      parts.push(text("type "));
+    }
+    parts.push(self.list("{", specifiers, "}", self.trailing_comma(true), true));
+    if let Some(src) = &n.src {
+      parts.push(text(" from "));
+      parts.push(self.string(&src.value));
+    }
+    parts.push(self.semi());
+    concat(parts)
+  }
+
+  fn stmt(&self, n: &Stmt) -> Doc {
+    match n {
+      Stmt::Decl(decl) => self.decl(decl),
+      Stmt::Expr(n) => concat(vec![
+        if !self.config.semi && needs_asi_protection(&n.expr) {
+          text(";")
+        } else {
+          nil()
+        },
+        self.expr(&n.expr),
+        self.semi(),
+      ]),
+      Stmt::Return(n) => match &n.arg {
+        Some(arg) => concat(vec![text("return "), self.wrapped_jsx(arg), self.semi()]),
+        None => concat(vec![text("return"), self.semi()]),
+      },
+      Stmt::Block(n) => self.block(n),
+      Stmt::If(n) => self.if_stmt(n),
+      n => self.fallback(n),
+    }
+  }
+
+  fn if_stmt(&self, n: &IfStmt) -> Doc {
+    let mut parts = vec![
+      text("if ("),
+      self.expr(&n.test),
+      text(")"),
+      self.clause(&n.cons),
+    ];
+    if let Some(alt) = &n.alt {
+      parts.push(match n.cons.as_ref() {
+        Stmt::Block(_) => text(" else"),
+        _ => concat(vec![hardline(), text("else")]),
+      });
+      parts.push(match alt.as_ref() {
+        Stmt::If(alt) => concat(vec![text(" "), self.if_stmt(alt)]),
+        alt => self.clause(alt),
+      });
+    }
+    concat(parts)
+  }
+
+  /// The body of a statement like `if`, a statement other than a block is indented on the next
+  /// line when it does not fit.
+  fn clause(&self, n: &Stmt) -> Doc {
+    match n {
+      Stmt::Block(block) => concat(vec![text(" "), self.block(block)]),
+      n => group(indent(concat(vec![line(), self.stmt(n)]))),
+    }
+  }
+
+  fn block(&self, n: &BlockStmt) -> Doc {
+    if n.stmts.is_empty() {
+      return text("{}");
+    }
+    concat(vec![
+      text("{"),
+      indent(concat(vec![
+        hardline(),
+        join(
+          hardline(),
+          n.stmts.iter().map(|stmt| self.stmt(stmt)).collect(),
+        ),
+      ])),
+      hardline(),
+      text("}"),
+    ])
+  }
+
+  fn decl(&self, n: &Decl) -> Doc {
+    match n {
+      Decl::Var(n) => concat(vec![self.var_decl(n), self.semi()]),
+      Decl::Fn(n) if !n.declare => self.function(Some(&n.ident), &n.function),
+      Decl::Class(n) if !n.declare && is_printable_class(&n.class) => {
+        self.class(Some(&n.ident), &n.class)
+      }
+      Decl::TsInterface(n) => self.interface(n),
+      Decl::TsTypeAlias(n) if !n.declare && n.type_params.is_none() => concat(vec![
+        text(format!("type {} = ", n.id.sym)),
+        self.fallback(&n.type_ann),
+        self.semi(),
+      ]),
+      n => self.fallback(n),
+    }
+  }
+
+  fn var_decl(&self, n: &VarDecl) -> Doc {
+    let decls = n
+      .decls
+      .iter()
+      .map(|decl| {
+        let mut parts = vec![self.pat(&decl.name)];
+        if let Some(init) = &decl.init {
+          parts.push(text(" = "));
+          parts.push(self.wrapped_jsx(init));
+        }
+        concat(parts)
+      })
+      .collect();
+    concat(vec![
+      text(format!("{} ", n.kind.as_str())),
+      join(text(", "), decls),
+    ])
+  }
+
+  fn function(&self, ident: Option<&Ident>, n: &Function) -> Doc {
+    let body = match &n.body {
+      Some(body) if is_printable_function(n) => body,
+      _ => return self.fallback(n),
+    };
+
+    let mut parts = vec![];
+    if n.is_async {
+      parts.push(text("async "));
+    }
+    parts.push(text("function"));
+    if let Some(ident) = ident {
+      parts.push(text(format!(" {}", ident.sym)));
+    }
+    let params: Vec<&Pat> = n.params.iter().map(|param| &param.pat).collect();
+    parts.push(self.params(&params));
+    if let Some(return_type) = &n.return_type {
+      parts.push(self.type_ann(return_type));
+    }
+    parts.push(text(" "));
+    parts.push(self.block(body));
+    concat(parts)
+  }
+
+  /// A class, its members are printed on their own line.
+  fn class(&self, ident: Option<&Ident>, n: &Class) -> Doc {
+    let mut parts = vec![text("class")];
+    if let Some(ident) = ident {
+      parts.push(text(format!(" {}", ident.sym)));
+    }
+    if let Some(super_class) = &n.super_class {
+      parts.push(text(" extends "));
+      parts.push(self.expr(super_class));
+    }
+    parts.push(text(" "));
+
+    let members = n
+      .body
+      .iter()
+      .filter(|member| !matches!(member, ClassMember::Empty(_)))
+      .collect::<Vec<_>>();
+    if members.is_empty() {
+      parts.push(text("{}"));
+      return concat(parts);
+    }
+
+    let mut body = vec![];
+    for (i, member) in members.iter().enumerate() {
+      body.push(hardline());
+      body.push(self.class_member(member));
+      // Without semicolons, a property would continue with the computed key of the next member.
+      if let (false, ClassMember::ClassProp(_)) = (self.config.semi, member) {
+        if let Some(
+          ClassMember::ClassProp(ClassProp {
+            key: PropName::Computed(_),
+            ..
+          })
+          | ClassMember::Method(ClassMethod {
+            key: PropName::Computed(_),
+            ..
+          }),
+        ) = members.get(i + 1)
+        {
+          body.push(text(";"));
+        }
+      }
+    }
+    parts.push(text("{"));
+    parts.push(indent(concat(body)));
+    parts.push(hardline());
+    parts.push(text("}"));
+    concat(parts)
+  }
+
+  fn class_member(&self, n: &ClassMember) -> Doc {
+    match n {
+      ClassMember::Constructor(c)
+        if c.accessibility.is_none()
+          && !c.is_optional
+          && c
+            .params
+            .iter()
+            .all(|param| matches!(param, ParamOrTsParamProp::Param(_))) =>
+      {
+        let Some(body) = &c.body else {
+          return self.fallback(n);
+        };
+        let params = c
+          .params
+          .iter()
+          .filter_map(|param| match param {
+            ParamOrTsParamProp::Param(param) => Some(&param.pat),
+            ParamOrTsParamProp::TsParamProp(_) => None,
+          })
+          .collect::<Vec<_>>();
+        concat(vec![
+          text("constructor"),
+          self.params(&params),
+          text(" "),
+          self.block(body),
+        ])
+      }
+      ClassMember::Method(m)
+        if is_printable_function(&m.function)
+          && m.accessibility.is_none()
+          && !m.is_abstract
+          && !m.is_optional
+          && !m.is_override =>
+      {
+        let kind = match m.kind {
+          MethodKind::Method => "",
+          MethodKind::Getter => "get ",
+          MethodKind::Setter => "set ",
+        };
+        concat(vec![
+          text(if m.is_static { "static " } else { "" }),
+          self.method(
+            concat(vec![text(kind), self.prop_name(&m.key)]),
+            &m.function,
+          ),
+        ])
+      }
+      ClassMember::ClassProp(p)
+        if p.decorators.is_empty()
+          && p.accessibility.is_none()
+          && !p.declare
+          && !p.is_abstract
+          && !p.is_optional
+          && !p.is_override
+          && !p.readonly
+          && !p.definite =>
+      {
+        let mut parts = vec![];
+        if p.is_static {
+          parts.push(text("static "));
+        }
+        parts.push(self.prop_name(&p.key));
+        parts.push(self.optional_type_ann(&p.type_ann));
+        if let Some(value) = &p.value {
+          parts.push(text(" = "));
+          parts.push(self.expr(value));
+        }
+        parts.push(self.semi());
+        concat(parts)
+      }
+      n => self.fallback(n),
+    }
+  }
+
+  /// A template literal, its lines are printed as is and its expressions are never broken.
+  fn tpl(&self, n: &Tpl) -> Doc {
+    let lines = |code: &str| join(literalline(), code.split('\n').map(text).collect());
+    let mut parts = vec![text("`")];
+    for (i, quasi) in n.quasis.iter().enumerate() {
+      parts.push(lines(&quasi.raw));
+      if let Some(expr) = n.exprs.get(i) {
+        let code = doc::print(
+          self.expr(expr),
+          isize::MAX as usize,
+          &get_indentation(self.config),
+        );
+        parts.push(text("${"));
+        parts.push(lines(&code));
+        parts.push(text("}"));
+      }
+    }
+    parts.push(text("`"));
+    concat(parts)
+  }
+
+  /// A method of an object or a class, like `setup() {}`, its function must be printable.
+  fn method(&self, key: Doc, n: &Function) -> Doc {
+    let Some(body) = &n.body else {
+      return self.fallback(n);
+    };
+
+    let params: Vec<&Pat> = n.params.iter().map(|param| &param.pat).collect();
+    let mut parts = vec![];
+    if n.is_async {
+      parts.push(text("async "));
+    }
+    parts.push(key);
+    parts.push(self.params(&params));
+    if let Some(return_type) = &n.return_type {
+      parts.push(self.type_ann(return_type));
+    }
+    parts.push(text(" "));
+    parts.push(self.block(body));
+    concat(parts)
+  }
+
+  fn interface(&self, n: &TsInterfaceDecl) -> Doc {
+    if n.type_params.is_some() || n.declare {
+      return self.fallback(n);
+    }
+
+    let mut parts = vec![text(format!("interface {} ", n.id.sym))];
+    if !n.extends.is_empty() {
+      parts.push(text("extends "));
+      parts.push(join(
+        text(", "),
+        n.extends.iter().map(|n| self.fallback(n)).collect(),
+      ));
+      parts.push(text(" "));
+    }
+
+    if n.body.body.is_empty() {
+      parts.push(text("{}"));
+      return concat(parts);
+    }
+
+    let members = n
+      .body
+      .body
+      .iter()
+      .map(|member| match member {
+        TsTypeElement::TsPropertySignature(p) if !p.computed => {
+          let mut parts = vec![];
+          if p.readonly {
+            parts.push(text("readonly "));
+          }
+          parts.push(self.expr(&p.key));
+          if p.optional {
+            parts.push(text("?"));
+          }
+          if let Some(type_ann) = &p.type_ann {
+            parts.push(self.type_ann(type_ann));
+          }
+          parts.push(self.semi());
+          concat(parts)
+        }
+        member => concat(vec![self.fallback(member), self.semi()]),
+      })
+      .collect();
+
+    parts.push(text("{"));
+    parts.push(indent(concat(vec![hardline(), join(hardline(), members)])));
+    parts.push(hardline());
+    parts.push(text("}"));
+    concat(parts)
+  }
+
+  fn type_ann(&self, n: &TsTypeAnn) -> Doc {
+    concat(vec![text(": "), self.fallback(&n.type_ann)])
+  }
+
+  fn params(&self, params: &[&Pat]) -> Doc {
+    // A single destructured parameter is hugged: only the object pattern breaks.
+    if let [Pat::Object(_)] = params {
+      return concat(vec![text("("), self.pat(params[0]), text(")")]);
+    }
+
+    let has_rest = params.iter().any(|pat| matches!(pat, Pat::Rest(_)));
+    let trailing_comma = if has_rest {
+      nil()
+    } else {
+      self.trailing_comma(false)
+    };
+    self.list(
+      "(",
+      params.iter().map(|pat| self.pat(pat)).collect(),
+      ")",
+      trailing_comma,
+      false,
+    )
+  }
+
+  fn optional_type_ann(&self, type_ann: &Option<Box<TsTypeAnn>>) -> Doc {
+    match type_ann {
+      Some(type_ann) => self.type_ann(type_ann),
+      None => nil(),
+    }
+  }
+
+  fn pat(&self, n: &Pat) -> Doc {
+    match n {
+      Pat::Ident(n) => concat(vec![
+        text(n.id.sym.as_str()),
+        if n.id.optional { text("?") } else { nil() },
+        self.optional_type_ann(&n.type_ann),
+      ]),
+      Pat::Object(n) => {
+        let has_rest = n
+          .props
+          .iter()
+          .any(|prop| matches!(prop, ObjectPatProp::Rest(_)));
+        let trailing_comma = if has_rest {
+          nil()
+        } else {
+          self.trailing_comma(true)
+        };
+        let props = n
+          .props
+          .iter()
+          .map(|prop| match prop {
+            ObjectPatProp::KeyValue(p) => {
+              concat(vec![self.prop_name(&p.key), text(": "), self.pat(&p.value)])
+            }
+            ObjectPatProp::Assign(p) => match &p.value {
+              Some(value) => concat(vec![
+                text(p.key.id.sym.as_str()),
+                text(" = "),
+                self.expr(value),
+              ]),
+              None => text(p.key.id.sym.as_str()),
+            },
+            ObjectPatProp::Rest(p) => concat(vec![text("..."), self.pat(&p.arg)]),
+          })
+          .collect();
+        concat(vec![
+          self.list("{", props, "}", trailing_comma, true),
+          self.optional_type_ann(&n.type_ann),
+        ])
+      }
+      Pat::Rest(n) => concat(vec![
+        text("..."),
+        self.pat(&n.arg),
+        self.optional_type_ann(&n.type_ann),
+      ]),
+      Pat::Assign(n) => concat(vec![self.pat(&n.left), text(" = "), self.expr(&n.right)]),
+      Pat::Expr(n) => self.expr(n),
+      n => self.fallback(n),
+    }
+  }
+
+  fn prop_name(&self, n: &PropName) -> Doc {
+    match n {
+      PropName::Ident(n) => text(n.sym.as_str()),
+      PropName::Str(n) => self.string(&n.value),
+      PropName::Computed(n) => concat(vec![text("["), self.expr(&n.expr), text("]")]),
+      n => self.fallback(n),
+    }
+  }
+
+  /// Wrap multi-line JSX in parentheses.
+  fn wrapped_jsx(&self, n: &Expr) -> Doc {
+    if !is_jsx(n) {
+      return self.expr(n);
+    }
+    group(concat(vec![
+      if_break(text("("), nil()),
+      indent(concat(vec![softline(), self.expr(n)])),
+      softline(),
+      if_break(text(")"), nil()),
+    ]))
+  }
+
+  fn args(&self, args: &[ExprOrSpread]) -> Doc {
+    let arg = |arg: &ExprOrSpread| {
+      concat(vec![
+        if arg.spread.is_some() {
+          text("...")
+        } else {
+          nil()
+        },
+        self.expr(&arg.expr),
+      ])
+    };
+
+    // The last argument is hugged when it is a function or an object, like `forwardRef((props, ref) => ...)`.
+    if let Some((last, rest)) = args.split_last() {
+      if last.spread.is_none()
+        && is_huggable(&last.expr)
+        && rest
+          .iter()
+          .all(|a| a.spread.is_none() && is_simple_arg(&a.expr))
+      {
+        return concat(vec![
+          text("("),
+          join(text(", "), args.iter().map(arg).collect()),
+          text(")"),
+        ]);
+      }
+    }
+
+    let expanded = self.list(
+      "(",
+      args.iter().map(arg).collect(),
+      ")",
+      self.trailing_comma(false),
+      false,
+    );
+
+    // Otherwise it is only hugged when the other arguments fit on the first line, like
+    // `h("svg", { width: 24 }, [`, and when it is not of the same kind as the previous argument.
+    if let [.., penultimate, last] = args {
+      let is_same_kind = std::mem::discriminant(penultimate.expr.as_ref())
+        == std::mem::discriminant(last.expr.as_ref());
+      let rest = args[..args.len() - 1].iter().map(arg).collect::<Vec<_>>();
+      if last.spread.is_none()
+        && is_huggable(&last.expr)
+        && !is_same_kind
+        && !rest.iter().any(will_break)
+      {
+        let hugged = |last: Doc| {
+          let mut docs = rest.clone();
+          docs.push(last);
+          concat(vec![text("("), join(text(", "), docs), text(")")])
+        };
+        return conditional_group(vec![
+          hugged(arg(last)),
+          hugged(group_with_break(arg(last), true)),
+          expanded,
+        ]);
+      }
+    }
+
+    expanded
+  }
+
+  fn arrow(&self, n: &ArrowExpr) -> Doc {
+    if n.type_params.is_some() || n.is_generator {
+      return self.fallback(n);
+    }
+
+    let params: Vec<&Pat> = n.params.iter().collect();
+    let mut parts = vec![];
+    if n.is_async {
+      parts.push(text("async "));
+    }
+    parts.push(self.params(&params));
+    if let Some(return_type) = &n.return_type {
+      parts.push(self.type_ann(return_type));
+    }
+    parts.push(text(" =>"));
+    parts.push(match n.body.as_ref() {
+      BlockStmtOrExpr::BlockStmt(block) => concat(vec![text(" "), self.block(block)]),
+      BlockStmtOrExpr::Expr(expr) => match expr.as_ref() {
+        Expr::Object(_) | Expr::Seq(_) => concat(vec![text(" ("), self.expr(expr), text(")")]),
+        Expr::Array(_)
+        | Expr::Arrow(_)
+        | Expr::Tpl(_)
+        | Expr::TaggedTpl(_)
+        | Expr::Cond(_)
+        | Expr::JSXElement(_)
+        | Expr::JSXFragment(_) => concat(vec![text(" "), self.wrapped_jsx(expr)]),
+        // The other bodies are moved to the next line when they do not fit after the arrow.
+        expr => group(indent(concat(vec![line(), self.expr(expr)]))),
+      },
+    });
+    concat(parts)
+  }
+
+  /// In JSX mode, the JSX branches are wrapped in parentheses when they break,
+  /// and a chain of conditions breaks as a whole.
+  fn jsx_mode_cond(&self, n: &CondExpr) -> Doc {
+    let branch = |expr: &Expr| match expr {
+      Expr::Cond(cond) => self.jsx_mode_cond(cond),
+      expr if is_jsx(expr) => concat(vec![
+        if_break(text("("), nil()),
+        indent(concat(vec![softline(), self.expr(expr)])),
+        softline(),
+        if_break(text(")"), nil()),
+      ]),
+      expr => self.expr(expr),
+    };
+    concat(vec![
+      self.expr(&n.test),
+      text(" ? "),
+      branch(&n.cons),
+      text(" : "),
+      branch(&n.alt),
+    ])
+  }
+
+  fn cond(&self, n: &CondExpr) -> Doc {
+    if is_jsx(&n.cons) || is_jsx(&n.alt) {
+      return group(self.jsx_mode_cond(n));
+    }
+
+    group(concat(vec![
+      self.expr(&n.test),
+      indent(concat(vec![
+        line(),
+        text("? "),
+        self.expr(&n.cons),
+        line(),
+        text(": "),
+        self.expr(&n.alt),
+      ])),
+    ]))
+  }
+
+  fn expr(&self, n: &Expr) -> Doc {
+    match n {
+      Expr::Ident(n) => text(n.sym.as_str()),
+      Expr::This(_) => text("this"),
+      Expr::Lit(Lit::Str(n)) => self.string(&n.value),
+      Expr::Lit(Lit::Num(n)) => text(match &n.raw {
+        Some(raw) => raw.to_string(),
+        None => n.value.to_string(),
+      }),
+      Expr::Lit(Lit::Bool(n)) => text(if n.value { "true" } else { "false" }),
+      Expr::Lit(Lit::Null(_)) => text("null"),
+      Expr::Array(n) => {
+        let elems = n
+          .elems
+          .iter()
+          .map(|elem| match elem {
+            Some(elem) if elem.spread.is_some() => concat(vec![text("..."), self.expr(&elem.expr)]),
+            Some(elem) => self.expr(&elem.expr),
+            None => nil(),
+          })
+          .collect();
+        self.list("[", elems, "]", self.trailing_comma(true), false)
+      }
+      Expr::Object(n) => {
+        let mut props = vec![];
+        for prop in &n.props {
+          props.push(match prop {
+            PropOrSpread::Spread(s) => concat(vec![text("..."), self.expr(&s.expr)]),
+            PropOrSpread::Prop(prop) => match prop.as_ref() {
+              Prop::Shorthand(ident) => text(ident.sym.as_str()),
+              Prop::KeyValue(kv) => concat(vec![
+                self.prop_name(&kv.key),
+                text(": "),
+                self.expr(&kv.value),
+              ]),
+              Prop::Method(method) if is_printable_function(&method.function) => {
+                self.method(self.prop_name(&method.key), &method.function)
+              }
+              prop => self.fallback(prop),
+            },
+          });
+        }
+        self.list("{", props, "}", self.trailing_comma(true), true)
+      }
+      Expr::Unary(n) => {
+        let op = n.op.as_str();
+        let space = if op.chars().all(|c| c.is_ascii_alphabetic()) {
+          " "
+        } else {
+          ""
+        };
+        concat(vec![text(format!("{}{}", op, space)), self.expr(&n.arg)])
+      }
+      Expr::Bin(n) => group(concat(vec![
+        self.expr(&n.left),
+        text(format!(" {}", n.op.as_str())),
+        indent(concat(vec![line(), self.expr(&n.right)])),
+      ])),
+      Expr::Assign(n) => {
+        let left = match &n.left {
+          AssignTarget::Simple(SimpleAssignTarget::Ident(ident)) => text(ident.id.sym.as_str()),
+          AssignTarget::Simple(SimpleAssignTarget::Member(member)) => self.member(member),
+          left => self.fallback(left),
+        };
+        concat(vec![
+          left,
+          text(format!(" {} ", n.op.as_str())),
+          self.wrapped_jsx(&n.right),
+        ])
+      }
+      Expr::Member(n) => self.member(n),
+      Expr::Cond(n) => self.cond(n),
+      Expr::Call(n) if n.type_args.is_none() => {
+        let callee = match &n.callee {
+          Callee::Expr(expr) => self.expr(expr),
+          Callee::Super(_) => text("super"),
+          Callee::Import(_) => text("import"),
+        };
+        concat(vec![callee, self.args(&n.args)])
+      }
+      Expr::New(n) if n.type_args.is_none() => concat(vec![
+        text("new "),
+        self.expr(&n.callee),
+        self.args(n.args.as_deref().unwrap_or_default()),
+      ]),
+      Expr::Seq(n) => join(text(", "), n.exprs.iter().map(|e| self.expr(e)).collect()),
+      Expr::Arrow(n) => self.arrow(n),
+      Expr::Fn(n) => self.function(n.ident.as_ref(), &n.function),
+      Expr::Class(n) if is_printable_class(&n.class) => self.class(n.ident.as_ref(), &n.class),
+      Expr::Tpl(n) => self.tpl(n),
+      Expr::TaggedTpl(n) if n.type_params.is_none() => {
+        concat(vec![self.expr(&n.tag), self.tpl(&n.tpl)])
+      }
+      Expr::Paren(n) => concat(vec![text("("), self.expr(&n.expr), text(")")]),
+      Expr::Await(n) => concat(vec![text("await "), self.expr(&n.arg)]),
+      Expr::TsAs(n) => concat(vec![
+        self.expr(&n.expr),
+        text(" as "),
+        self.fallback(&n.type_ann),
+      ]),
+      Expr::TsSatisfies(n) => concat(vec![
+        self.expr(&n.expr),
+        text(" satisfies "),
+        self.fallback(&n.type_ann),
+      ]),
+      Expr::TsConstAssertion(n) => concat(vec![self.expr(&n.expr), text(" as const")]),
+      Expr::TsNonNull(n) => concat(vec![self.expr(&n.expr), text("!")]),
+      Expr::JSXElement(n) => self.jsx_element(n),
+      Expr::JSXFragment(n) => self.jsx_fragment(n),
+      n => self.fallback(n),
+    }
+  }
+
+  fn member(&self, n: &MemberExpr) -> Doc {
+    let prop = match &n.prop {
+      MemberProp::Ident(ident) => text(format!(".{}", ident.sym)),
+      MemberProp::Computed(c) => concat(vec![text("["), self.expr(&c.expr), text("]")]),
+      MemberProp::PrivateName(p) => text(format!(".#{}", p.name)),
+    };
+    concat(vec![self.expr(&n.obj), prop])
+  }
+
+  fn jsx_element_name(&self, n: &JSXElementName) -> String {
+    fn jsx_object(n: &JSXObject) -> String {
+      match n {
+        JSXObject::Ident(ident) => ident.sym.to_string(),
+        JSXObject::JSXMemberExpr(member) => {
+          format!("{}.{}", jsx_object(&member.obj), member.prop.sym)
+        }
+      }
+    }
+
+    match n {
+      JSXElementName::Ident(ident) => ident.sym.to_string(),
+      JSXElementName::JSXMemberExpr(member) => {
+        format!("{}.{}", jsx_object(&member.obj), member.prop.sym)
+      }
+      JSXElementName::JSXNamespacedName(name) => format!("{}:{}", name.ns.sym, name.name.sym),
+    }
+  }
+
+  fn jsx_expr_container(&self, n: &JSXExprContainer) -> Doc {
+    match &n.expr {
+      JSXExpr::JSXEmptyExpr(_) => text("{}"),
+      JSXExpr::Expr(expr) => concat(vec![text("{"), self.expr(expr), text("}")]),
+    }
+  }
+
+  fn jsx_attr(&self, n: &JSXAttrOrSpread) -> Doc {
+    match n {
+      JSXAttrOrSpread::SpreadElement(s) => {
+        concat(vec![text("{..."), self.expr(&s.expr), text("}")])
+      }
+      JSXAttrOrSpread::JSXAttr(attr) => {
+        let name = match &attr.name {
+          JSXAttrName::Ident(ident) => ident.sym.to_string(),
+          JSXAttrName::JSXNamespacedName(name) => format!("{}:{}", name.ns.sym, name.name.sym),
+        };
+        let value = match &attr.value {
+          None => return text(name),
+          Some(JSXAttrValue::Lit(Lit::Str(s))) => {
+            // JSX strings can not be escaped, double quotes are used unless the value contains some.
+            let value = s.value.as_str();
+            if value.contains('"') && !value.contains('\'') {
+              text(format!("'{}'", value))
+            } else {
+              text(format!("\"{}\"", value.replace('"', "&quot;")))
+            }
+          }
+          Some(JSXAttrValue::Lit(lit)) => self.fallback(lit),
+          Some(JSXAttrValue::JSXExprContainer(c)) => self.jsx_expr_container(c),
+          Some(JSXAttrValue::JSXElement(e)) => self.jsx_element(e),
+          Some(JSXAttrValue::JSXFragment(f)) => self.jsx_fragment(f),
+        };
+        concat(vec![text(format!("{}=", name)), value])
+      }
+    }
+  }
+
+  fn jsx_children(&self, children: &[JSXElementChild]) -> Vec<JsxChild> {
+    let mut result = vec![];
+
+    for child in children {
+      match child {
+        JSXElementChild::JSXText(t) => {
+          let cleaned = clean_jsx_text(&t.value);
+          let words: Vec<&str> = cleaned.split_whitespace().collect();
+          if cleaned.starts_with(char::is_whitespace) {
+            result.push(JsxChild::Whitespace);
+          }
+          if !words.is_empty() {
+            result.push(JsxChild::Doc(text(words.join(" "))));
+            if cleaned.ends_with(char::is_whitespace) {
+              result.push(JsxChild::Whitespace);
+            }
+          }
+        }
+        JSXElementChild::JSXExprContainer(JSXExprContainer {
+          expr: JSXExpr::JSXEmptyExpr(empty),
+          ..
+        }) => {
+          for comment in self.leading_comments(empty.span.lo) {
+            result.push(JsxChild::Doc(concat(vec![text("{"), comment, text("}")])));
+          }
+        }
+        JSXElementChild::JSXExprContainer(c) => {
+          result.push(JsxChild::Doc(self.jsx_expr_container(c)));
+        }
+        JSXElementChild::JSXSpreadChild(s) => {
+          result.push(JsxChild::Doc(concat(vec![
+            text("{..."),
+            self.expr(&s.expr),
+            text("}"),
+          ])));
+        }
+        JSXElementChild::JSXElement(e) => result.push(JsxChild::Doc(self.jsx_element(e))),
+        JSXElementChild::JSXFragment(f) => result.push(JsxChild::Doc(self.jsx_fragment(f))),
+      }
+    }
+
+    result
+  }
+
+  /// Print the children between the opening and closing elements.
+  /// When the element breaks, each child is printed on its own line and
+  /// meaningful whitespaces are kept with `{" "}`.
+  fn jsx_with_children(
+    &self,
+    opening: Doc,
+    children: &[JSXElementChild],
+    closing: Doc,
+    force_break: bool,
+  ) -> Doc {
+    let children = self.jsx_children(children);
+    if children.is_empty() {
+      return concat(vec![opening, closing]);
+    }
+
+    let mut parts = vec![];
+    let mut previous_is_doc = false;
+    for child in children {
+      match child {
+        JsxChild::Whitespace => {
+          parts.push(if_break(
+            concat(vec![text("{\" \"}"), softline()]),
+            text(" "),
+          ));
+          previous_is_doc = false;
+        }
+        JsxChild::Doc(doc) => {
+          if previous_is_doc {
+            parts.push(softline());
+          }
+          parts.push(doc);
+          previous_is_doc = true;
+        }
+      }
+    }
+
+    group_with_break(
+      concat(vec![
+        opening,
+        indent(concat(vec![softline(), concat(parts)])),
+        softline(),
+        closing,
+      ]),
+      force_break,
+    )
+  }
+
+  fn jsx_element(&self, n: &JSXElement) -> Doc {
+    let name = self.jsx_element_name(&n.opening.name);
+    let type_args = match &n.opening.type_args {
+      Some(type_args) => self.fallback(type_args),
+      None => nil(),
+    };
+    let attrs = &n.opening.attrs;
+    let self_closing = n.opening.self_closing;
+
+    let opening = if attrs.is_empty() {
+      concat(vec![
+        text(format!("<{}", name)),
+        type_args,
+        text(if self_closing { " />" } else { ">" }),
+      ])
+    } else if attrs.len() == 1 && is_string_attr(&attrs[0]) {
+      // A single string attribute is never broken.
+      group(concat(vec![
+        text(format!("<{}", name)),
+        type_args,
+        text(" "),
+        self.jsx_attr(&attrs[0]),
+        text(if self_closing { " />" } else { ">" }),
+      ]))
+    } else {
+      group(concat(vec![
+        text(format!("<{}", name)),
+        type_args,
+        indent(concat(vec![
+          line(),
+          join(
+            line(),
+            attrs.iter().map(|attr| self.jsx_attr(attr)).collect(),
+          ),
+        ])),
+        if self_closing {
+          concat(vec![line(), text("/>")])
+        } else {
+          concat(vec![softline(), text(">")])
+        },
+      ]))
+    };
+
+    if self_closing {
+      return opening;
+    }
+
+    let has_element_child = n.children.iter().any(|child| {
+      matches!(
+        child,
+        JSXElementChild::JSXElement(_) | JSXElementChild::JSXFragment(_)
+      )
+    });
+    let expr_container_count = n
+      .children
+      .iter()
+      .filter(|child| matches!(child, JSXElementChild::JSXExprContainer(_)))
+      .count();
+    let force_break = has_element_child || attrs.len() > 1 || expr_container_count > 1;
+
+    self.jsx_with_children(
+      opening,
+      &n.children,
+      text(format!("</{}>", name)),
+      force_break,
+    )
+  }
+
+  fn jsx_fragment(&self, n: &JSXFragment) -> Doc {
+    let has_element_child = n.children.iter().any(|child| {
+      matches!(
+        child,
+        JSXElementChild::JSXElement(_) | JSXElementChild::JSXFragment(_)
+      )
+    });
+    self.jsx_with_children(text("<>"), &n.children, text("</>"), has_element_child)
+  }
+}
+
+#[cfg(test)]
+mod tests {
+  use std::sync::Arc;
+
+  use crate::{
+    transform, Config, PrettierConfig, State, StringTemplate, Target, TrailingComma,
+    TransformOutput,
+  };
+
+  fn format(input: &str, config: Config) -> TransformOutput {
+    transform(
+      input.to_string(),
+      Config {
+        prettier: true,
+        ..config
+      },
+      State {
+        component_name: Some("SvgComponent".to_string()),
+        ..Default::default()
+      },
+    )
+    .unwrap()
+  }
+
+  fn test_code(input: &str, config: Config, expected: &str) {
+    let result = format(input, config);
+    assert_eq!(result.code, expected);
+    assert_eq!(result.warnings, vec![]);
+  }
+
+  #[test]
+  fn keeps_short_jsx_on_one_line() {
+    test_code(
+      r#"<svg width="24" height="24"/>"#,
+      Default::default(),
+      r#"import * as React from "react";
+
+const SvgComponent = (props) => <svg width={24} height={24} {...props} />;
+
+export default SvgComponent;
+"#,
+    );
+  }
+
+  #[test]
+  fn breaks_attributes_and_children() {
+    test_code(
+      r#"<svg xmlns="http://www.w3.org/2000/svg" width="24" height="24" viewBox="0 0 24 24"><g><path d="M0 0h24v24H0z" fill="none"/></g></svg>"#,
+      Default::default(),
+      r#"import * as React from "react";
+
+const SvgComponent = (props) => (
+  <svg
+    xmlns="http://www.w3.org/2000/svg"
+    width={24}
+    height={24}
+    viewBox="0 0 24 24"
+    {...props}
+  >
+    <g>
+      <path d="M0 0h24v24H0z" fill="none" />
+    </g>
+  </svg>
+);
+
+export default SvgComponent;
+"#,
+    );
+  }
+
+  #[test]
+  fn hugs_destructured_props_and_prints_interfaces() {
+    test_code(
+      r#"<svg><title>Star</title></svg>"#,
+      Config {
+        typescript: true,
+        title_prop: true,
+        desc_prop: true,
+        ..Default::default()
+      },
+      r#"import * as React from "react";
+import { SVGProps } from "react";
+
+interface SVGRProps {
+  title?: string;
+  titleId?: string;
+  desc?: string;
+  descId?: string;
+}
+
+const SvgComponent = ({
+  title,
+  titleId,
+  desc,
+  descId,
+  ...props
+}: SVGProps<SVGSVGElement> & SVGRProps) => (
+  <svg aria-labelledby={titleId} aria-describedby={descId} {...props}>
+    {desc ? <desc id={descId}>{desc}</desc> : null}
+    {title === undefined ? (
+      <title id={titleId}>{"Star"}</title>
+    ) : title ? (
+      <title id={titleId}>{title}</title>
+    ) : null}
+  </svg>
+);
+
+export default SvgComponent;
+"#,
+    );
+  }
+
+  #[test]
+  fn prints_forward_ref_and_memo() {
+    test_code(
+      r#"<svg/>"#,
+      Config {
+        r#ref: true,
+        memo: true,
+        ..Default::default()
+      },
+      r#"import * as React from "react";
+import { forwardRef, memo } from "react";
+
+const SvgComponent = (props, ref) => <svg ref={ref} {...props} />;
+const ForwardRef = forwardRef(SvgComponent);
+const Memo = memo(ForwardRef);
+
+export default Memo;
+"#,
+    );
+  }
+
+  #[test]
+  fn supports_options() {
+    test_code(
+      r#"<svg width="24" height="24"><path d="M0 0h24v24H0z"/></svg>"#,
+      Config {
+        native: true,
+        prettier_config: PrettierConfig {
+          print_width: 50,
+          use_tabs: true,
+          single_quote: true,
+          semi: false,
+          trailing_comma: TrailingComma::None,
+          ..Default::default()
+        },
+        ..Default::default()
+      },
+      "import * as React from 'react'
+import Svg, { Path } from 'react-native-svg'
+
+const SvgComponent = (props) => (
+\t<Svg width={24} height={24} {...props}>
+\t\t<Path d=\"M0 0h24v24H0z\" />
+\t</Svg>
+)
+
+export default SvgComponent
+",
+    );
+  }
+
+  #[test]
+  fn prints_trailing_commas() {
+    test_code(
+      r#"<svg/>"#,
+      Config {
+        jsx_runtime_import: Some(crate::JSXRuntimeImport {
+          source: "some-very-long-jsx-runtime-module-name".to_string(),
+          specifiers: Some(vec![
+            "createElement".to_string(),
+            "Fragment".to_string(),
+            "cloneElement".to_string(),
+          ]),
+          ..Default::default()
+        }),
+        prettier_config: PrettierConfig {
+          trailing_comma: TrailingComma::Es5,
+          ..Default::default()
+        },
+        ..Default::default()
+      },
+      r#"import {
+  createElement,
+  Fragment,
+  cloneElement,
+} from "some-very-long-jsx-runtime-module-name";
+
+const SvgComponent = (props) => <svg {...props} />;
+
+export default SvgComponent;
+"#,
+    );
+  }
+
+  #[test]
+  fn prints_vue_render_functions() {
+    test_code(
+      r#"<svg viewBox="0 0 24 24" style="width:1px"><path d="M0 0"/></svg>"#,
+      Config {
+        target: Target::Vue,
+        title_prop: true,
+        ..Default::default()
+      },
+      r#"import { defineComponent, h } from "vue";
+
+const SvgComponent = defineComponent({
+  name: "SvgComponent",
+  props: { title: String, titleId: String },
+  setup(props) {
+    return () => {
+      const { title, titleId } = props;
+      return h(
+        "svg",
+        {
+          viewBox: "0 0 24 24",
+          style: "width:1px",
+          "aria-labelledby": titleId,
+        },
+        [
+          title ? h("title", { id: titleId }, [title]) : null,
+          h("path", { d: "M0 0" }),
+        ],
+      );
+    };
+  },
+});
+
+export default SvgComponent;
+"#,
+    );
+  }
+
+  #[test]
+  fn prints_custom_element_classes() {
+    test_code(
+      r#"<svg viewBox="0 0 24 24"><path d="M0 0"/></svg>"#,
+      Config {
+        target: Target::Lit,
+        title_prop: true,
+        element_name: Some("star-icon".to_string()),
+        ..Default::default()
+      },
+      r#"import { LitElement, html, svg, nothing } from "lit";
+import { ifDefined } from "lit/directives/if-defined.js";
+
+class SvgComponent extends LitElement {
+  static properties = { title: {}, titleId: { attribute: "title-id" } };
+  render() {
+    const { title, titleId } = this;
+    return html`<svg viewBox="0 0 24 24" aria-labelledby=${ifDefined(titleId)}>
+  ${title ? svg`<title id=${ifDefined(titleId)}>${title}</title>` : nothing}
+  <path d="M0 0" />
+</svg>`;
+  }
+}
+if (!customElements.get("star-icon")) {
+  customElements.define("star-icon", SvgComponent);
+}
+
+export default SvgComponent;
+"#,
+    );
+    test_code(
+      r#"<svg viewBox="0 0 24 24"><path d="M0 0"/></svg>"#,
+      Config {
+        target: Target::WebComponent,
+        element_name: Some("star-icon".to_string()),
+        prettier_config: PrettierConfig {
+          semi: false,
+          ..Default::default()
+        },
+        ..Default::default()
+      },
+      r#"class SvgComponent extends HTMLElement {
+  constructor() {
+    super()
+    this.attachShadow({ mode: "open" })
+  }
+  connectedCallback() {
+    this.render()
+  }
+  render() {
+    this.shadowRoot.innerHTML = `<svg viewBox="0 0 24 24">
+  <path d="M0 0" />
+</svg>`
+  }
+}
+if (!customElements.get("star-icon")) {
+  customElements.define("star-icon", SvgComponent)
+}
+
+export default SvgComponent
+"#,
+    );
+  }
+
+  #[test]
+  fn protects_statements_from_asi_without_semicolons() {
+    test_code(
+      r#"<svg/>"#,
+      Config {
+        template: Some(Arc::new(StringTemplate::new(
+          "%%imports%%
+const %%componentName%% = (%%props%%) => %%jsx%%;
+[%%componentName%%].forEach(Object.freeze);
+(() => {})();
+%%exports%%"
+            .to_string(),
+        ))),
+        prettier_config: PrettierConfig {
+          semi: false,
+          ..Default::default()
+        },
+        ..Default::default()
+      },
+      r#"import * as React from "react"
+
+const SvgComponent = (props) => <svg {...props} />
+;[SvgComponent].forEach(Object.freeze)
+;(() => {})()
+
+export default SvgComponent
+"#,
+    );
+  }
+
+  #[test]
+  fn warns_about_unformatted_code() {
+    let result = format(
+      r#"<svg/>"#,
+      Config {
+        template: Some(Arc::new(StringTemplate::new(
+          "%%imports%%
+const %%componentName%% = (%%props%%) => %%jsx%%;
+while (%%componentName%%) {
+  break;
+}
+%%exports%%"
+            .to_string(),
+        ))),
+        ..Default::default()
+      },
+    );
+    assert_eq!(
+      result
+        .warnings
+        .iter()
+        .map(|warning| warning.code.as_str())
+        .collect::<Vec<_>>(),
+      vec!["prettier-unformatted-code"]
+    );
+  }
+}
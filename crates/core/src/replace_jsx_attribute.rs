@@ -14,10 +14,8 @@ pub struct Visitor {
 
 impl Visitor {
   pub fn new(config: &core::config::Config) -> Self {
-    let replace_attr_values = config.replace_attr_values.as_ref().unwrap();
-
     Self {
-      values: replace_attr_values.clone(),
+      values: config.replace_attr_values.clone().unwrap_or_default(),
     }
   }
 }
@@ -27,11 +25,8 @@ impl VisitMut for Visitor {
     n.attrs.iter_mut().for_each(|attr| {
       if let JSXAttrOrSpread::JSXAttr(jsx_attr) = attr {
         if let Some(JSXAttrValue::Lit(Lit::Str(str))) = &jsx_attr.value {
-          let old_value = str.value.to_string();
-
-          if self.values.contains_key(&old_value) {
-            let attr_value = get_attr_value(self.values.get(&old_value).unwrap());
-            jsx_attr.value = Some(attr_value);
+          if let Some(new_value) = self.values.get(&*str.value) {
+            jsx_attr.value = Some(get_attr_value(new_value));
           }
         }
       }
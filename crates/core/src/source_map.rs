@@ -0,0 +1,51 @@
+use std::collections::HashMap;
+
+use sourcemap::SourceMapBuilder;
+use swc_core::common::{BytePos, LineCol, SourceMap};
+
+use crate::SvgrError;
+
+/// Build a v3 source map as JSON from the mappings collected by the code generator.
+///
+/// The map is built here rather than with `SourceMap::build_source_map`, which dumps every token
+/// to stderr in the version of `swc_common` we depend on.
+pub fn build_source_map(
+  cm: &SourceMap,
+  mappings: &[(BytePos, LineCol)],
+) -> Result<String, SvgrError> {
+  let mut builder = SourceMapBuilder::new(None);
+  let mut sources: HashMap<BytePos, u32> = HashMap::new();
+
+  for (pos, lc) in mappings {
+    if pos.is_reserved_for_comments() || pos.is_dummy() {
+      continue;
+    }
+    let Ok(file) = cm.try_lookup_source_file(*pos) else {
+      continue;
+    };
+    let Some(line) = file.lookup_line(*pos) else {
+      continue;
+    };
+
+    let src_id = *sources.entry(file.start_pos).or_insert_with(|| {
+      let src_id = builder.add_source(&file.name.to_string());
+      // The SVG is not always on disk, for example when it is read from stdin or given to a loader.
+      builder.set_source_contents(src_id, Some(&file.src));
+      src_id
+    });
+
+    // The columns of a source map count UTF-16 code units.
+    let line_start = (file.lines[line] - file.start_pos).0 as usize;
+    let offset = (*pos - file.start_pos).0 as usize;
+    let col = file.src[line_start..offset].encode_utf16().count() as u32;
+
+    builder.add_raw(lc.line, lc.col, line as u32, col, Some(src_id), None, false);
+  }
+
+  let mut buf = vec![];
+  builder
+    .into_sourcemap()
+    .to_writer(&mut buf)
+    .map_err(|e| SvgrError::SourceMap(e.to_string()))?;
+  Ok(String::from_utf8_lossy(&buf).to_string())
+}
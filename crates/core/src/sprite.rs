@@ -0,0 +1,335 @@
+use std::{collections::HashSet, rc::Rc};
+
+use rayon::prelude::*;
+use swc_core::common::{SourceMap, DUMMY_SP};
+use swc_xml::{
+  ast::{Attribute, Child, Element},
+  codegen::{
+    writer::basic::{BasicXmlWriter, BasicXmlWriterConfig},
+    CodeGenerator, CodegenConfig, Emit,
+  },
+};
+
+use crate::{
+  core::{self, state::InternalConfig as InternalState},
+  get_svg_root,
+  hast_to_swc_ast::util::is_numeric,
+  parse_document, transform, unique_ids,
+  util::{create_attr, get_attr},
+  Config, State, SvgrError, TransformOutput, Warning,
+};
+
+const SVG_NAMESPACE: &str = "http://www.w3.org/2000/svg";
+
+/// The output of `transform_sprite`.
+#[derive(Debug)]
+pub struct SpriteOutput {
+  /// The sprite sheet, an SVG with a `<symbol>` for each SVG transformed successfully.
+  pub sprite: String,
+
+  /// The components rendering the symbols with `<use>`, in the order of the inputs.
+  pub components: Vec<Result<TransformOutput, SvgrError>>,
+}
+
+/// A symbol of the sprite sheet, with the SVG the component is generated from.
+struct Symbol {
+  markup: String,
+  component_source: String,
+  warnings: Vec<Warning>,
+}
+
+/// The ids of the symbols, the kebab-case component names made unique with a numeric suffix.
+fn get_symbol_ids(states: &[Result<InternalState, SvgrError>]) -> Vec<String> {
+  let mut used = HashSet::new();
+  states
+    .iter()
+    .map(|state| {
+      let Ok(state) = state else {
+        return String::new();
+      };
+      let base = core::state::kebab_case(&state.component_name);
+      let mut id = base.clone();
+      let mut suffix = 2;
+      while !used.insert(id.clone()) {
+        id = format!("{}-{}", base, suffix);
+        suffix += 1;
+      }
+      id
+    })
+    .collect()
+}
+
+fn create_element(tag_name: &str, attributes: Vec<Attribute>, children: Vec<Child>) -> Element {
+  Element {
+    span: DUMMY_SP,
+    tag_name: tag_name.into(),
+    attributes,
+    children,
+  }
+}
+
+/// The `viewBox` of the SVG, derived from its `width` and `height` when it has none.
+fn get_view_box(root: &Element) -> Option<String> {
+  if let Some(view_box) = get_attr(root, "viewBox") {
+    return Some(view_box.to_string());
+  }
+  let width = get_attr(root, "width").filter(|width| is_numeric(width))?;
+  let height = get_attr(root, "height").filter(|height| is_numeric(height))?;
+  Some(format!("0 0 {} {}", width, height))
+}
+
+fn is_accessible_name(child: &Child) -> bool {
+  matches!(child, Child::Element(element) if element.tag_name == "title" || element.tag_name == "desc")
+}
+
+fn print_element(element: &Element) -> Result<String, SvgrError> {
+  let mut markup = String::new();
+  let wr = BasicXmlWriter::new(&mut markup, None, BasicXmlWriterConfig::default());
+  let mut generator = CodeGenerator::new(
+    wr,
+    CodegenConfig {
+      minify: true,
+      ..Default::default()
+    },
+  );
+  generator
+    .emit(element)
+    .map_err(|e| SvgrError::Codegen(e.to_string()))?;
+  Ok(markup)
+}
+
+/// Move the content of the SVG into a symbol, the component renders the symbol with `<use>` in an
+/// SVG keeping the attributes of the original one, so the options still apply to it.
+fn create_symbol(
+  code: String,
+  config: &Config,
+  state: &InternalState,
+  id: &str,
+  href: &str,
+) -> Result<Symbol, SvgrError> {
+  let config =
+    core::runtime_config::apply_runtime_config(config.clone(), state.file_path.as_deref())?;
+
+  let cm = Rc::<SourceMap>::default();
+  let (document, xml_errors) = parse_document(&cm, code, &config, state)?;
+  let root = get_svg_root(&cm, &document, state)?;
+  let view_box = get_view_box(root);
+
+  let (accessible_names, mut children): (Vec<Child>, Vec<Child>) =
+    root.children.iter().cloned().partition(is_accessible_name);
+
+  unique_ids::prefix_document_ids(&mut children, id);
+
+  let mut symbol_attributes = vec![create_attr("id", id)];
+  if let Some(view_box) = &view_box {
+    symbol_attributes.push(create_attr("viewBox", view_box));
+  }
+  let symbol = create_element("symbol", symbol_attributes, children);
+
+  let mut svg_attributes = vec![create_attr("xmlns", SVG_NAMESPACE)];
+  if let Some(view_box) = &view_box {
+    svg_attributes.push(create_attr("viewBox", view_box));
+  }
+  svg_attributes.extend(
+    root
+      .attributes
+      .iter()
+      .filter(|attr| {
+        !attr.name.starts_with("xmlns") && !matches!(&*attr.name, "viewBox" | "id" | "version")
+      })
+      .cloned(),
+  );
+  let mut svg_children = accessible_names;
+  svg_children.push(Child::Element(create_element(
+    "use",
+    vec![create_attr("href", &format!("{}#{}", href, id))],
+    vec![],
+  )));
+  let svg = create_element("svg", svg_attributes, svg_children);
+
+  Ok(Symbol {
+    markup: print_element(&symbol)?,
+    component_source: print_element(&svg)?,
+    warnings: xml_errors
+      .into_iter()
+      .map(|error| Warning {
+        code: "xml-parse-error".to_string(),
+        message: error.message,
+        loc: error.span.map(|span| span.loc),
+      })
+      .collect(),
+  })
+}
+
+fn transform_symbol(
+  code: String,
+  config: &Config,
+  state: State,
+  id: &str,
+  href: &str,
+) -> Result<(String, TransformOutput), SvgrError> {
+  let internal_state = core::state::expand_state(&state)?;
+  let symbol = create_symbol(code, config, &internal_state, id, href)?;
+  let mut output = transform(symbol.component_source, config.clone(), state)?;
+  output.warnings.splice(0..0, symbol.warnings);
+  Ok((symbol.markup, output))
+}
+
+/// Merge many SVG into a sprite sheet, and transform each of them into a component rendering its
+/// symbol with `<use href="{href}#{id}">`, in parallel on the global thread pool.
+///
+/// The id of a symbol is the kebab-case component name, like `svg-star`, and the ids of its
+/// elements are prefixed with it. The component keeps the attributes of the SVG, its `viewBox`,
+/// its `<title>` and its `<desc>`, so `svg_props`, `title_prop` or `icon` still apply to it.
+/// A file failing to transform is left out of the sprite sheet.
+///
+/// # Examples
+///
+/// ```rust
+/// use svgr_rs::{transform_sprite, State};
+///
+/// let output = transform_sprite(
+///   vec![(
+///     r#"<svg viewBox="0 0 24 24"><path d="M0 0h24"/></svg>"#.to_string(),
+///     State {
+///       component_name: Some("Star".to_string()),
+///       ..Default::default()
+///     },
+///   )],
+///   &Default::default(),
+///   "/icons/sprite.svg",
+/// );
+/// assert!(output
+///   .sprite
+///   .contains(r#"<symbol id="star" viewBox="0 0 24 24">"#));
+/// ```
+pub fn transform_sprite(inputs: Vec<(String, State)>, config: &Config, href: &str) -> SpriteOutput {
+  let states = inputs
+    .iter()
+    .map(|(_, state)| core::state::expand_state(state))
+    .collect::<Vec<_>>();
+  let ids = get_symbol_ids(&states);
+
+  let results = inputs
+    .into_par_iter()
+    .zip(ids)
+    .map(|((code, state), id)| transform_symbol(code, config, state, &id, href))
+    .collect::<Vec<_>>();
+
+  let mut sprite = format!("<svg xmlns=\"{}\"", SVG_NAMESPACE);
+  if results
+    .iter()
+    .any(|result| matches!(result, Ok((markup, _)) if markup.contains("xlink:")))
+  {
+    sprite.push_str(" xmlns:xlink=\"http://www.w3.org/1999/xlink\"");
+  }
+  sprite.push_str(">\n");
+  let components = results
+    .into_iter()
+    .map(|result| {
+      result.map(|(markup, output)| {
+        sprite.push_str(&markup);
+        sprite.push('\n');
+        output
+      })
+    })
+    .collect();
+  sprite.push_str("</svg>\n");
+
+  SpriteOutput { sprite, components }
+}
+
+#[cfg(test)]
+mod tests {
+  use super::*;
+  use crate::{Icon, SvgProp};
+
+  fn input(code: &str, component_name: &str) -> (String, State) {
+    (
+      code.to_string(),
+      State {
+        component_name: Some(component_name.to_string()),
+        ..Default::default()
+      },
+    )
+  }
+
+  #[test]
+  fn prefixes_the_ids_of_the_symbols() {
+    let output = transform_sprite(
+      vec![
+        input(
+          r##"<svg viewBox="0 0 24 24"><defs><linearGradient id="a"/></defs><path fill="url(#a)"/><use xlink:href="#a"/></svg>"##,
+          "SvgStar",
+        ),
+        input(
+          r#"<svg width="16" height="16"><clipPath id="a"/><g clip-path="url(#a)"/></svg>"#,
+          "SvgStar",
+        ),
+      ],
+      &Default::default(),
+      "sprite.svg",
+    );
+
+    assert_eq!(
+      output.sprite,
+      r##"<svg xmlns="http://www.w3.org/2000/svg" xmlns:xlink="http://www.w3.org/1999/xlink">
+<symbol id="svg-star" viewBox="0 0 24 24"><defs><linearGradient id="svg-star-a"/></defs><path fill="url(#svg-star-a)"/><use xlink:href="#svg-star-a"/></symbol>
+<symbol id="svg-star-2" viewBox="0 0 16 16"><clipPath id="svg-star-2-a"/><g clip-path="url(#svg-star-2-a)"/></symbol>
+</svg>
+"##
+    );
+  }
+
+  #[test]
+  fn prefixes_the_id_selectors_of_the_symbols() {
+    let output = transform_sprite(
+      vec![input(
+        r##"<svg viewBox="0 0 24 24"><style>#a{fill:red}.b{mask:url(#c)}</style><path id="a"/><mask id="c"/></svg>"##,
+        "SvgStar",
+      )],
+      &Default::default(),
+      "sprite.svg",
+    );
+
+    assert!(output.sprite.contains(
+      r##"<style>#svg-star-a{fill:red}.b{mask:url(#svg-star-c)}</style><path id="svg-star-a"/><mask id="svg-star-c"/>"##
+    ));
+  }
+
+  #[test]
+  fn renders_the_symbols_with_use() {
+    let output = transform_sprite(
+      vec![
+        input(
+          r#"<svg viewBox="0 0 24 24" width="24" height="24"><title>Star</title><path d="M0 0"/></svg>"#,
+          "SvgStar",
+        ),
+        input("<div/>", "SvgDiv"),
+      ],
+      &Config {
+        icon: Some(Icon::Bool(true)),
+        title_prop: true,
+        svg_props: vec![SvgProp {
+          key: "role".to_string(),
+          value: "img".to_string(),
+        }],
+        ..Default::default()
+      },
+      "/icons/sprite.svg",
+    );
+
+    assert!(!output.sprite.contains("svg-div"));
+    assert_eq!(
+      output.components[1].as_ref().unwrap_err().code(),
+      "non-svg-root"
+    );
+    assert_eq!(
+      output.components[0].as_ref().unwrap().code,
+      r#"import * as React from "react";
+const SvgStar = ({ title, titleId, ...props })=><svg xmlns="http://www.w3.org/2000/svg" viewBox="0 0 24 24" width="1em" height="1em" role="img" aria-labelledby={titleId} {...props}>{title === undefined ? <title id={titleId}>{"Star"}</title> : title ? <title id={titleId}>{title}</title> : null}<use href="/icons/sprite.svg#svg-star"/></svg>;
+export default SvgStar;
+"#
+    );
+  }
+}
@@ -4,18 +4,77 @@ use swc_core::{
   ecma::{ast::*, visit::VisitMut},
 };
 
+use crate::util::create_ident;
+
 const ELEMENTS: [&str; 2] = ["svg", "Svg"];
 
 pub struct Visitor {
   tag: String,
   tag_id: String,
+  /// The id always has a value, generated when it is not given, so the SVG only references it
+  /// when the tag is rendered.
+  fallback_id: bool,
 }
 
 impl Visitor {
-  pub fn new(tag: String) -> Self {
+  pub fn new(tag: String, fallback_id: bool) -> Self {
     let tag_id = format!("{}Id", tag);
 
-    Self { tag, tag_id }
+    Self {
+      tag,
+      tag_id,
+      fallback_id,
+    }
+  }
+
+  /// Make the `aria-labelledby` or `aria-describedby` attribute of the SVG reference the id only
+  /// when the tag is rendered, that is when it is given or when the SVG has a default one.
+  fn reference_rendered_tag(&self, svg: &mut JSXOpeningElement, has_default: bool) {
+    let aria_name = match self.tag.as_str() {
+      "title" => "aria-labelledby",
+      _ => "aria-describedby",
+    };
+    let test = if has_default {
+      Expr::Bin(BinExpr {
+        span: DUMMY_SP,
+        left: Box::new(Expr::Bin(BinExpr {
+          span: DUMMY_SP,
+          left: Box::new(Expr::Ident(create_ident(&self.tag))),
+          op: op!("==="),
+          right: Box::new(Expr::Ident(create_ident("undefined"))),
+        })),
+        op: op!("||"),
+        right: Box::new(Expr::Ident(create_ident(&self.tag))),
+      })
+    } else {
+      Expr::Ident(create_ident(&self.tag))
+    };
+
+    for attr in svg.attrs.iter_mut() {
+      let JSXAttrOrSpread::JSXAttr(JSXAttr {
+        name: JSXAttrName::Ident(name),
+        value:
+          Some(JSXAttrValue::JSXExprContainer(JSXExprContainer {
+            expr: JSXExpr::Expr(expr),
+            ..
+          })),
+        ..
+      }) = attr
+      else {
+        continue;
+      };
+      if name.sym != aria_name
+        || !matches!(&**expr, Expr::Ident(ident) if ident.sym == *self.tag_id)
+      {
+        continue;
+      }
+      *expr = Box::new(Expr::Cond(CondExpr {
+        span: DUMMY_SP,
+        test: Box::new(test.clone()),
+        cons: Box::new(Expr::Ident(create_ident(&self.tag_id))),
+        alt: Box::new(Expr::Ident(create_ident("undefined"))),
+      }));
+    }
   }
 
   fn get_tag_expr(&self, value: JSXAttrValue) -> Expr {
@@ -197,21 +256,32 @@ impl VisitMut for Visitor {
         return;
       }
 
-      let has_tag = n.children.clone().iter_mut().enumerate().any(|(i, c)| {
-        if let JSXElementChild::JSXElement(e) = c {
-          if let JSXElementName::Ident(ident) = &e.opening.name {
-            if ident.sym == self.tag {
-              let tag_element = self.get_tag_element_with_existing_title(e);
-              n.children[i] = tag_element;
-              return true;
-            }
-          }
+      let index = n.children.iter().position(|c| match c {
+        JSXElementChild::JSXElement(e) => {
+          matches!(&e.opening.name, JSXElementName::Ident(ident) if ident.sym == self.tag)
         }
-        false
+        _ => false,
       });
 
-      if !has_tag {
-        n.children.insert(0, self.get_tag_element());
+      // The existing tag is rendered by default when it has content.
+      let has_default = match index {
+        Some(i) => {
+          let JSXElementChild::JSXElement(e) = &mut n.children[i] else {
+            return;
+          };
+          let has_default = !e.children.is_empty();
+          let tag_element = self.get_tag_element_with_existing_title(e);
+          n.children[i] = tag_element;
+          has_default
+        }
+        None => {
+          n.children.insert(0, self.get_tag_element());
+          false
+        }
+      };
+
+      if self.fallback_id {
+        self.reference_rendered_tag(&mut n.opening, has_default);
       }
     }
   }
@@ -234,6 +304,10 @@ mod tests {
   use super::*;
 
   fn code_test(input: &str, tag: String, expected: &str) {
+    visitor_test(input, Visitor::new(tag, false), expected);
+  }
+
+  fn visitor_test(input: &str, visitor: Visitor, expected: &str) {
     let cm = Rc::<SourceMap>::default();
     let fm = cm.new_source_file(FileName::Anon.into(), input.to_string());
 
@@ -251,7 +325,7 @@ mod tests {
     let mut parser = Parser::new_from(lexer);
     let module = parser.parse_module().unwrap();
 
-    let module = module.fold_with(&mut as_folder(Visitor::new(tag)));
+    let module = module.fold_with(&mut as_folder(visitor));
 
     let mut buf = vec![];
     let mut emitter = Emitter {
@@ -385,4 +459,19 @@ mod tests {
       r#"<svg>{desc ? <desc id={descId}>{desc}</desc> : null}<foo/></svg>;"#,
     );
   }
+
+  #[test]
+  fn references_the_fallback_id_only_when_the_tag_is_rendered() {
+    visitor_test(
+      r#"<svg aria-labelledby={titleId}></svg>;"#,
+      Visitor::new("title".to_string(), true),
+      r#"<svg aria-labelledby={title ? titleId : undefined}>{title ? <title id={titleId}>{title}</title> : null}</svg>;"#,
+    );
+
+    visitor_test(
+      r#"<svg aria-describedby={descId}><desc>Hello</desc></svg>;"#,
+      Visitor::new("desc".to_string(), true),
+      r#"<svg aria-describedby={desc === undefined || desc ? descId : undefined}>{desc === undefined ? <desc id={descId}>Hello</desc> : desc ? <desc id={descId}>{desc}</desc> : null}</svg>;"#,
+    );
+  }
 }
@@ -0,0 +1,49 @@
+use lazy_static::lazy_static;
+use regex::Regex;
+use swc_xml::{ast::Attribute, visit::VisitMut};
+
+pub struct Visitor;
+
+fn cleanup(value: &str) -> String {
+  lazy_static! {
+    static ref NEWLINES_BETWEEN_WORDS_REGEX: Regex = Regex::new(r"(\S)\r?\n(\S)").unwrap();
+    static ref NEWLINES_REGEX: Regex = Regex::new(r"\r?\n").unwrap();
+    static ref SPACES_REGEX: Regex = Regex::new(r"\s{2,}").unwrap();
+  }
+
+  let value = NEWLINES_BETWEEN_WORDS_REGEX.replace_all(value, "$1 $2");
+  let value = NEWLINES_REGEX.replace_all(&value, "");
+  let value = SPACES_REGEX.replace_all(value.trim(), " ");
+  value.to_string()
+}
+
+impl VisitMut for Visitor {
+  fn visit_mut_attribute(&mut self, n: &mut Attribute) {
+    if let Some(value) = &n.value {
+      let cleaned = cleanup(value);
+      if cleaned != value.as_str() {
+        n.value = Some(cleaned.into());
+        n.raw_value = None;
+      }
+    }
+  }
+}
+
+#[cfg(test)]
+mod tests {
+  use swc_xml::visit::VisitMutWith;
+
+  use super::*;
+  use crate::svgo::tests::code_test;
+
+  #[test]
+  fn cleans_up_attribute_values() {
+    code_test(
+      r#"<svg><path d="  M0 0
+L10   10
+ Z  " fill="red"/></svg>"#,
+      |document| document.visit_mut_with(&mut Visitor),
+      r#"<svg><path d="M0 0 L10 10 Z" fill="red"/></svg>"#,
+    );
+  }
+}
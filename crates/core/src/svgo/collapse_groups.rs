@@ -0,0 +1,213 @@
+use swc_xml::{
+  ast::{Attribute, Child, Element},
+  visit::{VisitMut, VisitMutWith},
+};
+
+use super::is_whitespace_text;
+use crate::util::{get_attr, has_attr};
+
+const ANIMATION_ELEMENTS: [&str; 5] = [
+  "animate",
+  "animateColor",
+  "animateMotion",
+  "animateTransform",
+  "set",
+];
+
+// From https://github.com/svg/svgo/blob/main/plugins/_collections.js
+const INHERITABLE_ATTRS: [&str; 47] = [
+  "clip-rule",
+  "color-interpolation-filters",
+  "color-interpolation",
+  "color-profile",
+  "color-rendering",
+  "color",
+  "cursor",
+  "direction",
+  "dominant-baseline",
+  "fill-opacity",
+  "fill-rule",
+  "fill",
+  "font-family",
+  "font-size-adjust",
+  "font-size",
+  "font-stretch",
+  "font-style",
+  "font-variant",
+  "font-weight",
+  "font",
+  "glyph-orientation-horizontal",
+  "glyph-orientation-vertical",
+  "image-rendering",
+  "letter-spacing",
+  "marker-end",
+  "marker-mid",
+  "marker-start",
+  "marker",
+  "paint-order",
+  "pointer-events",
+  "shape-rendering",
+  "stroke-dasharray",
+  "stroke-dashoffset",
+  "stroke-linecap",
+  "stroke-linejoin",
+  "stroke-miterlimit",
+  "stroke-opacity",
+  "stroke-width",
+  "stroke",
+  "text-anchor",
+  "text-rendering",
+  "transform",
+  "visibility",
+  "word-spacing",
+  "writing-mode",
+  "clip-path",
+  "mask",
+];
+
+pub struct Visitor;
+
+fn is_animation(child: &Child) -> bool {
+  match child {
+    Child::Element(element) => ANIMATION_ELEMENTS.contains(&element.tag_name.as_str()),
+    _ => false,
+  }
+}
+
+fn has_animated_attr(element: &Element, name: &str) -> bool {
+  (ANIMATION_ELEMENTS.contains(&element.tag_name.as_str())
+    && get_attr(element, "attributeName") == Some(name))
+    || element.children.iter().any(|child| match child {
+      Child::Element(child) => has_animated_attr(child, name),
+      _ => false,
+    })
+}
+
+/// Move the attributes of the group onto its only child element when it is safe to do so.
+fn move_attrs_to_child(group: &mut Element) {
+  let group_has_filter = has_attr(group, "filter");
+  let group_has_class = has_attr(group, "class");
+  let group_has_clip_path_or_mask = has_attr(group, "clip-path") || has_attr(group, "mask");
+  let group_has_transform = has_attr(group, "transform");
+
+  let mut elements = group
+    .children
+    .iter_mut()
+    .filter(|child| !is_whitespace_text(child));
+  let child = match (elements.next(), elements.next()) {
+    (Some(Child::Element(child)), None) => child,
+    _ => return,
+  };
+
+  if has_attr(child, "id") || group_has_filter || (group_has_class && has_attr(child, "class")) {
+    return;
+  }
+  if group_has_clip_path_or_mask
+    && (child.tag_name != *"g" || group_has_transform || has_attr(child, "transform"))
+  {
+    return;
+  }
+
+  let attributes = std::mem::take(&mut group.attributes);
+  let mut remaining: Vec<Attribute> = vec![];
+  let mut stopped = false;
+
+  for attr in attributes {
+    if stopped || has_animated_attr(child, &attr.name) {
+      stopped = true;
+      remaining.push(attr);
+      continue;
+    }
+
+    let existing = child
+      .attributes
+      .iter_mut()
+      .find(|child_attr| child_attr.name == attr.name);
+
+    match existing {
+      None => child.attributes.push(attr),
+      Some(existing) => {
+        let group_value = attr.value.as_ref().map(|v| v.as_str()).unwrap_or("");
+        let child_value = existing.value.as_ref().map(|v| v.as_str()).unwrap_or("");
+        if attr.name == *"transform" {
+          existing.value = Some(format!("{} {}", group_value, child_value).into());
+          existing.raw_value = None;
+        } else if child_value == "inherit" {
+          existing.value.clone_from(&attr.value);
+          existing.raw_value = None;
+        } else if !INHERITABLE_ATTRS.contains(&attr.name.as_str()) && child_value != group_value {
+          stopped = true;
+          remaining.push(attr);
+        }
+      }
+    }
+  }
+
+  group.attributes = remaining;
+}
+
+impl VisitMut for Visitor {
+  fn visit_mut_childs(&mut self, n: &mut Vec<Child>) {
+    n.visit_mut_children_with(self);
+
+    let children = std::mem::take(n);
+    for child in children {
+      match child {
+        Child::Element(mut element) if element.tag_name == *"g" && !element.children.is_empty() => {
+          if !element.attributes.is_empty() {
+            move_attrs_to_child(&mut element);
+          }
+
+          if element.attributes.is_empty() && !element.children.iter().any(is_animation) {
+            n.extend(element.children);
+          } else {
+            n.push(Child::Element(element));
+          }
+        }
+        child => n.push(child),
+      }
+    }
+  }
+}
+
+#[cfg(test)]
+mod tests {
+  use super::*;
+  use crate::svgo::tests::code_test;
+
+  #[test]
+  fn collapses_groups_without_attributes() {
+    code_test(
+      r#"<svg><g><g><path d="M0 0"/></g><path d="M1 1"/></g></svg>"#,
+      |document| document.visit_mut_with(&mut Visitor),
+      r#"<svg><path d="M0 0"/><path d="M1 1"/></svg>"#,
+    );
+  }
+
+  #[test]
+  fn moves_attributes_to_the_only_child() {
+    code_test(
+      r#"<svg><g fill="red" transform="rotate(45)"><path transform="scale(2)" d="M0 0"/></g></svg>"#,
+      |document| document.visit_mut_with(&mut Visitor),
+      r#"<svg><path transform="rotate(45) scale(2)" d="M0 0" fill="red"/></svg>"#,
+    );
+  }
+
+  #[test]
+  fn keeps_groups_with_filter_or_child_id() {
+    code_test(
+      r#"<svg><g filter="url(#a)"><path d="M0 0"/></g><g fill="red"><path id="b" d="M0 0"/></g></svg>"#,
+      |document| document.visit_mut_with(&mut Visitor),
+      r#"<svg><g filter="url(#a)"><path d="M0 0"/></g><g fill="red"><path id="b" d="M0 0"/></g></svg>"#,
+    );
+  }
+
+  #[test]
+  fn keeps_groups_with_an_animated_attribute() {
+    code_test(
+      r#"<svg><g fill="red"><animate attributeName="fill" to="blue"/></g><g fill="red"><path d="M0 0"><set attributeName="fill" to="blue"/></path></g></svg>"#,
+      |document| document.visit_mut_with(&mut Visitor),
+      r#"<svg><g fill="red"><animate attributeName="fill" to="blue"/></g><g fill="red"><path d="M0 0"><set attributeName="fill" to="blue"/></path></g></svg>"#,
+    );
+  }
+}
@@ -0,0 +1,147 @@
+use swc_xml::{
+  ast::{Child, Document},
+  visit::VisitMutWith,
+};
+
+use crate::core::config::SvgoConfig;
+
+mod cleanup_attrs;
+mod collapse_groups;
+mod remove_comments;
+mod remove_doctype;
+mod remove_editors_ns_data;
+mod remove_empty_attrs;
+mod remove_empty_containers;
+mod remove_empty_text;
+mod remove_metadata;
+mod remove_useless_defs;
+mod remove_xml_proc_inst;
+
+/// Optimize the SVG document in place, running the enabled plugins in the `preset-default` order.
+pub fn optimize(document: &mut Document, config: &SvgoConfig) {
+  if config.remove_doctype {
+    document.visit_mut_with(&mut remove_doctype::Visitor);
+  }
+  if config.remove_xml_proc_inst {
+    document.visit_mut_with(&mut remove_xml_proc_inst::Visitor);
+  }
+  if config.remove_comments {
+    document.visit_mut_with(&mut remove_comments::Visitor);
+  }
+  if config.remove_metadata {
+    document.visit_mut_with(&mut remove_metadata::Visitor);
+  }
+  if config.remove_editors_ns_data {
+    document.visit_mut_with(&mut remove_editors_ns_data::Visitor::default());
+  }
+  if config.cleanup_attrs {
+    document.visit_mut_with(&mut cleanup_attrs::Visitor);
+  }
+  if config.remove_useless_defs {
+    document.visit_mut_with(&mut remove_useless_defs::Visitor);
+  }
+  if config.remove_empty_text {
+    document.visit_mut_with(&mut remove_empty_text::Visitor);
+  }
+  if config.collapse_groups {
+    document.visit_mut_with(&mut collapse_groups::Visitor);
+  }
+  if config.remove_empty_attrs {
+    document.visit_mut_with(&mut remove_empty_attrs::Visitor);
+  }
+  if config.remove_empty_containers {
+    document.visit_mut_with(&mut remove_empty_containers::Visitor);
+  }
+}
+
+fn is_whitespace_text(child: &Child) -> bool {
+  match child {
+    Child::Text(text) => text.data.trim().is_empty(),
+    _ => false,
+  }
+}
+
+#[cfg(test)]
+pub(crate) mod tests {
+  use std::rc::Rc;
+
+  use swc_core::common::{FileName, SourceMap};
+  use swc_xml::{
+    codegen::{
+      writer::basic::{BasicXmlWriter, BasicXmlWriterConfig},
+      CodeGenerator, CodegenConfig, Emit,
+    },
+    parser::parse_file_as_document,
+  };
+
+  use super::*;
+
+  pub fn code_test(input: &str, optimize: impl FnOnce(&mut Document), expected: &str) {
+    let cm = Rc::<SourceMap>::default();
+    let fm = cm.new_source_file(FileName::Anon.into(), input.to_string());
+
+    let mut errors = vec![];
+    let mut document =
+      parse_file_as_document(fm.as_ref(), Default::default(), &mut errors).unwrap();
+
+    optimize(&mut document);
+
+    let mut buf = String::new();
+    let wr = BasicXmlWriter::new(&mut buf, None, BasicXmlWriterConfig::default());
+    let mut gen = CodeGenerator::new(
+      wr,
+      CodegenConfig {
+        minify: true,
+        ..Default::default()
+      },
+    );
+    gen.emit(&document).unwrap();
+
+    assert_eq!(buf, expected);
+  }
+
+  #[test]
+  fn optimizes_with_preset_default() {
+    code_test(
+      r#"<?xml version="1.0" encoding="UTF-8"?><!-- Generator: Adobe Illustrator 27.0.0 --><svg xmlns="http://www.w3.org/2000/svg" xmlns:sketch="http://www.bohemiancoding.com/sketch/ns" viewBox="0 0 24 24"><metadata>Created with Sketch.</metadata><defs></defs><g sketch:type="MSPage" fill="none"><g><path class="" d="M0 0
+        L10 10"/></g></g></svg>"#,
+      |document| optimize(document, &Default::default()),
+      r#"<svg xmlns="http://www.w3.org/2000/svg" viewBox="0 0 24 24"><path d="M0 0 L10 10" fill="none"/></svg>"#,
+    );
+  }
+
+  #[test]
+  fn removes_empty_text_before_collapsing_groups() {
+    code_test(
+      r#"<svg><g fill="red"><text/><path d="M0 0"/></g></svg>"#,
+      |document| optimize(document, &Default::default()),
+      r#"<svg><path d="M0 0" fill="red"/></svg>"#,
+    );
+  }
+
+  #[test]
+  fn does_nothing_when_all_plugins_are_disabled() {
+    code_test(
+      r#"<svg><!-- a --><g/></svg>"#,
+      |document| {
+        optimize(
+          document,
+          &SvgoConfig {
+            remove_doctype: false,
+            remove_xml_proc_inst: false,
+            remove_comments: false,
+            remove_metadata: false,
+            remove_editors_ns_data: false,
+            cleanup_attrs: false,
+            remove_useless_defs: false,
+            collapse_groups: false,
+            remove_empty_attrs: false,
+            remove_empty_text: false,
+            remove_empty_containers: false,
+          },
+        )
+      },
+      r#"<svg><!-- a --><g/></svg>"#,
+    );
+  }
+}
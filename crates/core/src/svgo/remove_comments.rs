@@ -0,0 +1,41 @@
+use swc_xml::{
+  ast::Child,
+  visit::{VisitMut, VisitMutWith},
+};
+
+pub struct Visitor;
+
+impl VisitMut for Visitor {
+  fn visit_mut_childs(&mut self, n: &mut Vec<Child>) {
+    // Comments starting with `!` are legal notices and must be preserved.
+    n.retain(|child| match child {
+      Child::Comment(comment) => comment.data.starts_with('!'),
+      _ => true,
+    });
+    n.visit_mut_children_with(self);
+  }
+}
+
+#[cfg(test)]
+mod tests {
+  use super::*;
+  use crate::svgo::tests::code_test;
+
+  #[test]
+  fn removes_comments() {
+    code_test(
+      r#"<!-- outer --><svg><!-- inner --><g><!-- nested --></g></svg>"#,
+      |document| document.visit_mut_with(&mut Visitor),
+      r#"<svg><g/></svg>"#,
+    );
+  }
+
+  #[test]
+  fn preserves_legal_comments() {
+    code_test(
+      r#"<svg><!--! Font Awesome Free 6.0.0 --><g/></svg>"#,
+      |document| document.visit_mut_with(&mut Visitor),
+      r#"<svg><!--! Font Awesome Free 6.0.0 --><g/></svg>"#,
+    );
+  }
+}
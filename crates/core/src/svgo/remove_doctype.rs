@@ -0,0 +1,30 @@
+use swc_xml::{
+  ast::{Child, Document},
+  visit::VisitMut,
+};
+
+pub struct Visitor;
+
+impl VisitMut for Visitor {
+  fn visit_mut_document(&mut self, n: &mut Document) {
+    n.children
+      .retain(|child| !matches!(child, Child::DocumentType(_)));
+  }
+}
+
+#[cfg(test)]
+mod tests {
+  use swc_xml::visit::VisitMutWith;
+
+  use super::*;
+  use crate::svgo::tests::code_test;
+
+  #[test]
+  fn removes_doctype() {
+    code_test(
+      r#"<!DOCTYPE svg PUBLIC "-//W3C//DTD SVG 1.1//EN" "http://www.w3.org/Graphics/SVG/1.1/DTD/svg11.dtd"><svg/>"#,
+      |document| document.visit_mut_with(&mut Visitor),
+      r#"<svg/>"#,
+    );
+  }
+}
@@ -0,0 +1,97 @@
+use swc_xml::{
+  ast::{Child, Element},
+  visit::{VisitMut, VisitMutWith},
+};
+
+// From https://github.com/svg/svgo/blob/main/plugins/_collections.js
+const EDITOR_NAMESPACES: [&str; 22] = [
+  "http://creativecommons.org/ns#",
+  "http://inkscape.sourceforge.net/DTD/sodipodi-0.dtd",
+  "http://ns.adobe.com/AdobeIllustrator/10.0/",
+  "http://ns.adobe.com/AdobeSVGViewerExtensions/3.0/",
+  "http://ns.adobe.com/Extensibility/1.0/",
+  "http://ns.adobe.com/Flows/1.0/",
+  "http://ns.adobe.com/GenericCustomNamespace/1.0/",
+  "http://ns.adobe.com/Graphs/1.0/",
+  "http://ns.adobe.com/ImageReplacement/1.0/",
+  "http://ns.adobe.com/SaveForWeb/1.0/",
+  "http://ns.adobe.com/Variables/1.0/",
+  "http://ns.adobe.com/XPath/1.0/",
+  "http://purl.org/dc/elements/1.1/",
+  "http://schemas.microsoft.com/visio/2003/SVGExtensions/",
+  "http://sodipodi.sourceforge.net/DTD/sodipodi-0.dtd",
+  "http://taptrix.com/vectorillustrator/svg_extensions",
+  "http://www.bohemiancoding.com/sketch/ns",
+  "http://www.figma.com/figma/ns",
+  "http://www.inkscape.org/namespaces/inkscape",
+  "http://www.serif.com/",
+  "http://www.vector.evaxdesign.sk",
+  "http://www.w3.org/1999/02/22-rdf-syntax-ns#",
+];
+
+#[derive(Default)]
+pub struct Visitor {
+  prefixes: Vec<String>,
+}
+
+impl Visitor {
+  fn has_editor_prefix(&self, name: &str) -> bool {
+    match name.split_once(':') {
+      Some((prefix, _)) => self.prefixes.iter().any(|p| p == prefix),
+      None => false,
+    }
+  }
+}
+
+impl VisitMut for Visitor {
+  fn visit_mut_element(&mut self, n: &mut Element) {
+    n.attributes.retain(|attr| {
+      if let Some(prefix) = attr.name.strip_prefix("xmlns:") {
+        if let Some(value) = &attr.value {
+          if EDITOR_NAMESPACES.contains(&value.as_str()) {
+            self.prefixes.push(prefix.to_string());
+            return false;
+          }
+        }
+      }
+      true
+    });
+
+    n.attributes
+      .retain(|attr| !self.has_editor_prefix(&attr.name));
+
+    n.visit_mut_children_with(self);
+  }
+
+  fn visit_mut_childs(&mut self, n: &mut Vec<Child>) {
+    n.retain(|child| match child {
+      Child::Element(element) => !self.has_editor_prefix(&element.tag_name),
+      _ => true,
+    });
+    n.visit_mut_children_with(self);
+  }
+}
+
+#[cfg(test)]
+mod tests {
+  use super::*;
+  use crate::svgo::tests::code_test;
+
+  #[test]
+  fn removes_editor_namespaces_attributes_and_elements() {
+    code_test(
+      r#"<svg xmlns="http://www.w3.org/2000/svg" xmlns:sodipodi="http://sodipodi.sourceforge.net/DTD/sodipodi-0.dtd" xmlns:inkscape="http://www.inkscape.org/namespaces/inkscape" inkscape:version="1.0"><sodipodi:namedview pagecolor="white"/><path sodipodi:nodetypes="cc" d="M0 0"/></svg>"#,
+      |document| document.visit_mut_with(&mut Visitor::default()),
+      r#"<svg xmlns="http://www.w3.org/2000/svg"><path d="M0 0"/></svg>"#,
+    );
+  }
+
+  #[test]
+  fn keeps_other_namespaces() {
+    code_test(
+      r##"<svg xmlns:xlink="http://www.w3.org/1999/xlink"><use xlink:href="#a"/></svg>"##,
+      |document| document.visit_mut_with(&mut Visitor::default()),
+      r##"<svg xmlns:xlink="http://www.w3.org/1999/xlink"><use xlink:href="#a"/></svg>"##,
+    );
+  }
+}
@@ -0,0 +1,35 @@
+use swc_xml::{
+  ast::Element,
+  visit::{VisitMut, VisitMutWith},
+};
+
+// Conditional processing attributes change the rendering even when they are empty.
+const CONDITIONAL_PROCESSING_ATTRS: [&str; 3] =
+  ["requiredExtensions", "requiredFeatures", "systemLanguage"];
+
+pub struct Visitor;
+
+impl VisitMut for Visitor {
+  fn visit_mut_element(&mut self, n: &mut Element) {
+    n.attributes.retain(|attr| {
+      CONDITIONAL_PROCESSING_ATTRS.contains(&attr.name.as_str())
+        || attr.value.as_ref().map_or(false, |v| !v.is_empty())
+    });
+    n.visit_mut_children_with(self);
+  }
+}
+
+#[cfg(test)]
+mod tests {
+  use super::*;
+  use crate::svgo::tests::code_test;
+
+  #[test]
+  fn removes_empty_attributes() {
+    code_test(
+      r#"<svg><path class="" d="M0 0" systemLanguage=""/></svg>"#,
+      |document| document.visit_mut_with(&mut Visitor),
+      r#"<svg><path d="M0 0" systemLanguage=""/></svg>"#,
+    );
+  }
+}
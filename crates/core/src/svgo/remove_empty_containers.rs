@@ -0,0 +1,71 @@
+use swc_xml::{
+  ast::{Child, Element},
+  visit::{VisitMut, VisitMutWith},
+};
+
+use super::is_whitespace_text;
+use crate::util::has_attr;
+
+// From https://github.com/svg/svgo/blob/main/plugins/_collections.js
+const CONTAINER_ELEMENTS: [&str; 14] = [
+  "a",
+  "defs",
+  "foreignObject",
+  "g",
+  "marker",
+  "mask",
+  "missing-glyph",
+  "pattern",
+  "svg",
+  "switch",
+  "symbol",
+  "clipPath",
+  "linearGradient",
+  "radialGradient",
+];
+
+pub struct Visitor;
+
+fn is_empty_container(element: &Element) -> bool {
+  let tag_name = element.tag_name.as_str();
+  if !CONTAINER_ELEMENTS.contains(&tag_name) || !element.children.iter().all(is_whitespace_text) {
+    return false;
+  }
+
+  match tag_name {
+    // The root element, gradients and clip paths are meaningful without children.
+    "svg" | "linearGradient" | "radialGradient" | "clipPath" => false,
+    // A pattern may reference another one through `href`.
+    "pattern" => element.attributes.is_empty(),
+    // A mask with an id hides the element referencing it.
+    "mask" => !has_attr(element, "id"),
+    // A group with a filter may render the filter primitives.
+    "g" => !has_attr(element, "filter"),
+    _ => true,
+  }
+}
+
+impl VisitMut for Visitor {
+  fn visit_mut_childs(&mut self, n: &mut Vec<Child>) {
+    n.visit_mut_children_with(self);
+    n.retain(|child| match child {
+      Child::Element(element) => !is_empty_container(element),
+      _ => true,
+    });
+  }
+}
+
+#[cfg(test)]
+mod tests {
+  use super::*;
+  use crate::svgo::tests::code_test;
+
+  #[test]
+  fn removes_empty_containers() {
+    code_test(
+      r#"<svg><defs/><g><g> </g></g><mask id="a"/><path d="M0 0"/></svg>"#,
+      |document| document.visit_mut_with(&mut Visitor),
+      r#"<svg><mask id="a"/><path d="M0 0"/></svg>"#,
+    );
+  }
+}
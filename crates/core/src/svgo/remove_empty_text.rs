@@ -0,0 +1,42 @@
+use swc_xml::{
+  ast::{Child, Element},
+  visit::{VisitMut, VisitMutWith},
+};
+
+use super::is_whitespace_text;
+use crate::util::has_attr;
+
+pub struct Visitor;
+
+fn is_empty_text(element: &Element) -> bool {
+  match element.tag_name.as_str() {
+    "text" | "tspan" => element.children.iter().all(is_whitespace_text),
+    "tref" => !has_attr(element, "xlink:href"),
+    _ => false,
+  }
+}
+
+impl VisitMut for Visitor {
+  fn visit_mut_childs(&mut self, n: &mut Vec<Child>) {
+    n.retain(|child| match child {
+      Child::Element(element) => !is_empty_text(element),
+      _ => true,
+    });
+    n.visit_mut_children_with(self);
+  }
+}
+
+#[cfg(test)]
+mod tests {
+  use super::*;
+  use crate::svgo::tests::code_test;
+
+  #[test]
+  fn removes_empty_text_elements() {
+    code_test(
+      r#"<svg><text/><text>Hello<tspan> </tspan></text><tref/></svg>"#,
+      |document| document.visit_mut_with(&mut Visitor),
+      r#"<svg><text>Hello</text></svg>"#,
+    );
+  }
+}
@@ -0,0 +1,31 @@
+use swc_xml::{
+  ast::Child,
+  visit::{VisitMut, VisitMutWith},
+};
+
+pub struct Visitor;
+
+impl VisitMut for Visitor {
+  fn visit_mut_childs(&mut self, n: &mut Vec<Child>) {
+    n.retain(|child| match child {
+      Child::Element(element) => element.tag_name != *"metadata",
+      _ => true,
+    });
+    n.visit_mut_children_with(self);
+  }
+}
+
+#[cfg(test)]
+mod tests {
+  use super::*;
+  use crate::svgo::tests::code_test;
+
+  #[test]
+  fn removes_metadata() {
+    code_test(
+      r#"<svg><metadata><rdf:RDF/></metadata><g/></svg>"#,
+      |document| document.visit_mut_with(&mut Visitor),
+      r#"<svg><g/></svg>"#,
+    );
+  }
+}
@@ -0,0 +1,84 @@
+use swc_xml::{
+  ast::{Child, Element},
+  visit::{VisitMut, VisitMutWith},
+};
+
+use crate::util::has_attr;
+
+// From https://github.com/svg/svgo/blob/main/plugins/_collections.js
+const NON_RENDERING_ELEMENTS: [&str; 9] = [
+  "clipPath",
+  "filter",
+  "linearGradient",
+  "marker",
+  "mask",
+  "pattern",
+  "radialGradient",
+  "solidColor",
+  "symbol",
+];
+
+pub struct Visitor;
+
+/// A `<defs>`, or a non-rendering element which can not be referenced without an id.
+fn is_definition_container(element: &Element) -> bool {
+  element.tag_name == *"defs"
+    || (NON_RENDERING_ELEMENTS.contains(&element.tag_name.as_str()) && !has_attr(element, "id"))
+}
+
+/// Collect the children of a definition container that can still be referenced, flattening the
+/// ones that can not.
+fn collect_useful_nodes(children: Vec<Child>, useful: &mut Vec<Child>) {
+  for child in children {
+    match child {
+      Child::Element(element) => {
+        if has_attr(&element, "id") || element.tag_name == *"style" {
+          useful.push(Child::Element(element));
+        } else {
+          collect_useful_nodes(element.children, useful);
+        }
+      }
+      Child::Text(_) => {}
+      child => useful.push(child),
+    }
+  }
+}
+
+impl VisitMut for Visitor {
+  fn visit_mut_childs(&mut self, n: &mut Vec<Child>) {
+    n.retain_mut(|child| match child {
+      Child::Element(element) if is_definition_container(element) => {
+        let mut useful = vec![];
+        collect_useful_nodes(std::mem::take(&mut element.children), &mut useful);
+        element.children = useful;
+        !element.children.is_empty()
+      }
+      _ => true,
+    });
+    n.visit_mut_children_with(self);
+  }
+}
+
+#[cfg(test)]
+mod tests {
+  use super::*;
+  use crate::svgo::tests::code_test;
+
+  #[test]
+  fn removes_elements_without_id_inside_defs() {
+    code_test(
+      r#"<svg><defs><path d="M0 0"/><g><path id="a" d="M1 1"/></g><style>.a{}</style></defs></svg>"#,
+      |document| document.visit_mut_with(&mut Visitor),
+      r#"<svg><defs><path id="a" d="M1 1"/><style>.a{}</style></defs></svg>"#,
+    );
+  }
+
+  #[test]
+  fn removes_non_rendering_elements_without_id() {
+    code_test(
+      r#"<svg><linearGradient><stop offset="0"/></linearGradient><clipPath><path id="a" d="M0 0"/></clipPath><mask id="b"><pattern><rect/></pattern></mask><symbol><style>.a{}</style></symbol><defs><marker><path d="M0 0"/></marker></defs></svg>"#,
+      |document| document.visit_mut_with(&mut Visitor),
+      r#"<svg><clipPath><path id="a" d="M0 0"/></clipPath><mask id="b"/><symbol><style>.a{}</style></symbol></svg>"#,
+    );
+  }
+}
@@ -0,0 +1,41 @@
+use swc_xml::{
+  ast::{Child, Document},
+  visit::VisitMut,
+};
+
+pub struct Visitor;
+
+impl VisitMut for Visitor {
+  fn visit_mut_document(&mut self, n: &mut Document) {
+    n.children.retain(|child| match child {
+      Child::ProcessingInstruction(instruction) => instruction.target != *"xml",
+      _ => true,
+    });
+  }
+}
+
+#[cfg(test)]
+mod tests {
+  use swc_xml::visit::VisitMutWith;
+
+  use super::*;
+  use crate::svgo::tests::code_test;
+
+  #[test]
+  fn removes_xml_declaration() {
+    code_test(
+      r#"<?xml version="1.0" encoding="utf-8"?><svg/>"#,
+      |document| document.visit_mut_with(&mut Visitor),
+      r#"<svg/>"#,
+    );
+  }
+
+  #[test]
+  fn keeps_other_processing_instructions() {
+    code_test(
+      r#"<?xml-stylesheet href="style.css"?><svg/>"#,
+      |document| document.visit_mut_with(&mut Visitor),
+      r#"<?xml-stylesheet href="style.css"?><svg/>"#,
+    );
+  }
+}
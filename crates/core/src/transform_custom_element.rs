@@ -0,0 +1,184 @@
+use std::{collections::BTreeSet, rc::Rc};
+
+use swc_core::{
+  common::{comments::Comments, FileName, SourceMap, DUMMY_SP},
+  ecma::{
+    ast::*,
+    parser,
+    visit::{VisitMut, VisitMutWith},
+  },
+};
+
+use crate::{jsx_to_markup, transform_svg_component::DropSpan, Config, SvgrError};
+
+fn parse_module(source: String) -> Result<Module, SvgrError> {
+  let cm = Rc::<SourceMap>::default();
+  let fm = cm.new_source_file(FileName::Anon.into(), source);
+
+  let mut recovered_errors = vec![];
+  let mut module = parser::parse_file_as_module(
+    fm.as_ref(),
+    parser::Syntax::Typescript(Default::default()),
+    EsVersion::latest(),
+    None,
+    &mut recovered_errors,
+  )
+  .map_err(|e| SvgrError::Codegen(format!("invalid template literal: {}", e.kind().msg())))?;
+  module.visit_mut_with(&mut DropSpan);
+  Ok(module)
+}
+
+fn parse_expr(source: String) -> Result<Box<Expr>, SvgrError> {
+  let module = parse_module(format!("({});", source))?;
+  match module.body.into_iter().next() {
+    Some(ModuleItem::Stmt(Stmt::Expr(ExprStmt { expr, .. }))) => match *expr {
+      Expr::Paren(ParenExpr { expr, .. }) => Ok(expr),
+      expr => Ok(Box::new(expr)),
+    },
+    _ => Err(SvgrError::Codegen(
+      "invalid template literal: not an expression".to_string(),
+    )),
+  }
+}
+
+/// Replace the JSX with template literals, the helpers they use are recorded.
+struct Visitor<'a> {
+  cm: &'a Rc<SourceMap>,
+  syntax: jsx_to_markup::Syntax,
+  helpers: BTreeSet<&'static str>,
+  comments: &'a dyn Comments,
+  error: Option<SvgrError>,
+}
+
+impl VisitMut for Visitor<'_> {
+  fn visit_mut_expr(&mut self, n: &mut Expr) {
+    let Expr::JSXElement(jsx) = n else {
+      n.visit_mut_children_with(self);
+      return;
+    };
+    if self.error.is_some() {
+      return;
+    }
+
+    let result = jsx_to_markup::to_template_literal(
+      self.cm,
+      jsx,
+      self.syntax,
+      &mut self.helpers,
+      Some(self.comments),
+    )
+    .and_then(parse_expr);
+    match result {
+      Ok(expr) => *n = *expr,
+      Err(error) => self.error = Some(error),
+    }
+  }
+}
+
+fn create_import(names: &[&str], source: &str) -> ModuleItem {
+  ModuleItem::ModuleDecl(ModuleDecl::Import(ImportDecl {
+    span: DUMMY_SP,
+    specifiers: names.iter().map(|name| create_specifier(name)).collect(),
+    src: Box::new(Str::from(source)),
+    type_only: false,
+    with: None,
+    phase: Default::default(),
+  }))
+}
+
+fn create_specifier(name: &str) -> ImportSpecifier {
+  ImportSpecifier::Named(ImportNamedSpecifier {
+    span: DUMMY_SP,
+    local: Ident::from(name),
+    imported: None,
+    is_type_only: false,
+  })
+}
+
+fn get_last_import_index(m: &Module) -> usize {
+  m.body
+    .iter()
+    .rposition(|item| matches!(item, ModuleItem::ModuleDecl(ModuleDecl::Import(_))))
+    .map_or(0, |index| index + 1)
+}
+
+/// Import the Lit helpers used by the template, next to the `html` tag.
+fn add_lit_helpers(m: &mut Module, helpers: &BTreeSet<&'static str>) {
+  let names = ["svg", "nothing"]
+    .into_iter()
+    .filter(|name| helpers.contains(name))
+    .collect::<Vec<&str>>();
+  let lit_import = m.body.iter_mut().find_map(|item| match item {
+    ModuleItem::ModuleDecl(ModuleDecl::Import(import)) if import.src.value == *"lit" => {
+      Some(import)
+    }
+    _ => None,
+  });
+  match lit_import {
+    Some(import) => import
+      .specifiers
+      .extend(names.iter().map(|name| create_specifier(name))),
+    None if !names.is_empty() => m.body.insert(0, create_import(&names, "lit")),
+    None => {}
+  }
+
+  if helpers.contains("ifDefined") {
+    let index = get_last_import_index(m);
+    m.body.insert(
+      index,
+      create_import(&["ifDefined"], "lit/directives/if-defined.js"),
+    );
+  }
+}
+
+/// Declare the function escaping the values interpolated in the HTML, after the imports.
+fn add_html_helpers(
+  m: &mut Module,
+  helpers: &BTreeSet<&'static str>,
+  config: &Config,
+) -> Result<(), SvgrError> {
+  if !helpers.contains("escapeHtml") {
+    return Ok(());
+  }
+
+  let param = if config.typescript {
+    "value: unknown"
+  } else {
+    "value"
+  };
+  let helper = parse_module(format!(
+    "const escapeHtml = ({}) => String(value).replace(/[&<>\"']/g, (char) => `&#${{char.charCodeAt(0)}};`);",
+    param
+  ))?;
+  let index = get_last_import_index(m);
+  m.body.splice(index..index, helper.body);
+  Ok(())
+}
+
+/// Render the JSX of a custom element as template literals, with the `html` tag of Lit or as
+/// plain HTML.
+pub fn render(
+  cm: &Rc<SourceMap>,
+  m: &mut Module,
+  config: &Config,
+  syntax: jsx_to_markup::Syntax,
+  comments: &dyn Comments,
+) -> Result<(), SvgrError> {
+  let mut visitor = Visitor {
+    cm,
+    syntax,
+    helpers: BTreeSet::new(),
+    comments,
+    error: None,
+  };
+  m.visit_mut_with(&mut visitor);
+  if let Some(error) = visitor.error {
+    return Err(error);
+  }
+
+  match syntax {
+    jsx_to_markup::Syntax::Lit => add_lit_helpers(m, &visitor.helpers),
+    _ => add_html_helpers(m, &visitor.helpers, config)?,
+  }
+  Ok(())
+}
@@ -6,7 +6,7 @@ use swc_core::common::SyntaxContext;
 use swc_core::{
   common::{
     comments::{Comment, CommentKind, Comments},
-    Span, DUMMY_SP,
+    BytePos, Span, DUMMY_SP,
   },
   ecma::{
     ast::*,
@@ -15,33 +15,69 @@ use swc_core::{
   },
 };
 
+use crate::core::config::{Config, NativeElementPolicy};
+
+/// The position the comment listing the dropped elements is attached to, when the import has
+/// no position. It is reserved for comments: the code generator prints them and the source map
+/// ignores it.
+const COMMENT_POS: BytePos = BytePos(u32::MAX - 1);
+
 pub struct Visitor<'a> {
+  config: &'a Config,
   replaced_components: Rc<RefCell<LinkedHashSet<String>>>,
   unsupported_components: Rc<RefCell<LinkedHashSet<String>>>,
+  rejected_components: Rc<RefCell<LinkedHashSet<String>>>,
   comments: &'a dyn Comments,
 }
 
 impl<'a> Visitor<'a> {
-  pub fn new(comments: &'a dyn Comments) -> Self {
+  pub fn new(config: &'a Config, comments: &'a dyn Comments) -> Self {
     Visitor {
+      config,
       replaced_components: Rc::new(RefCell::new(LinkedHashSet::new())),
       unsupported_components: Rc::new(RefCell::new(LinkedHashSet::new())),
+      rejected_components: Rc::new(RefCell::new(LinkedHashSet::new())),
       comments,
     }
   }
+
+  /// The elements dropped because they are not supported by react-native-svg, in order.
+  pub fn unsupported_components(&self) -> Vec<String> {
+    self
+      .unsupported_components
+      .borrow()
+      .iter()
+      .cloned()
+      .collect()
+  }
+
+  /// The elements whose policy is `NativeElementPolicy::Error`, in order.
+  pub fn rejected_components(&self) -> Vec<String> {
+    self.rejected_components.borrow().iter().cloned().collect()
+  }
 }
 
 impl VisitMut for Visitor<'_> {
   fn visit_mut_module(&mut self, n: &mut Module) {
     let mut svg_element_visitor = SvgElementVisitor::new(
+      self.config,
       self.replaced_components.clone(),
       self.unsupported_components.clone(),
+      self.rejected_components.clone(),
     );
     n.visit_mut_with(&mut svg_element_visitor);
 
-    let mut import_decl_visitor = ImportDeclVisitor::new(self.replaced_components.clone());
+    let has_unsupported_components = !self.unsupported_components.borrow().is_empty();
+    let mut import_decl_visitor = ImportDeclVisitor::new(
+      &self.config.native_import_source,
+      self.replaced_components.clone(),
+      has_unsupported_components,
+    );
     n.visit_mut_with(&mut import_decl_visitor);
 
+    if !has_unsupported_components {
+      return;
+    }
     if let Some(span) = import_decl_visitor.import_decl_span {
       let component_list = self
         .unsupported_components
@@ -66,30 +102,38 @@ impl VisitMut for Visitor<'_> {
   }
 }
 
-struct SvgElementVisitor {
+struct SvgElementVisitor<'a> {
+  config: &'a Config,
   replaced_components: Rc<RefCell<LinkedHashSet<String>>>,
   unsupported_components: Rc<RefCell<LinkedHashSet<String>>>,
+  rejected_components: Rc<RefCell<LinkedHashSet<String>>>,
 }
 
-impl SvgElementVisitor {
+impl<'a> SvgElementVisitor<'a> {
   fn new(
+    config: &'a Config,
     replaced_components: Rc<RefCell<LinkedHashSet<String>>>,
     unsupported_components: Rc<RefCell<LinkedHashSet<String>>>,
+    rejected_components: Rc<RefCell<LinkedHashSet<String>>>,
   ) -> Self {
     SvgElementVisitor {
+      config,
       replaced_components,
       unsupported_components,
+      rejected_components,
     }
   }
 }
 
-impl VisitMut for SvgElementVisitor {
+impl VisitMut for SvgElementVisitor<'_> {
   fn visit_mut_jsx_element(&mut self, n: &mut JSXElement) {
     if let JSXElementName::Ident(ident) = &mut n.opening.name {
       if ident.sym.as_str() == "svg" {
         let mut jsx_element_visitor = JSXElementVisitor::new(
+          self.config,
           self.replaced_components.clone(),
           self.unsupported_components.clone(),
+          self.rejected_components.clone(),
         );
         ident.sym = "Svg".into();
         if let Some(closing) = &mut n.closing {
@@ -103,50 +147,78 @@ impl VisitMut for SvgElementVisitor {
   }
 }
 
-struct JSXElementVisitor {
+struct JSXElementVisitor<'a> {
+  config: &'a Config,
   element_to_component: HashMap<&'static str, &'static str>,
 
   replaced_components: Rc<RefCell<LinkedHashSet<String>>>,
   unsupported_components: Rc<RefCell<LinkedHashSet<String>>>,
+  rejected_components: Rc<RefCell<LinkedHashSet<String>>>,
 }
 
-impl JSXElementVisitor {
+impl<'a> JSXElementVisitor<'a> {
   fn new(
+    config: &'a Config,
     replaced_components: Rc<RefCell<LinkedHashSet<String>>>,
     unsupported_components: Rc<RefCell<LinkedHashSet<String>>>,
+    rejected_components: Rc<RefCell<LinkedHashSet<String>>>,
   ) -> Self {
     JSXElementVisitor {
+      config,
       element_to_component: get_element_to_component(),
       replaced_components,
       unsupported_components,
+      rejected_components,
+    }
+  }
+
+  fn get_component(&self, element: &str) -> Option<&str> {
+    match self.config.native_components.get(element) {
+      Some(component) => Some(component),
+      None => self.element_to_component.get(element).copied(),
     }
   }
 
+  /// Replace the element by its component or apply its policy, returns whether it is removed.
   fn replace_element(&self, n: &mut JSXElement) -> bool {
     if let JSXElementName::Ident(ident) = &mut n.opening.name {
       let element = ident.sym.to_string();
-      if let Some(component) = self.element_to_component.get(&element.as_str()) {
-        self
-          .replaced_components
-          .borrow_mut()
-          .insert(component.to_string());
-        ident.sym = JsWord::from(*component);
-        if let Some(closing) = &mut n.closing {
-          if let JSXElementName::Ident(ident) = &mut closing.name {
-            ident.sym = JsWord::from(*component);
+      let policy = match self.config.native_elements.get(&element) {
+        Some(policy) => policy,
+        None => match self.get_component(&element) {
+          Some(component) => {
+            self
+              .replaced_components
+              .borrow_mut()
+              .insert(component.to_string());
+            ident.sym = JsWord::from(component);
+            if let Some(closing) = &mut n.closing {
+              if let JSXElementName::Ident(ident) = &mut closing.name {
+                ident.sym = JsWord::from(component);
+              }
+            }
+            return false;
           }
+          None => &self.config.native_unsupported,
+        },
+      };
+      match policy {
+        NativeElementPolicy::Keep => {}
+        NativeElementPolicy::Drop => {
+          self.unsupported_components.borrow_mut().insert(element);
+          return true;
+        }
+        NativeElementPolicy::Error => {
+          self.rejected_components.borrow_mut().insert(element);
+          return true;
         }
-      } else {
-        // Remove element if not supported
-        self.unsupported_components.borrow_mut().insert(element);
-        return true;
       }
     }
     false
   }
 }
 
-impl VisitMut for JSXElementVisitor {
+impl VisitMut for JSXElementVisitor<'_> {
   fn visit_mut_jsx_element(&mut self, n: &mut JSXElement) {
     n.visit_mut_children_with(self);
 
@@ -191,23 +263,39 @@ fn get_element_to_component() -> HashMap<&'static str, &'static str> {
   ])
 }
 
-struct ImportDeclVisitor {
+struct ImportDeclVisitor<'a> {
+  import_source: &'a str,
   replaced_components: Rc<RefCell<LinkedHashSet<String>>>,
+  /// Whether a comment is attached to the import, it is given a position if it has none.
+  has_comment: bool,
   import_decl_span: Option<Span>,
 }
 
-impl ImportDeclVisitor {
-  fn new(replaced_components: Rc<RefCell<LinkedHashSet<String>>>) -> Self {
+impl<'a> ImportDeclVisitor<'a> {
+  fn new(
+    import_source: &'a str,
+    replaced_components: Rc<RefCell<LinkedHashSet<String>>>,
+    has_comment: bool,
+  ) -> Self {
     ImportDeclVisitor {
+      import_source,
       replaced_components,
+      has_comment,
       import_decl_span: None,
     }
   }
+
+  fn set_import_decl_span(&mut self, n: &mut ImportDecl) {
+    if self.has_comment && n.span.hi.is_dummy() {
+      n.span = Span::new(n.span.lo, COMMENT_POS);
+    }
+    self.import_decl_span = Some(n.span);
+  }
 }
 
-impl VisitMut for ImportDeclVisitor {
+impl VisitMut for ImportDeclVisitor<'_> {
   fn visit_mut_import_decl(&mut self, n: &mut ImportDecl) {
-    if n.src.value.as_str() == "react-native-svg" {
+    if n.src.value.as_str() == self.import_source {
       for component in self.replaced_components.borrow().iter() {
         if n.specifiers.iter().any(|specifier| {
           if let ImportSpecifier::Named(named) = specifier {
@@ -233,7 +321,7 @@ impl VisitMut for ImportDeclVisitor {
           }));
       }
 
-      self.import_decl_span = Some(n.span);
+      self.set_import_decl_span(n);
     } else if n.src.value.as_str() == "expo" {
       n.specifiers
         .push(ImportSpecifier::Named(ImportNamedSpecifier {
@@ -243,7 +331,7 @@ impl VisitMut for ImportDeclVisitor {
           is_type_only: false,
         }));
 
-      self.import_decl_span = Some(n.span);
+      self.set_import_decl_span(n);
     }
   }
 }
@@ -263,6 +351,10 @@ mod tests {
   use super::*;
 
   fn code_test(input: &str, expected: &str) {
+    code_test_with_config(input, expected, &Default::default());
+  }
+
+  fn code_test_with_config(input: &str, expected: &str, config: &Config) {
     let cm = Rc::<SourceMap>::default();
     let fm = cm.new_source_file(FileName::Anon.into(), input.to_string());
 
@@ -281,7 +373,7 @@ mod tests {
     let module = parser.parse_module().unwrap();
 
     let comments = SingleThreadedComments::default();
-    let module = module.fold_with(&mut as_folder(Visitor::new(&comments)));
+    let module = module.fold_with(&mut as_folder(Visitor::new(config, &comments)));
 
     let mut buf = vec![];
     let mut emitter = Emitter {
@@ -308,4 +400,22 @@ mod tests {
       r#"import Svg, { G } from 'react-native-svg'; /* SVGR has dropped some elements not supported by react-native-svg: div */ <Svg><G/></Svg>;"#,
     );
   }
+
+  #[test]
+  fn should_use_configured_components_and_policies() {
+    code_test_with_config(
+      r#"import Svg from 'react-native-svg-web'; <svg><filter/><style/><path/><div/></svg>;"#,
+      r#"import Svg, { Filter } from 'react-native-svg-web';<Svg><Filter/><style/><div/></Svg>;"#,
+      &Config {
+        native_components: HashMap::from([("filter".to_string(), "Filter".to_string())]),
+        native_import_source: "react-native-svg-web".to_string(),
+        native_elements: HashMap::from([
+          ("style".to_string(), NativeElementPolicy::Keep),
+          ("path".to_string(), NativeElementPolicy::Error),
+        ]),
+        native_unsupported: NativeElementPolicy::Keep,
+        ..Default::default()
+      },
+    );
+  }
 }
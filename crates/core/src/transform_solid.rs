@@ -0,0 +1,102 @@
+use swc_core::{
+  common::DUMMY_SP,
+  ecma::{
+    ast::*,
+    visit::{VisitMut, VisitMutWith},
+  },
+};
+
+use crate::{util::create_ident, Config, ExpandProps, FallbackIds};
+
+/// The props used by the JSX, they are split from the props given to the SVG.
+pub fn get_local_props(config: &Config) -> Vec<&'static str> {
+  let mut names = vec![];
+  if config.title_prop {
+    names.push("title");
+    names.push("titleId");
+  }
+  if config.desc_prop {
+    names.push("desc");
+    names.push("descId");
+  }
+  if config.r#ref && config.expand_props == ExpandProps::None {
+    names.push("ref");
+  }
+  names
+}
+
+/// Whether the props are split with `splitProps`, into `local` and `others`.
+pub fn need_split_props(config: &Config) -> bool {
+  config.expand_props != ExpandProps::None && !get_local_props(config).is_empty()
+}
+
+/// Whether the props are merged with `mergeProps`, into `merged`, to give a fallback to `titleId`
+/// and `descId`.
+pub fn need_merge_props(config: &Config) -> bool {
+  config.fallback_ids != FallbackIds::None && (config.title_prop || config.desc_prop)
+}
+
+/// Read the props used by the JSX from the props object, SolidJS props must not be destructured
+/// to stay reactive.
+pub struct Visitor {
+  names: Vec<&'static str>,
+  /// The object holding the props used by the JSX.
+  object: &'static str,
+  /// The object holding the props given to the SVG.
+  rest: &'static str,
+  in_jsx: bool,
+}
+
+impl Visitor {
+  pub fn new(config: &Config) -> Self {
+    let split = need_split_props(config);
+    Self {
+      names: get_local_props(config),
+      object: match (split, need_merge_props(config)) {
+        (true, _) => "local",
+        (false, true) => "merged",
+        (false, false) => "props",
+      },
+      rest: if split { "others" } else { "props" },
+      in_jsx: false,
+    }
+  }
+}
+
+impl VisitMut for Visitor {
+  fn visit_mut_jsx_element(&mut self, n: &mut JSXElement) {
+    let in_jsx = self.in_jsx;
+    self.in_jsx = true;
+    n.visit_mut_children_with(self);
+    self.in_jsx = in_jsx;
+  }
+
+  fn visit_mut_jsx_attr_or_spread(&mut self, n: &mut JSXAttrOrSpread) {
+    n.visit_mut_children_with(self);
+
+    if let JSXAttrOrSpread::SpreadElement(spread) = n {
+      if let Expr::Ident(ident) = spread.expr.as_mut() {
+        if &*ident.sym == "props" {
+          ident.sym = self.rest.into();
+        }
+      }
+    }
+  }
+
+  fn visit_mut_expr(&mut self, n: &mut Expr) {
+    n.visit_mut_children_with(self);
+
+    if !self.in_jsx {
+      return;
+    }
+    if let Expr::Ident(ident) = n {
+      if self.names.contains(&&*ident.sym) {
+        *n = Expr::Member(MemberExpr {
+          span: ident.span,
+          obj: Box::new(Expr::Ident(create_ident(self.object))),
+          prop: MemberProp::Ident(IdentName::new(ident.sym.clone(), DUMMY_SP)),
+        });
+      }
+    }
+  }
+}
@@ -0,0 +1,218 @@
+use swc_core::ecma::ast::*;
+
+use super::{
+  template::{StringTemplate, Template},
+  variables::{create_exports, TemplateVariables},
+};
+use crate::{core, SvgrError, Target};
+
+/// The props read by the JSX, with the attributes they are read from.
+fn get_props(config: &core::config::Config) -> Vec<(&'static str, &'static str)> {
+  let mut props = vec![];
+  if config.title_prop {
+    props.push(("title", "title"));
+    props.push(("titleId", "title-id"));
+  }
+  if config.desc_prop {
+    props.push(("desc", "desc"));
+    props.push(("descId", "desc-id"));
+  }
+  props
+}
+
+fn is_valid_element_name(name: &str) -> bool {
+  name.starts_with(|c: char| c.is_ascii_lowercase())
+    && name.contains('-')
+    && !name.chars().any(|c| c.is_uppercase() || c.is_whitespace())
+}
+
+/// The name the custom element is registered under, it must contain a hyphen.
+pub fn get_element_name(
+  config: &core::config::Config,
+  component_name: &str,
+) -> Result<String, SvgrError> {
+  match &config.element_name {
+    Some(element_name) if is_valid_element_name(element_name) => Ok(element_name.clone()),
+    Some(element_name) => Err(SvgrError::Configuration(format!(
+      r#""elementName" must start with a lowercase letter and contain a hyphen, got "{}""#,
+      element_name
+    ))),
+    None => {
+      let element_name = core::state::kebab_case(component_name);
+      if element_name.contains('-') {
+        Ok(element_name)
+      } else {
+        Ok(format!("{}-icon", element_name))
+      }
+    }
+  }
+}
+
+/// The import of the base class and the name it is extended by.
+fn get_base_class(config: &core::config::Config) -> (Option<String>, String) {
+  match &config.base_class {
+    Some(base_class) => (
+      base_class
+        .source
+        .as_ref()
+        .map(|source| format!("import {{ {} }} from \"{}\";\n", base_class.name, source)),
+      base_class.name.clone(),
+    ),
+    None if config.target == Target::Lit => (None, "LitElement".to_string()),
+    None => (None, "HTMLElement".to_string()),
+  }
+}
+
+/// The body of a `LitElement`, the props are reactive properties rendered with the `html` tag.
+fn get_lit_body(config: &core::config::Config) -> String {
+  let props = get_props(config);
+
+  let mut body = String::new();
+  if !props.is_empty() {
+    let properties = props
+      .iter()
+      .map(|(name, attribute)| match name == attribute {
+        true => format!("{}: {{}}", name),
+        false => format!("{}: {{ attribute: \"{}\" }}", name, attribute),
+      })
+      .collect::<Vec<String>>();
+    body.push_str(&format!(
+      "static properties = {{ {} }};\n",
+      properties.join(", ")
+    ));
+    if config.typescript {
+      for (name, _) in &props {
+        // `title` is already declared by `HTMLElement`.
+        match *name {
+          "title" => body.push_str("declare title: string;\n"),
+          name => body.push_str(&format!("declare {}?: string;\n", name)),
+        }
+      }
+    }
+  }
+
+  let names = props.iter().map(|(name, _)| *name).collect::<Vec<&str>>();
+  body.push_str("render() {\n");
+  if !names.is_empty() {
+    body.push_str(&format!("const {{ {} }} = this;\n", names.join(", ")));
+  }
+  body.push_str("return %%jsx%%;\n}\n");
+  body
+}
+
+/// The body of an `HTMLElement`, the SVG is rendered in its shadow root each time an observed
+/// attribute changes.
+fn get_web_component_body(config: &core::config::Config) -> String {
+  let props = get_props(config);
+
+  let mut body = String::new();
+  if !props.is_empty() {
+    let attributes = props
+      .iter()
+      .map(|(_, attribute)| format!("\"{}\"", attribute))
+      .collect::<Vec<String>>();
+    body.push_str(&format!(
+      "static observedAttributes = [{}];\n",
+      attributes.join(", ")
+    ));
+  }
+  body.push_str("constructor() {\nsuper();\nthis.attachShadow({ mode: \"open\" });\n}\n");
+  body.push_str("connectedCallback() {\nthis.render();\n}\n");
+  if !props.is_empty() {
+    body.push_str("attributeChangedCallback() {\nthis.render();\n}\n");
+  }
+
+  body.push_str("render() {\n");
+  for (name, attribute) in &props {
+    body.push_str(&format!(
+      "const {} = this.getAttribute(\"{}\") ?? undefined;\n",
+      name, attribute
+    ));
+  }
+  let shadow_root = if config.typescript {
+    "this.shadowRoot!"
+  } else {
+    "this.shadowRoot"
+  };
+  body.push_str(&format!("{}.innerHTML = %%jsx%%;\n}}\n", shadow_root));
+  body
+}
+
+fn get_source(config: &core::config::Config, element_name: &str) -> String {
+  let (base_class_import, base_class) = get_base_class(config);
+
+  let mut source = String::new();
+  if config.target == Target::Lit {
+    // The other helpers used by the template are imported once the JSX is rendered.
+    match &config.base_class {
+      Some(_) => source.push_str("import { html } from \"lit\";\n"),
+      None => source.push_str("import { LitElement, html } from \"lit\";\n"),
+    }
+  }
+  source.push_str(&base_class_import.unwrap_or_default());
+
+  let body = match config.target {
+    Target::Lit => get_lit_body(config),
+    _ => get_web_component_body(config),
+  };
+  source.push_str(&format!(
+    "class %%componentName%% extends {} {{\n{}}}\n",
+    base_class, body
+  ));
+  source.push_str(&format!(
+    "if (!customElements.get(\"{0}\")) {{\ncustomElements.define(\"{0}\", %%componentName%%);\n}}\n",
+    element_name
+  ));
+  source.push_str("%%exports%%\n");
+  source
+}
+
+pub fn transform(
+  jsx: JSXElement,
+  config: &core::config::Config,
+  state: &core::state::InternalConfig,
+) -> Result<Module, SvgrError> {
+  let element_name = get_element_name(config, &state.component_name)?;
+  let exports = create_exports(
+    &state.component_name,
+    Some(config.named_export.clone()),
+    &config.export_type,
+    state,
+  )?;
+
+  StringTemplate::new(get_source(config, &element_name)).render(TemplateVariables {
+    component_name: state.component_name.clone(),
+    interfaces: vec![],
+    props: vec![],
+    imports: vec![],
+    exports,
+    jsx,
+  })
+}
+
+#[cfg(test)]
+mod tests {
+  use super::*;
+
+  #[test]
+  fn derives_element_name_from_component_name() {
+    let config = core::config::Config::default();
+    let element_name = |name| get_element_name(&config, name).unwrap();
+    assert_eq!(element_name("SvgArrowLeft"), "svg-arrow-left");
+    assert_eq!(element_name("SVGIcon"), "svg-icon");
+    assert_eq!(element_name("Svg2Fa"), "svg2-fa");
+    assert_eq!(element_name("Star"), "star-icon");
+  }
+
+  #[test]
+  fn fails_with_invalid_element_name() {
+    let config = core::config::Config {
+      element_name: Some("Icon".to_string()),
+      ..Default::default()
+    };
+    assert!(matches!(
+      get_element_name(&config, "SvgComponent"),
+      Err(SvgrError::Configuration(_))
+    ));
+  }
+}
@@ -0,0 +1,187 @@
+use swc_core::{
+  common::{SyntaxContext, DUMMY_SP},
+  ecma::ast::*,
+};
+
+use crate::{
+  core::{
+    config::{Config, FallbackIds},
+    state::InternalConfig,
+  },
+  unique_ids::{
+    add_component_statements, create_const, create_hook_callee, create_unique_id_call, get_prefix,
+  },
+  util::{create_ident, create_tpl_element},
+  SvgrError, Target,
+};
+
+/// The variable holding the id the fallback ids are derived from.
+pub const FALLBACK_ID: &str = "fallbackId";
+
+/// The module variable counting the rendered components, in `counter` mode.
+pub const ID_COUNTER: &str = "idCounter";
+
+/// The id props given a fallback, with the suffix appended to the generated id.
+pub fn get_fallback_props(config: &Config) -> Vec<(&'static str, &'static str)> {
+  if config.fallback_ids == FallbackIds::None {
+    return vec![];
+  }
+  let mut props = vec![];
+  if config.title_prop {
+    props.push(("titleId", "title"));
+  }
+  if config.desc_prop {
+    props.push(("descId", "desc"));
+  }
+  props
+}
+
+/// The name the id prop is destructured as, so the fallback can be declared under its name.
+pub fn get_prop_alias(name: &str) -> String {
+  format!("{}Prop", name)
+}
+
+/// Fail when the fallback ids are used by a target that can not generate them.
+pub fn check_target(config: &Config) -> Result<(), SvgrError> {
+  match config.target {
+    Target::React | Target::Solid => Ok(()),
+    _ if get_fallback_props(config).is_empty() => Ok(()),
+    _ => Err(SvgrError::Configuration(
+      r#""fallbackIds" is only supported by the "react" and "solid" targets"#.to_string(),
+    )),
+  }
+}
+
+/// `let idCounter = 0;`, inserted after the imports.
+fn add_id_counter(m: &mut Module) {
+  let index = m
+    .body
+    .iter()
+    .position(|item| !matches!(item, ModuleItem::ModuleDecl(ModuleDecl::Import(_))))
+    .unwrap_or(m.body.len());
+  let decl = ModuleItem::Stmt(Stmt::Decl(Decl::Var(Box::new(VarDecl {
+    span: DUMMY_SP,
+    ctxt: SyntaxContext::empty(),
+    kind: VarDeclKind::Let,
+    declare: false,
+    decls: vec![VarDeclarator {
+      span: DUMMY_SP,
+      name: Pat::Ident(BindingIdent::from(create_ident(ID_COUNTER))),
+      init: Some(Box::new(Expr::Lit(Lit::Num(0.0.into())))),
+      definite: false,
+    }],
+  }))));
+  m.body.insert(index, decl);
+}
+
+/// The start of the ids generated from the counter. Each module has its own counter, the ids are
+/// prefixed like the unique ids so two components of a page do not generate the same one.
+pub fn get_counter_prefix(state: &InternalConfig) -> String {
+  format!(
+    "svgr-{}-",
+    get_prefix(state.file_path.as_deref(), &state.component_name)
+  )
+}
+
+/// `` `svgr-star-1a2b3c-${++idCounter}` ``
+fn create_counter_id(state: &InternalConfig) -> Expr {
+  Expr::Tpl(Tpl {
+    span: DUMMY_SP,
+    exprs: vec![Box::new(Expr::Update(UpdateExpr {
+      span: DUMMY_SP,
+      op: UpdateOp::PlusPlus,
+      prefix: true,
+      arg: Box::new(Expr::Ident(create_ident(ID_COUNTER))),
+    }))],
+    quasis: vec![
+      create_tpl_element(&get_counter_prefix(state), false),
+      create_tpl_element("", true),
+    ],
+  })
+}
+
+/// `const [fallbackId] = React.useState(() => `svgr-star-1a2b3c-${++idCounter}`);`, the id is kept across
+/// renders.
+fn create_counter_state(m: &mut Module, config: &Config, state: &InternalConfig) -> Stmt {
+  let callee = create_hook_callee(m, config, "useState");
+  let init = Expr::Arrow(ArrowExpr {
+    span: DUMMY_SP,
+    ctxt: SyntaxContext::empty(),
+    params: vec![],
+    body: Box::new(BlockStmtOrExpr::Expr(Box::new(create_counter_id(state)))),
+    is_async: false,
+    is_generator: false,
+    type_params: None,
+    return_type: None,
+  });
+  Stmt::Decl(Decl::Var(Box::new(VarDecl {
+    span: DUMMY_SP,
+    ctxt: SyntaxContext::empty(),
+    kind: VarDeclKind::Const,
+    declare: false,
+    decls: vec![VarDeclarator {
+      span: DUMMY_SP,
+      name: Pat::Array(ArrayPat {
+        span: DUMMY_SP,
+        elems: vec![Some(Pat::Ident(BindingIdent::from(create_ident(
+          FALLBACK_ID,
+        ))))],
+        optional: false,
+        type_ann: None,
+      }),
+      init: Some(Box::new(Expr::Call(CallExpr {
+        span: DUMMY_SP,
+        ctxt: SyntaxContext::empty(),
+        callee: Callee::Expr(Box::new(callee)),
+        args: vec![ExprOrSpread {
+          spread: None,
+          expr: Box::new(init),
+        }],
+        type_args: None,
+      }))),
+      definite: false,
+    }],
+  })))
+}
+
+/// `` const titleId = titleIdProp ?? `${fallbackId}-title`; ``
+fn create_fallback(name: &str, suffix: &str) -> Stmt {
+  let fallback = Expr::Tpl(Tpl {
+    span: DUMMY_SP,
+    exprs: vec![Box::new(Expr::Ident(create_ident(FALLBACK_ID)))],
+    quasis: vec![
+      create_tpl_element("", false),
+      create_tpl_element(&format!("-{}", suffix), true),
+    ],
+  });
+  create_const(
+    name,
+    Expr::Bin(BinExpr {
+      span: DUMMY_SP,
+      op: BinaryOp::NullishCoalescing,
+      left: Box::new(Expr::Ident(create_ident(&get_prop_alias(name)))),
+      right: Box::new(fallback),
+    }),
+  )
+}
+
+/// Declare the id props of a React component in its body, defaulting to a generated id when they
+/// are not given. The arrow function of the component is given a block body.
+pub fn transform(m: &mut Module, config: &Config, state: &InternalConfig) -> Result<(), SvgrError> {
+  let props = get_fallback_props(config);
+  if props.is_empty() {
+    return Ok(());
+  }
+
+  let mut stmts = vec![match config.fallback_ids {
+    FallbackIds::Counter => {
+      add_id_counter(m);
+      create_counter_state(m, config, state)
+    }
+    _ => create_const(FALLBACK_ID, create_unique_id_call(m, config)?),
+  }];
+  for (name, suffix) in props {
+    stmts.push(create_fallback(name, suffix));
+  }
+  add_component_statements(m, stmts)
+}
@@ -1,9 +1,31 @@
-use swc_core::common::SyntaxContext;
-use swc_core::{common::DUMMY_SP, ecma::ast::*};
+use swc_core::{
+  common::{Span, DUMMY_SP},
+  ecma::{ast::*, visit::VisitMut},
+};
 
-use crate::{core, SvgrError};
+use crate::{core, SvgrError, Target};
 
+mod custom_element;
+mod fallback_ids;
+mod solid;
+mod svelte;
+mod template;
 mod variables;
+mod vue;
+
+pub use self::template::{DefaultTemplate, StringTemplate, Template};
+pub(crate) use self::variables::get_or_create_named_import;
+pub use self::variables::TemplateVariables;
+
+/// Reset the spans of the code parsed from strings, they belong to another source map
+/// and must not end up in the source map of the SVG.
+pub(crate) struct DropSpan;
+
+impl VisitMut for DropSpan {
+  fn visit_mut_span(&mut self, n: &mut Span) {
+    *n = DUMMY_SP;
+  }
+}
 
 fn get_variables_options(config: &core::config::Config) -> variables::Options {
   let mut opts = variables::Options {
@@ -12,10 +34,11 @@ fn get_variables_options(config: &core::config::Config) -> variables::Options {
     desc_prop: config.desc_prop,
     expand_props: config.expand_props.clone(),
     r#ref: config.r#ref,
-    native: config.native,
+    native: config.native.then(|| config.native_import_source.clone()),
     memo: config.memo,
     named_export: Some(config.named_export.clone()),
     export_type: config.export_type.clone(),
+    fallback_ids: !fallback_ids::get_fallback_props(config).is_empty(),
     ..Default::default()
   };
 
@@ -52,67 +75,41 @@ fn get_variables_options(config: &core::config::Config) -> variables::Options {
   opts
 }
 
+/// Create the component module, `css_module` is the source of the CSS module its class names
+/// are read from.
 pub fn transform(
   jsx_element: JSXElement,
   config: &core::config::Config,
   state: &core::state::InternalConfig,
+  css_module: Option<&str>,
 ) -> Result<Module, SvgrError> {
-  let variables_options = get_variables_options(config);
+  fallback_ids::check_target(config)?;
+  match config.target {
+    Target::Vue | Target::VueSfc => return vue::transform(jsx_element, config, state),
+    Target::Solid => return solid::transform(jsx_element, config, state),
+    Target::Svelte => return svelte::transform(jsx_element, config, state),
+    Target::Lit | Target::WebComponent => {
+      return custom_element::transform(jsx_element, config, state)
+    }
+    Target::React => {}
+  }
+
+  let mut variables_options = get_variables_options(config);
+  variables_options.css_module = css_module.map(|css_module| css_module.to_string());
 
   let variables = variables::get_variables(variables_options, state, jsx_element)?;
 
-  let mut body = vec![];
-
-  for import in variables.imports {
-    body.push(import);
-  }
-
-  for interface in variables.interfaces {
-    body.push(interface);
-  }
-
-  body.push(ModuleItem::Stmt(Stmt::Decl(Decl::Var(Box::new(VarDecl {
-    span: DUMMY_SP,
-    ctxt: SyntaxContext::empty(),
-    kind: VarDeclKind::Const,
-    declare: false,
-    decls: vec![VarDeclarator {
-      span: DUMMY_SP,
-      name: Pat::Ident(BindingIdent::from(Ident::new(
-        state.component_name.clone().into(),
-        DUMMY_SP,
-        SyntaxContext::empty(),
-      ))),
-      definite: false,
-      init: Some(Box::new(Expr::Arrow(ArrowExpr {
-        span: DUMMY_SP,
-        ctxt: SyntaxContext::empty(),
-        params: variables.props,
-        body: Box::new(BlockStmtOrExpr::Expr(Box::new(Expr::JSXElement(Box::new(
-          variables.jsx,
-        ))))),
-        is_async: false,
-        is_generator: false,
-        type_params: None,
-        return_type: None,
-      }))),
-    }],
-  })))));
-
-  for export in variables.exports {
-    body.push(export);
-  }
-
-  Ok(Module {
-    span: DUMMY_SP,
-    body,
-    shebang: None,
-  })
+  let mut module = match &config.template {
+    Some(template) => template.render(variables)?,
+    None => DefaultTemplate.render(variables)?,
+  };
+  fallback_ids::transform(&mut module, config, state)?;
+  Ok(module)
 }
 
 #[cfg(test)]
 mod tests {
-  use std::rc::Rc;
+  use std::{rc::Rc, sync::Arc};
 
   use swc_core::{
     common::{FileName, SourceMap},
@@ -149,7 +146,7 @@ mod tests {
 
     let jsx_element = expr.as_jsx_element().unwrap();
 
-    let m = transform(*jsx_element.clone(), config, state).unwrap();
+    let m = transform(*jsx_element.clone(), config, state, None).unwrap();
 
     let mut buf = vec![];
     let mut emitter = Emitter {
@@ -661,6 +658,27 @@ export default img;
     );
   }
 
+  #[test]
+  fn fails_with_invalid_previous_export() {
+    let err = crate::transform(
+      r#"<svg><g/></svg>"#.to_string(),
+      Default::default(),
+      crate::State {
+        caller: Some(core::state::Caller {
+          previous_export: Some("export default {".to_string()),
+          ..Default::default()
+        }),
+        ..Default::default()
+      },
+    )
+    .unwrap_err();
+
+    assert_eq!(err.code(), "invalid-configuration");
+    assert!(err
+      .to_string()
+      .starts_with(r#"invalid configuration option: invalid "caller.previousExport": "#));
+  }
+
   #[test]
   fn with_named_export_and_export_type_option_and_without_previous_export_state_exports_via_named_export(
   ) {
@@ -687,7 +705,169 @@ export { SvgComponent as ReactComponent };
     );
   }
 
-  // TODO: custom templates
+  #[test]
+  fn custom_templates_supports_basic_template() {
+    test_code(
+      r#"<svg><g/></svg>"#,
+      &core::config::Config {
+        template: Some(Arc::new(StringTemplate::new(
+          "const MyComponent = () => <div />; export default MyComponent;".to_string(),
+        ))),
+        ..Default::default()
+      },
+      &core::state::InternalConfig {
+        ..Default::default()
+      },
+      r#"const MyComponent = ()=><div/>;
+export default MyComponent;
+"#,
+    );
+  }
+
+  #[test]
+  fn custom_templates_supports_placeholders() {
+    test_js_n_ts(
+      r#"<svg><g/></svg>"#,
+      &core::config::Config {
+        title_prop: true,
+        template: Some(Arc::new(StringTemplate::new(
+          r#"%%imports%%
+%%interfaces%%
+function %%componentName%%(%%props%%) {
+  return %%jsx%%;
+}
+%%componentName%%.displayName = "Icon";
+%%exports%%"#
+            .to_string(),
+        ))),
+        ..Default::default()
+      },
+      &core::state::InternalConfig {
+        ..Default::default()
+      },
+      r#"import * as React from "react";
+function SvgComponent({ title, titleId, ...props }) {
+    return <svg><g/></svg>;
+}
+SvgComponent.displayName = "Icon";
+export default SvgComponent;
+"#,
+      r#"import * as React from "react";
+import { SVGProps } from "react";
+interface SVGRProps {
+    title?: string;
+    titleId?: string;
+}
+function SvgComponent({ title, titleId, ...props }: SVGProps<SVGSVGElement> & SVGRProps) {
+    return <svg><g/></svg>;
+}
+SvgComponent.displayName = "Icon";
+export default SvgComponent;
+"#,
+    );
+  }
+
+  #[test]
+  fn custom_templates_supports_template_trait() {
+    struct DeclarationOnlyTemplate;
+
+    impl Template for DeclarationOnlyTemplate {
+      fn render(&self, variables: TemplateVariables) -> Result<Module, SvgrError> {
+        let mut module = DefaultTemplate.render(variables)?;
+        module.body.truncate(2);
+        Ok(module)
+      }
+    }
+
+    test_code(
+      r#"<svg><g/></svg>"#,
+      &core::config::Config {
+        expand_props: core::config::ExpandProps::None,
+        template: Some(Arc::new(DeclarationOnlyTemplate)),
+        ..Default::default()
+      },
+      &core::state::InternalConfig {
+        ..Default::default()
+      },
+      r#"import * as React from "react";
+const SvgComponent = ()=><svg><g/></svg>;
+"#,
+    );
+  }
+
+  #[test]
+  fn with_solid_target_splits_props() {
+    test_js_n_ts(
+      r#"<svg><g/></svg>"#,
+      &core::config::Config {
+        target: core::config::Target::Solid,
+        title_prop: true,
+        ..Default::default()
+      },
+      &core::state::InternalConfig {
+        ..Default::default()
+      },
+      r#"import { splitProps } from "solid-js";
+const SvgComponent = (props)=>{
+    const [local, others] = splitProps(props, [
+        "title",
+        "titleId"
+    ]);
+    return <svg><g/></svg>;
+};
+export default SvgComponent;
+"#,
+      r#"import { splitProps, type JSX } from "solid-js";
+interface SVGRProps {
+    title?: string;
+    titleId?: string;
+}
+const SvgComponent = (props: JSX.SvgSVGAttributes<SVGSVGElement> & SVGRProps)=>{
+    const [local, others] = splitProps(props, [
+        "title",
+        "titleId"
+    ]);
+    return <svg><g/></svg>;
+};
+export default SvgComponent;
+"#,
+    );
+  }
+
+  #[test]
+  #[should_panic(expected = "invalid template")]
+  fn custom_templates_throws_with_invalid_template() {
+    test_code(
+      r#"<svg><g/></svg>"#,
+      &core::config::Config {
+        template: Some(Arc::new(StringTemplate::new("const = ;".to_string()))),
+        ..Default::default()
+      },
+      &core::state::InternalConfig {
+        ..Default::default()
+      },
+      r#""#,
+    );
+  }
+
+  #[test]
+  #[should_panic(expected = "invalid template: Legacy octal literals")]
+  fn custom_templates_throws_with_recovered_errors() {
+    test_code(
+      r#"<svg><g/></svg>"#,
+      &core::config::Config {
+        template: Some(Arc::new(StringTemplate::new(
+          "%%imports%%\nconst size = 010;\nconst %%componentName%% = (%%props%%) => %%jsx%%;\n%%exports%%"
+            .to_string(),
+        ))),
+        ..Default::default()
+      },
+      &core::state::InternalConfig {
+        ..Default::default()
+      },
+      r#""#,
+    );
+  }
 
   #[test]
   fn jsx_runtime_supports_automatic_jsx_runtime() {
@@ -817,6 +997,97 @@ export default SvgComponent;
     );
   }
 
+  #[test]
+  fn with_vue_target_creates_component_with_define_component() {
+    test_js_n_ts(
+      r#"<svg><g/></svg>"#,
+      &core::config::Config {
+        target: core::config::Target::Vue,
+        title_prop: true,
+        r#ref: true,
+        ..Default::default()
+      },
+      &core::state::InternalConfig {
+        component_name: "SvgComponent".to_string(),
+        ..Default::default()
+      },
+      r#"import { defineComponent, h, ref } from "vue";
+const SvgComponent = defineComponent({
+    name: "SvgComponent",
+    props: {
+        title: String,
+        titleId: String
+    },
+    setup (props, { expose }) {
+        const svg = ref();
+        expose({
+            svg
+        });
+        return ()=>{
+            const { title, titleId } = props;
+            return <svg><g/></svg>;
+        };
+    }
+});
+export default SvgComponent;
+"#,
+      r#"import { defineComponent, h, ref } from "vue";
+const SvgComponent = defineComponent({
+    name: "SvgComponent",
+    props: {
+        title: String,
+        titleId: String
+    },
+    setup (props, { expose }) {
+        const svg = ref<SVGSVGElement>();
+        expose({
+            svg
+        });
+        return ()=>{
+            const { title, titleId } = props;
+            return <svg><g/></svg>;
+        };
+    }
+});
+export default SvgComponent;
+"#,
+    );
+  }
+
+  #[test]
+  fn with_vue_sfc_target_creates_script_setup() {
+    test_js_n_ts(
+      r#"<svg><g/></svg>"#,
+      &core::config::Config {
+        target: core::config::Target::VueSfc,
+        desc_prop: true,
+        expand_props: core::config::ExpandProps::None,
+        ..Default::default()
+      },
+      &core::state::InternalConfig {
+        ..Default::default()
+      },
+      r#"defineOptions({
+    inheritAttrs: false
+});
+defineProps({
+    desc: String,
+    descId: String
+});
+<svg><g/></svg>;
+"#,
+      r#"defineOptions({
+    inheritAttrs: false
+});
+defineProps<{
+    desc?: string;
+    descId?: string;
+}>();
+<svg><g/></svg>;
+"#,
+    );
+  }
+
   #[test]
   #[should_panic(
     expected = r#"called `Result::unwrap()` on an `Err` value: Configuration("Specify \"namespace\", \"defaultSpecifier\", or \"specifiers\" in \"jsxRuntimeImport\" option")"#
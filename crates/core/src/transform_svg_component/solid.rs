@@ -0,0 +1,146 @@
+use swc_core::ecma::ast::*;
+
+use super::{
+  fallback_ids,
+  template::{StringTemplate, Template},
+  variables::{create_exports, TemplateVariables},
+};
+use crate::{core, transform_solid, ExpandProps, FallbackIds, SvgrError};
+
+const SVG_ATTRIBUTES_TYPE: &str = "JSX.SvgSVGAttributes<SVGSVGElement>";
+
+/// The type declaration of the props used by the JSX, for example
+/// `interface SVGRProps { title?: string; titleId?: string; }`.
+fn get_props_interface(names: &[&str]) -> String {
+  let props = names
+    .iter()
+    .map(|name| match *name {
+      "ref" => format!("ref?: {}[\"ref\"];", SVG_ATTRIBUTES_TYPE),
+      name => format!("{}?: string;", name),
+    })
+    .collect::<Vec<String>>();
+  format!("interface SVGRProps {{ {} }}\n", props.join(" "))
+}
+
+fn get_props_type(config: &core::config::Config, names: &[&str]) -> String {
+  match (config.expand_props == ExpandProps::None, names.is_empty()) {
+    (true, _) => "SVGRProps".to_string(),
+    (false, true) => SVG_ATTRIBUTES_TYPE.to_string(),
+    (false, false) => format!("{} & SVGRProps", SVG_ATTRIBUTES_TYPE),
+  }
+}
+
+/// The id generated for the component and the props merged with the fallback ids, a SolidJS
+/// component only runs once so the module counter gives a stable id.
+fn get_merged_props(config: &core::config::Config, state: &core::state::InternalConfig) -> String {
+  let id = match config.fallback_ids {
+    FallbackIds::UseId => "createUniqueId()".to_string(),
+    _ => format!(
+      "`{}${{++{}}}`",
+      fallback_ids::get_counter_prefix(state),
+      fallback_ids::ID_COUNTER
+    ),
+  };
+  let defaults = fallback_ids::get_fallback_props(config)
+    .iter()
+    .map(|(name, suffix)| format!("{}: `${{{}}}-{}`", name, fallback_ids::FALLBACK_ID, suffix))
+    .collect::<Vec<String>>();
+  format!(
+    "const {} = {};\nconst merged = mergeProps({{ {} }}, props);\n",
+    fallback_ids::FALLBACK_ID,
+    id,
+    defaults.join(", ")
+  )
+}
+
+/// The source of the component, the props are split with `splitProps` instead of a rest pattern
+/// so that they stay reactive.
+fn get_source(config: &core::config::Config, state: &core::state::InternalConfig) -> String {
+  let names = transform_solid::get_local_props(config);
+  let has_props = config.expand_props != ExpandProps::None || !names.is_empty();
+  let split = transform_solid::need_split_props(config);
+  let need_jsx_type =
+    config.typescript && (config.expand_props != ExpandProps::None || names.contains(&"ref"));
+
+  let merge = transform_solid::need_merge_props(config);
+  let use_id = merge && config.fallback_ids == FallbackIds::UseId;
+
+  let mut source = String::new();
+  let specifiers = [
+    (use_id, "createUniqueId"),
+    (merge, "mergeProps"),
+    (split, "splitProps"),
+    (need_jsx_type, "type JSX"),
+  ]
+  .into_iter()
+  .filter_map(|(used, specifier)| used.then_some(specifier))
+  .collect::<Vec<&str>>();
+  match specifiers.as_slice() {
+    [] => {}
+    ["type JSX"] => source.push_str("import type { JSX } from \"solid-js\";\n"),
+    specifiers => source.push_str(&format!(
+      "import {{ {} }} from \"solid-js\";\n",
+      specifiers.join(", ")
+    )),
+  }
+  if merge && !use_id {
+    source.push_str(&format!("let {} = 0;\n", fallback_ids::ID_COUNTER));
+  }
+  if config.typescript && !names.is_empty() {
+    source.push_str(&get_props_interface(&names));
+  }
+
+  let params = match (has_props, config.typescript) {
+    (false, _) => "".to_string(),
+    (true, false) => "props".to_string(),
+    (true, true) => format!("props: {}", get_props_type(config, &names)),
+  };
+
+  if split || merge {
+    source.push_str(&format!("const %%componentName%% = ({}) => {{\n", params));
+    if merge {
+      source.push_str(&get_merged_props(config, state));
+    }
+    if split {
+      let names = names
+        .iter()
+        .map(|name| format!("\"{}\"", name))
+        .collect::<Vec<String>>();
+      source.push_str(&format!(
+        "const [local, others] = splitProps({}, [{}]);\n",
+        if merge { "merged" } else { "props" },
+        names.join(", ")
+      ));
+    }
+    source.push_str("return %%jsx%%;\n};\n");
+  } else {
+    source.push_str(&format!(
+      "const %%componentName%% = ({}) => %%jsx%%;\n",
+      params
+    ));
+  }
+  source.push_str("%%exports%%\n");
+  source
+}
+
+pub fn transform(
+  jsx: JSXElement,
+  config: &core::config::Config,
+  state: &core::state::InternalConfig,
+) -> Result<Module, SvgrError> {
+  let exports = create_exports(
+    &state.component_name,
+    Some(config.named_export.clone()),
+    &config.export_type,
+    state,
+  )?;
+
+  StringTemplate::new(get_source(config, state)).render(TemplateVariables {
+    component_name: state.component_name.clone(),
+    interfaces: vec![],
+    props: vec![],
+    imports: vec![],
+    exports,
+    jsx,
+  })
+}
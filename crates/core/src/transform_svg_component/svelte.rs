@@ -0,0 +1,57 @@
+use swc_core::ecma::ast::*;
+
+use super::{
+  template::{StringTemplate, Template},
+  variables::TemplateVariables,
+};
+use crate::{core, SvgrError};
+
+/// The props exported by the component, with their TypeScript type.
+fn get_props(config: &core::config::Config) -> Vec<(&'static str, &'static str)> {
+  let mut props = vec![];
+  if config.title_prop {
+    props.push(("title", "string"));
+    props.push(("titleId", "string"));
+  }
+  if config.desc_prop {
+    props.push(("desc", "string"));
+    props.push(("descId", "string"));
+  }
+  if config.r#ref {
+    props.push(("ref", "SVGSVGElement"));
+  }
+  props
+}
+
+/// The source of the `<script>` block, the props are optional so they are initialized with
+/// `undefined`. It ends with the JSX rendered in the markup.
+fn get_script_source(config: &core::config::Config) -> String {
+  let mut source = String::new();
+  for (name, ts_type) in get_props(config) {
+    if config.typescript {
+      source.push_str(&format!(
+        "export let {}: {} | undefined = undefined;\n",
+        name, ts_type
+      ));
+    } else {
+      source.push_str(&format!("export let {} = undefined;\n", name));
+    }
+  }
+  source.push_str("%%jsx%%;\n");
+  source
+}
+
+pub fn transform(
+  jsx: JSXElement,
+  config: &core::config::Config,
+  state: &core::state::InternalConfig,
+) -> Result<Module, SvgrError> {
+  StringTemplate::new(get_script_source(config)).render(TemplateVariables {
+    component_name: state.component_name.clone(),
+    interfaces: vec![],
+    props: vec![],
+    imports: vec![],
+    exports: vec![],
+    jsx,
+  })
+}
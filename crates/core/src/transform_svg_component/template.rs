@@ -0,0 +1,244 @@
+use std::{fmt, rc::Rc};
+
+use swc_core::common::SyntaxContext;
+use swc_core::{
+  common::{FileName, SourceMap, DUMMY_SP},
+  ecma::{
+    ast::*,
+    parser,
+    visit::{VisitMut, VisitMutWith},
+  },
+};
+
+use super::{variables::TemplateVariables, DropSpan};
+use crate::SvgrError;
+
+/// A template used to create the component module from the template variables.
+///
+/// # Examples
+///
+/// ```rust
+/// use std::sync::Arc;
+///
+/// use svgr_rs::{Config, StringTemplate};
+///
+/// let config = Config {
+///   template: Some(Arc::new(StringTemplate::new(
+///     "%%imports%%
+/// const %%componentName%% = (%%props%%) => %%jsx%%;
+/// %%componentName%%.displayName = \"Icon\";
+/// %%exports%%"
+///       .to_string(),
+///   ))),
+///   ..Default::default()
+/// };
+/// ```
+pub trait Template: Send + Sync {
+  fn render(&self, variables: TemplateVariables) -> Result<Module, SvgrError>;
+}
+
+impl fmt::Debug for dyn Template {
+  fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+    f.write_str("Template")
+  }
+}
+
+/// The template used when no custom template is specified,
+/// it declares the component as an arrow function returning the JSX.
+pub struct DefaultTemplate;
+
+impl Template for DefaultTemplate {
+  fn render(&self, variables: TemplateVariables) -> Result<Module, SvgrError> {
+    let mut body = vec![];
+
+    for import in variables.imports {
+      body.push(import);
+    }
+
+    for interface in variables.interfaces {
+      body.push(interface);
+    }
+
+    body.push(ModuleItem::Stmt(Stmt::Decl(Decl::Var(Box::new(VarDecl {
+      span: DUMMY_SP,
+      ctxt: SyntaxContext::empty(),
+      kind: VarDeclKind::Const,
+      declare: false,
+      decls: vec![VarDeclarator {
+        span: DUMMY_SP,
+        name: Pat::Ident(BindingIdent::from(Ident::new(
+          variables.component_name.into(),
+          DUMMY_SP,
+          SyntaxContext::empty(),
+        ))),
+        definite: false,
+        init: Some(Box::new(Expr::Arrow(ArrowExpr {
+          span: DUMMY_SP,
+          ctxt: SyntaxContext::empty(),
+          params: variables.props,
+          body: Box::new(BlockStmtOrExpr::Expr(Box::new(Expr::JSXElement(Box::new(
+            variables.jsx,
+          ))))),
+          is_async: false,
+          is_generator: false,
+          type_params: None,
+          return_type: None,
+        }))),
+      }],
+    })))));
+
+    for export in variables.exports {
+      body.push(export);
+    }
+
+    Ok(Module {
+      span: DUMMY_SP,
+      body,
+      shebang: None,
+    })
+  }
+}
+
+const PLACEHOLDER_PREFIX: &str = "__SVGR_PLACEHOLDER_";
+
+/// A template written as TypeScript source code.
+///
+/// The following placeholders are replaced with the template variables:
+///
+/// * `%%imports%%`, `%%interfaces%%` and `%%exports%%`: as module statements
+/// * `%%componentName%%`: as an identifier
+/// * `%%props%%`: as function parameters
+/// * `%%jsx%%`: as an expression
+pub struct StringTemplate {
+  source: String,
+}
+
+impl StringTemplate {
+  pub fn new(source: String) -> Self {
+    Self { source }
+  }
+
+  fn parse(&self) -> Result<Module, SvgrError> {
+    let source = [
+      "imports",
+      "interfaces",
+      "exports",
+      "componentName",
+      "props",
+      "jsx",
+    ]
+    .iter()
+    .fold(self.source.clone(), |source, name| {
+      source.replace(
+        &format!("%%{}%%", name),
+        &format!("{}{}", PLACEHOLDER_PREFIX, name),
+      )
+    });
+
+    let cm = Rc::<SourceMap>::default();
+    let fm = cm.new_source_file(FileName::Anon.into(), source);
+
+    let invalid_template = |e: parser::error::Error| {
+      SvgrError::Configuration(format!("invalid template: {}", e.kind().msg()))
+    };
+    let mut recovered_errors = vec![];
+    let module = parser::parse_file_as_module(
+      fm.as_ref(),
+      parser::Syntax::Typescript(parser::TsSyntax {
+        tsx: true,
+        ..Default::default()
+      }),
+      EsVersion::latest(),
+      None,
+      &mut recovered_errors,
+    )
+    .map_err(invalid_template)?;
+    // The parser recovers from some errors, the template is still invalid.
+    match recovered_errors.into_iter().next() {
+      Some(e) => Err(invalid_template(e)),
+      None => Ok(module),
+    }
+  }
+}
+
+impl Template for StringTemplate {
+  fn render(&self, variables: TemplateVariables) -> Result<Module, SvgrError> {
+    let mut module = self.parse()?;
+    module.visit_mut_with(&mut DropSpan);
+    module.visit_mut_with(&mut PlaceholderVisitor { variables });
+    Ok(module)
+  }
+}
+
+struct PlaceholderVisitor {
+  variables: TemplateVariables,
+}
+
+fn get_placeholder(sym: &str) -> Option<&str> {
+  sym.strip_prefix(PLACEHOLDER_PREFIX)
+}
+
+fn get_stmt_placeholder(item: &ModuleItem) -> Option<&str> {
+  if let ModuleItem::Stmt(Stmt::Expr(ExprStmt { expr, .. })) = item {
+    if let Expr::Ident(ident) = expr.as_ref() {
+      return get_placeholder(&ident.sym);
+    }
+  }
+  None
+}
+
+fn is_props_placeholder(pat: &Pat) -> bool {
+  match pat {
+    Pat::Ident(binding) => get_placeholder(&binding.id.sym) == Some("props"),
+    _ => false,
+  }
+}
+
+impl VisitMut for PlaceholderVisitor {
+  fn visit_mut_module_items(&mut self, n: &mut Vec<ModuleItem>) {
+    n.visit_mut_children_with(self);
+
+    let items = std::mem::take(n);
+    for item in items {
+      match get_stmt_placeholder(&item) {
+        Some("imports") => n.extend(self.variables.imports.iter().cloned()),
+        Some("interfaces") => n.extend(self.variables.interfaces.iter().cloned()),
+        Some("exports") => n.extend(self.variables.exports.iter().cloned()),
+        _ => n.push(item),
+      }
+    }
+  }
+
+  fn visit_mut_pats(&mut self, n: &mut Vec<Pat>) {
+    n.visit_mut_children_with(self);
+
+    if let Some(index) = n.iter().position(is_props_placeholder) {
+      n.splice(index..index + 1, self.variables.props.iter().cloned());
+    }
+  }
+
+  fn visit_mut_params(&mut self, n: &mut Vec<Param>) {
+    n.visit_mut_children_with(self);
+
+    if let Some(index) = n.iter().position(|param| is_props_placeholder(&param.pat)) {
+      let params = self.variables.props.iter().cloned().map(Param::from);
+      n.splice(index..index + 1, params);
+    }
+  }
+
+  fn visit_mut_expr(&mut self, n: &mut Expr) {
+    n.visit_mut_children_with(self);
+
+    if let Expr::Ident(ident) = n {
+      if get_placeholder(&ident.sym) == Some("jsx") {
+        *n = Expr::JSXElement(Box::new(self.variables.jsx.clone()));
+      }
+    }
+  }
+
+  fn visit_mut_ident(&mut self, n: &mut Ident) {
+    if get_placeholder(&n.sym) == Some("componentName") {
+      n.sym = self.variables.component_name.clone().into();
+    }
+  }
+}
@@ -4,19 +4,25 @@ use swc_core::common::SyntaxContext;
 use swc_core::{
   common::DUMMY_SP,
   common::{FileName, SourceMap},
-  ecma::{ast::*, parser},
+  ecma::{ast::*, parser, visit::VisitMutWith},
 };
 
-use super::core;
-use crate::{ExpandProps, ExportType, SvgrError};
+use super::{core, fallback_ids, DropSpan};
+use crate::{css_modules, ExpandProps, ExportType, SvgrError};
 
+/// The variables given to a [`Template`](super::Template) to create the component module.
 pub struct TemplateVariables {
-  #[allow(dead_code)]
+  /// The name of the component.
   pub component_name: String,
+  /// The TypeScript interfaces declaring the component props.
   pub interfaces: Vec<ModuleItem>,
+  /// The parameters of the component function.
   pub props: Vec<Pat>,
+  /// The import declarations needed by the component.
   pub imports: Vec<ModuleItem>,
+  /// The statements exporting the component.
   pub exports: Vec<ModuleItem>,
+  /// The JSX element of the SVG.
   pub jsx: JSXElement,
 }
 
@@ -34,14 +40,19 @@ pub struct Options {
   pub desc_prop: bool,
   pub expand_props: ExpandProps,
   pub r#ref: bool,
-  // pub template: Option<Box<dyn Template>>,
-  pub native: bool,
+  /// The source of the react-native-svg components, when they are used.
+  pub native: Option<String>,
+  /// The source of the CSS module the class names are read from, when there is one.
+  pub css_module: Option<String>,
   pub memo: bool,
   pub export_type: ExportType,
   pub named_export: Option<String>,
   pub jsx_runtime: JSXRuntime,
   pub jsx_runtime_import: Option<core::config::JSXRuntimeImport>,
   pub import_source: Option<String>,
+  /// Destructure `titleId` and `descId` under another name, their fallback is declared in the
+  /// component body.
+  pub fallback_ids: bool,
 }
 
 pub fn get_variables(
@@ -77,7 +88,7 @@ pub fn get_variables(
     }
   }
 
-  if opts.native {
+  if let Some(native) = &opts.native {
     let specifier = ImportSpecifier::Default(ImportDefaultSpecifier {
       span: DUMMY_SP,
       local: Ident {
@@ -87,7 +98,20 @@ pub fn get_variables(
         optional: false,
       },
     });
-    get_or_create_import(&mut imports, "react-native-svg", specifier);
+    get_or_create_import(&mut imports, native, specifier);
+  }
+
+  if let Some(css_module) = &opts.css_module {
+    let specifier = ImportSpecifier::Default(ImportDefaultSpecifier {
+      span: DUMMY_SP,
+      local: Ident {
+        span: DUMMY_SP,
+        ctxt: SyntaxContext::empty(),
+        sym: css_modules::STYLES.into(),
+        optional: false,
+      },
+    });
+    get_or_create_import(&mut imports, css_module, specifier);
   }
 
   if opts.title_prop || opts.desc_prop {
@@ -96,7 +120,7 @@ pub fn get_variables(
 
     if opts.title_prop {
       properties.push(create_property("title"));
-      properties.push(create_property("titleId"));
+      properties.push(create_id_property("titleId", opts.fallback_ids));
 
       if opts.typescript {
         property_signatures.push(create_signature("title"));
@@ -106,7 +130,7 @@ pub fn get_variables(
 
     if opts.desc_prop {
       properties.push(create_property("desc"));
-      properties.push(create_property("descId"));
+      properties.push(create_id_property("descId", opts.fallback_ids));
 
       if opts.typescript {
         property_signatures.push(create_signature("desc"));
@@ -170,7 +194,7 @@ pub fn get_variables(
 
         if opts.typescript {
           let svg_props_type =
-            ts_type_reference_svg_props(&mut imports, opts.native, &import_source);
+            ts_type_reference_svg_props(&mut imports, opts.native.as_deref(), &import_source);
           let type_ann = Box::new(TsType::TsUnionOrIntersectionType(
             TsUnionOrIntersectionType::TsIntersectionType(TsIntersectionType {
               span: DUMMY_SP,
@@ -207,7 +231,8 @@ pub fn get_variables(
         BindingIdent::from(Ident::new("props".into(), DUMMY_SP, SyntaxContext::empty()));
 
       if opts.typescript {
-        let type_ann = ts_type_reference_svg_props(&mut imports, opts.native, &import_source);
+        let type_ann =
+          ts_type_reference_svg_props(&mut imports, opts.native.as_deref(), &import_source);
         prop.type_ann = Some(Box::new(TsTypeAnn {
           span: DUMMY_SP,
           type_ann,
@@ -271,17 +296,44 @@ pub fn get_variables(
     export_identifier = "Memo".to_string();
   }
 
+  exports.extend(create_exports(
+    &export_identifier,
+    opts.named_export,
+    &opts.export_type,
+    state,
+  )?);
+
+  Ok(TemplateVariables {
+    component_name: state.component_name.clone(),
+    interfaces,
+    props,
+    imports,
+    exports,
+    jsx,
+  })
+}
+
+/// The statements exporting the component, under the named export when it is requested or when
+/// the transformation has a caller, by default otherwise.
+pub(super) fn create_exports(
+  export_identifier: &str,
+  named_export: Option<String>,
+  export_type: &ExportType,
+  state: &core::state::InternalConfig,
+) -> Result<Vec<ModuleItem>, SvgrError> {
+  let mut exports = vec![];
+
   let need_named_export = if state.caller.is_some() {
     true
   } else {
-    matches!(opts.export_type, ExportType::Named)
+    matches!(*export_type, ExportType::Named)
   };
   if need_named_export {
-    if let Some(named_export) = opts.named_export {
+    if let Some(named_export) = named_export {
       let specifier = ExportSpecifier::Named(ExportNamedSpecifier {
         span: DUMMY_SP,
         orig: ModuleExportName::Ident(Ident::new(
-          export_identifier.clone().into(),
+          export_identifier.into(),
           DUMMY_SP,
           SyntaxContext::empty(),
         )),
@@ -310,7 +362,7 @@ pub fn get_variables(
           let fm = cm.new_source_file(FileName::Anon.into(), previous_export);
 
           let mut recovered_errors = vec![];
-          let module = parser::parse_file_as_module(
+          let mut module = parser::parse_file_as_module(
             fm.as_ref(),
             parser::Syntax::Es(parser::EsSyntax {
               jsx: true,
@@ -320,7 +372,13 @@ pub fn get_variables(
             None,
             &mut recovered_errors,
           )
-          .unwrap();
+          .map_err(|e| {
+            SvgrError::Configuration(format!(
+              r#"invalid "caller.previousExport": {}"#,
+              e.kind().msg()
+            ))
+          })?;
+          module.visit_mut_with(&mut DropSpan);
           for module_item in module.body {
             exports.push(module_item)
           }
@@ -346,14 +404,7 @@ pub fn get_variables(
     )));
   }
 
-  Ok(TemplateVariables {
-    component_name: state.component_name.clone(),
-    interfaces,
-    props,
-    imports,
-    exports,
-    jsx,
-  })
+  Ok(exports)
 }
 
 fn get_jsx_runtime_import(cfg: &core::config::JSXRuntimeImport) -> Result<ModuleItem, SvgrError> {
@@ -465,7 +516,7 @@ fn get_or_create_import(
   imports.push(module_item);
 }
 
-fn get_or_create_named_import(imports: &mut Vec<ModuleItem>, soruce_value: &str, name: &str) {
+pub fn get_or_create_named_import(imports: &mut Vec<ModuleItem>, soruce_value: &str, name: &str) {
   let specifier = ImportSpecifier::Named(ImportNamedSpecifier {
     span: DUMMY_SP,
     local: Ident {
@@ -524,6 +575,21 @@ fn create_property(key: &str) -> ObjectPatProp {
   })
 }
 
+/// `titleId`, or `titleId: titleIdProp` when a fallback is declared under its name.
+fn create_id_property(key: &str, fallback_ids: bool) -> ObjectPatProp {
+  if !fallback_ids {
+    return create_property(key);
+  }
+  ObjectPatProp::KeyValue(KeyValuePatProp {
+    key: PropName::Ident(IdentName::new(key.into(), DUMMY_SP)),
+    value: Box::new(Pat::Ident(BindingIdent::from(Ident::new(
+      fallback_ids::get_prop_alias(key).into(),
+      DUMMY_SP,
+      SyntaxContext::empty(),
+    )))),
+  })
+}
+
 fn create_signature(key: &str) -> TsTypeElement {
   TsTypeElement::TsPropertySignature(TsPropertySignature {
     span: DUMMY_SP,
@@ -547,11 +613,11 @@ fn create_signature(key: &str) -> TsTypeElement {
 
 fn ts_type_reference_svg_props(
   imports: &mut Vec<ModuleItem>,
-  native: bool,
+  native: Option<&str>,
   import_source: &str,
 ) -> Box<TsType> {
-  if native {
-    get_or_create_named_import(imports, "react-native-svg", "SvgProps");
+  if let Some(native) = native {
+    get_or_create_named_import(imports, native, "SvgProps");
 
     return Box::new(TsType::TsTypeRef(TsTypeRef {
       span: DUMMY_SP,
@@ -0,0 +1,167 @@
+use swc_core::ecma::ast::*;
+
+use super::{
+  template::{StringTemplate, Template},
+  variables::{create_exports, TemplateVariables},
+};
+use crate::{core, ExpandProps, SvgrError, Target};
+
+/// The names of the props declared by the component.
+fn get_prop_names(config: &core::config::Config) -> Vec<&'static str> {
+  let mut names = vec![];
+  if config.title_prop {
+    names.push("title");
+    names.push("titleId");
+  }
+  if config.desc_prop {
+    names.push("desc");
+    names.push("descId");
+  }
+  names
+}
+
+/// The runtime declaration of the props, for example `{ title: String, titleId: String }`.
+fn get_props_object(names: &[&str]) -> String {
+  let props = names
+    .iter()
+    .map(|name| format!("{}: String", name))
+    .collect::<Vec<String>>();
+  format!("{{ {} }}", props.join(", "))
+}
+
+/// The type declaration of the props, for example `{ title?: string; titleId?: string }`.
+fn get_props_type(names: &[&str]) -> String {
+  let props = names
+    .iter()
+    .map(|name| format!("{}?: string", name))
+    .collect::<Vec<String>>();
+  format!("{{ {} }}", props.join("; "))
+}
+
+fn get_vue_import(config: &core::config::Config) -> Option<String> {
+  let mut specifiers = vec![];
+  if config.target == Target::Vue {
+    specifiers.push("defineComponent");
+    specifiers.push("h");
+  }
+  if config.r#ref {
+    specifiers.push("ref");
+  }
+
+  if specifiers.is_empty() {
+    return None;
+  }
+  Some(format!(
+    "import {{ {} }} from \"vue\";\n",
+    specifiers.join(", ")
+  ))
+}
+
+fn get_svg_ref(config: &core::config::Config) -> &'static str {
+  if config.typescript {
+    "const svg = ref<SVGSVGElement>();\n"
+  } else {
+    "const svg = ref();\n"
+  }
+}
+
+/// The source of a component created with `defineComponent`, the JSX is rendered with `h()`
+/// once the plugins are applied.
+fn get_render_function_source(config: &core::config::Config, component_name: &str) -> String {
+  let prop_names = get_prop_names(config);
+
+  let mut options = format!("  name: \"{}\",\n", component_name);
+  if config.expand_props == ExpandProps::None {
+    options.push_str("  inheritAttrs: false,\n");
+  }
+  if !prop_names.is_empty() {
+    options.push_str(&format!("  props: {},\n", get_props_object(&prop_names)));
+  }
+
+  let params = match (prop_names.is_empty(), config.r#ref) {
+    (true, false) => "",
+    (false, false) => "props",
+    (true, true) => "_, { expose }",
+    (false, true) => "props, { expose }",
+  };
+
+  let mut setup = String::new();
+  if config.r#ref {
+    setup.push_str(get_svg_ref(config));
+    setup.push_str("expose({ svg });\n");
+  }
+  if prop_names.is_empty() {
+    setup.push_str("return () => %%jsx%%;\n");
+  } else {
+    setup.push_str(&format!(
+      "return () => {{\nconst {{ {} }} = props;\nreturn %%jsx%%;\n}};\n",
+      prop_names.join(", ")
+    ));
+  }
+
+  format!(
+    "{}const %%componentName%% = defineComponent({{\n{}  setup({}) {{\n{}  }},\n}});\n%%exports%%\n",
+    get_vue_import(config).unwrap_or_default(),
+    options,
+    params,
+    setup
+  )
+}
+
+/// The source of the `<script setup>` block of a single-file component,
+/// it ends with the JSX rendered in the `<template>` block.
+fn get_script_setup_source(config: &core::config::Config) -> String {
+  let prop_names = get_prop_names(config);
+
+  let mut source = get_vue_import(config).unwrap_or_default();
+  if config.expand_props == ExpandProps::None {
+    source.push_str("defineOptions({ inheritAttrs: false });\n");
+  }
+  if !prop_names.is_empty() {
+    if config.typescript {
+      source.push_str(&format!(
+        "defineProps<{}>();\n",
+        get_props_type(&prop_names)
+      ));
+    } else {
+      source.push_str(&format!(
+        "defineProps({});\n",
+        get_props_object(&prop_names)
+      ));
+    }
+  }
+  if config.r#ref {
+    source.push_str(get_svg_ref(config));
+    source.push_str("defineExpose({ svg });\n");
+  }
+  source.push_str("%%jsx%%;\n");
+  source
+}
+
+pub fn transform(
+  jsx: JSXElement,
+  config: &core::config::Config,
+  state: &core::state::InternalConfig,
+) -> Result<Module, SvgrError> {
+  let (source, exports) = match config.target {
+    Target::VueSfc => (get_script_setup_source(config), vec![]),
+    _ => (
+      get_render_function_source(config, &state.component_name),
+      create_exports(
+        &state.component_name,
+        Some(config.named_export.clone()),
+        &config.export_type,
+        state,
+      )?,
+    ),
+  };
+
+  StringTemplate::new(source).render(TemplateVariables {
+    component_name: state.component_name.clone(),
+    interfaces: vec![],
+    props: vec![],
+    imports: vec![],
+    exports,
+    jsx,
+  })
+}
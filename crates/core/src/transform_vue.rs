@@ -0,0 +1,171 @@
+use swc_core::{
+  common::{util::take::Take, SyntaxContext, DUMMY_SP},
+  ecma::{
+    ast::*,
+    visit::{VisitMut, VisitMutWith},
+  },
+};
+
+fn is_ident_name(name: &str) -> bool {
+  let mut chars = name.chars();
+  chars
+    .next()
+    .map_or(false, |c| c.is_ascii_alphabetic() || c == '_' || c == '$')
+    && chars.all(|c| c.is_ascii_alphanumeric() || c == '_' || c == '$')
+}
+
+fn get_prop_name(name: &JSXAttrName) -> PropName {
+  let name = match name {
+    JSXAttrName::Ident(ident) => ident.sym.to_string(),
+    JSXAttrName::JSXNamespacedName(name) => format!("{}:{}", name.ns.sym, name.name.sym),
+  };
+  if is_ident_name(&name) {
+    PropName::Ident(IdentName::new(name.into(), DUMMY_SP))
+  } else {
+    PropName::Str(name.into())
+  }
+}
+
+fn get_tag_name(name: &JSXElementName) -> String {
+  match name {
+    JSXElementName::Ident(ident) => ident.sym.to_string(),
+    JSXElementName::JSXNamespacedName(name) => format!("{}:{}", name.ns.sym, name.name.sym),
+    JSXElementName::JSXMemberExpr(member) => member.prop.sym.to_string(),
+  }
+}
+
+fn get_props(attrs: Vec<JSXAttrOrSpread>) -> Option<Expr> {
+  if attrs.is_empty() {
+    return None;
+  }
+
+  let props = attrs
+    .into_iter()
+    .map(|attr| match attr {
+      JSXAttrOrSpread::JSXAttr(attr) => {
+        let value = match attr.value {
+          None => Expr::Lit(Lit::Str("".into())),
+          Some(JSXAttrValue::Lit(lit)) => Expr::Lit(lit),
+          Some(JSXAttrValue::JSXExprContainer(JSXExprContainer {
+            expr: JSXExpr::Expr(expr),
+            ..
+          })) => *expr,
+          Some(JSXAttrValue::JSXExprContainer(_)) => Expr::Lit(Lit::Str("".into())),
+          Some(JSXAttrValue::JSXElement(element)) => create_h(*element),
+          Some(JSXAttrValue::JSXFragment(fragment)) => create_children(fragment.children),
+        };
+        PropOrSpread::Prop(Box::new(Prop::KeyValue(KeyValueProp {
+          key: get_prop_name(&attr.name),
+          value: Box::new(value),
+        })))
+      }
+      JSXAttrOrSpread::SpreadElement(spread) => PropOrSpread::Spread(spread),
+    })
+    .collect();
+
+  Some(Expr::Object(ObjectLit {
+    span: DUMMY_SP,
+    props,
+  }))
+}
+
+fn push_children(children: Vec<JSXElementChild>, elems: &mut Vec<Option<ExprOrSpread>>) {
+  for child in children {
+    let expr = match child {
+      JSXElementChild::JSXText(text) => {
+        let text = text
+          .value
+          .split_whitespace()
+          .collect::<Vec<&str>>()
+          .join(" ");
+        if text.is_empty() {
+          continue;
+        }
+        Expr::Lit(Lit::Str(text.into()))
+      }
+      JSXElementChild::JSXExprContainer(JSXExprContainer {
+        expr: JSXExpr::Expr(expr),
+        ..
+      }) => *expr,
+      JSXElementChild::JSXExprContainer(_) => continue,
+      JSXElementChild::JSXSpreadChild(spread) => {
+        elems.push(Some(ExprOrSpread {
+          spread: Some(DUMMY_SP),
+          expr: spread.expr,
+        }));
+        continue;
+      }
+      JSXElementChild::JSXElement(element) => create_h(*element),
+      JSXElementChild::JSXFragment(fragment) => {
+        push_children(fragment.children, elems);
+        continue;
+      }
+    };
+    elems.push(Some(ExprOrSpread {
+      spread: None,
+      expr: Box::new(expr),
+    }));
+  }
+}
+
+fn create_children(children: Vec<JSXElementChild>) -> Expr {
+  let mut elems = vec![];
+  push_children(children, &mut elems);
+  Expr::Array(ArrayLit {
+    span: DUMMY_SP,
+    elems,
+  })
+}
+
+/// Create the `h()` call rendering a JSX element, its children are already converted.
+fn create_h(element: JSXElement) -> Expr {
+  let mut args = vec![Expr::Lit(Lit::Str(
+    get_tag_name(&element.opening.name).into(),
+  ))];
+
+  let props = get_props(element.opening.attrs);
+  let has_children = !element.children.is_empty();
+  match props {
+    Some(props) => args.push(props),
+    None if has_children => args.push(Expr::Lit(Lit::Null(Null { span: DUMMY_SP }))),
+    None => {}
+  }
+  if has_children {
+    args.push(create_children(element.children));
+  }
+
+  Expr::Call(CallExpr {
+    span: element.span,
+    ctxt: SyntaxContext::empty(),
+    callee: Callee::Expr(Box::new(Expr::Ident(Ident::new(
+      "h".into(),
+      DUMMY_SP,
+      SyntaxContext::empty(),
+    )))),
+    args: args
+      .into_iter()
+      .map(|expr| ExprOrSpread {
+        spread: None,
+        expr: Box::new(expr),
+      })
+      .collect(),
+    type_args: None,
+  })
+}
+
+/// Render the JSX of the module with the `h()` function of Vue.
+pub struct Visitor;
+
+impl VisitMut for Visitor {
+  fn visit_mut_expr(&mut self, n: &mut Expr) {
+    n.visit_mut_children_with(self);
+
+    if matches!(n, Expr::JSXElement(_) | Expr::JSXFragment(_)) {
+      *n = match n.take() {
+        Expr::JSXElement(element) => create_h(*element),
+        Expr::JSXFragment(fragment) => create_children(fragment.children),
+        expr => expr,
+      };
+    }
+  }
+}
@@ -0,0 +1,710 @@
+use std::{collections::HashSet, ops::Range, path::Path};
+
+use lazy_static::lazy_static;
+use regex::Regex;
+use swc_core::{
+  common::{SyntaxContext, DUMMY_SP},
+  ecma::{
+    ast::*,
+    visit::{Visit, VisitMut, VisitMutWith, VisitWith},
+  },
+};
+use swc_xml::{
+  ast as xml,
+  visit::{self as xml_visit, VisitMutWith as _, VisitWith as _},
+};
+
+use crate::{
+  core::{
+    self,
+    config::{JSXRuntime, UniqueIds},
+  },
+  transform_svg_component::get_or_create_named_import,
+  util::{create_ident, create_tpl_element},
+  Config, SvgrError, Target,
+};
+
+/// The variable holding the id generated at runtime, in `use-id` mode.
+const UNIQUE_ID: &str = "uniqueId";
+
+/// A part of an attribute value, the ids are the ones to prefix.
+#[derive(Debug, PartialEq)]
+enum Part {
+  Text(String),
+  Id(String),
+}
+
+/// A deterministic hash of the file path, the prefix of two files with the same name differs.
+fn hash(value: &str) -> u32 {
+  value
+    .replace('\\', "/")
+    .bytes()
+    .fold(0x811c9dc5, |hash, byte| {
+      (hash ^ byte as u32).wrapping_mul(0x01000193)
+    })
+}
+
+/// The prefix of the ids of a file, its name followed by a hash of its path, like `star-4e2a1f`.
+/// The kebab-case component name is used when the file path is unknown.
+pub fn get_prefix(file_path: Option<&str>, component_name: &str) -> String {
+  let file_prefix = file_path.and_then(|file_path| {
+    Path::new(file_path)
+      .file_prefix()
+      .and_then(|file_prefix| file_prefix.to_str())
+  });
+  match (file_path, file_prefix) {
+    (Some(file_path), Some(file_prefix)) if !file_prefix.is_empty() => {
+      let name = file_prefix
+        .chars()
+        .map(
+          |c| match c.is_ascii_alphanumeric() || c == '_' || c == '-' {
+            true => c,
+            false => '-',
+          },
+        )
+        .collect::<String>();
+      format!("{}-{:06x}", name, hash(file_path) & 0xffffff)
+    }
+    _ => core::state::kebab_case(component_name),
+  }
+}
+
+/// Split a value on the ranges of the ids it references, sorted by their start.
+fn split_ranges(value: &str, ranges: Vec<Range<usize>>) -> Vec<Part> {
+  if ranges.is_empty() {
+    return vec![];
+  }
+
+  let mut parts = vec![];
+  let mut last = 0;
+  for range in ranges {
+    parts.push(Part::Text(value[last..range.start].to_string()));
+    parts.push(Part::Id(value[range.clone()].to_string()));
+    last = range.end;
+  }
+  parts.push(Part::Text(value[last..].to_string()));
+  parts
+}
+
+/// The ranges of the ids referenced by `url(#id)`.
+fn url_reference_ranges(value: &str, ids: &HashSet<String>) -> Vec<Range<usize>> {
+  lazy_static! {
+    static ref URL_REGEX: Regex = Regex::new(r#"url\(\s*['"]?#([^'")\s]+)"#).unwrap();
+  }
+
+  URL_REGEX
+    .captures_iter(value)
+    .map(|caps| caps.get(1).unwrap())
+    .filter(|id| ids.contains(id.as_str()))
+    .map(|id| id.range())
+    .collect()
+}
+
+/// The ranges of the ids referenced by the `#id` selectors of a stylesheet, the preludes of its
+/// rules are the text before a `{`. The at-rules preludes are skipped.
+fn id_selector_ranges(css: &str, ids: &HashSet<String>) -> Vec<Range<usize>> {
+  lazy_static! {
+    static ref ID_SELECTOR_REGEX: Regex = Regex::new(r#"#([_a-zA-Z0-9-]+)"#).unwrap();
+  }
+
+  let mut ranges = vec![];
+  let mut start = 0;
+  for (index, c) in css.char_indices() {
+    match c {
+      '{' => {
+        let prelude = &css[start..index];
+        if !prelude.trim_start().starts_with('@') {
+          ranges.extend(
+            ID_SELECTOR_REGEX
+              .captures_iter(prelude)
+              .map(|caps| caps.get(1).unwrap())
+              .filter(|id| ids.contains(id.as_str()))
+              .map(|id| start + id.start()..start + id.end()),
+          );
+        }
+        start = index + 1;
+      }
+      '}' | ';' => start = index + 1,
+      _ => {}
+    }
+  }
+  ranges
+}
+
+/// Split the CSS of a `<style>` element on its `#id` selectors and `url(#id)` references.
+fn split_css_references(css: &str, ids: &HashSet<String>) -> Vec<Part> {
+  let mut ranges = id_selector_ranges(css, ids);
+  ranges.extend(url_reference_ranges(css, ids));
+  ranges.sort_by_key(|range| range.start);
+  split_ranges(css, ranges)
+}
+
+/// Split the value of an attribute on the references to the ids of the SVG, it is empty when the
+/// value references none of them.
+fn split_references(name: &str, value: &str, ids: &HashSet<String>) -> Vec<Part> {
+  match name {
+    "id" if ids.contains(value) => vec![Part::Id(value.to_string())],
+    "href" | "xlinkHref" | "xlink:href" => match value.strip_prefix('#') {
+      Some(id) if ids.contains(id) => vec![Part::Text("#".to_string()), Part::Id(id.to_string())],
+      _ => vec![],
+    },
+    "aria-labelledby" | "aria-describedby" => {
+      let references = value.split_whitespace().collect::<Vec<&str>>();
+      if !references.iter().any(|id| ids.contains(*id)) {
+        return vec![];
+      }
+      let mut parts = vec![];
+      for (index, id) in references.into_iter().enumerate() {
+        if index > 0 {
+          parts.push(Part::Text(" ".to_string()));
+        }
+        match ids.contains(id) {
+          true => parts.push(Part::Id(id.to_string())),
+          false => parts.push(Part::Text(id.to_string())),
+        }
+      }
+      parts
+    }
+    _ => split_ranges(value, url_reference_ranges(value, ids)),
+  }
+}
+
+/// Join the parts of a value, with the ids prefixed with a static prefix.
+fn join_parts(parts: Vec<Part>, prefix: &str) -> String {
+  parts
+    .into_iter()
+    .map(|part| match part {
+      Part::Text(text) => text,
+      Part::Id(id) => format!("{}-{}", prefix, id),
+    })
+    .collect()
+}
+
+/// The ids of the SVG, collected from the `id` attributes with a string value.
+#[derive(Default)]
+struct IdCollector {
+  ids: HashSet<String>,
+}
+
+impl Visit for IdCollector {
+  fn visit_jsx_attr(&mut self, n: &JSXAttr) {
+    if let JSXAttrName::Ident(name) = &n.name {
+      if name.sym == "id" {
+        if let Some(value) = get_str_value(n.value.as_ref()).or_else(|| get_fallback(n)) {
+          self.ids.insert(value.to_string());
+        }
+      }
+    }
+  }
+}
+
+fn get_str_value(value: Option<&JSXAttrValue>) -> Option<&str> {
+  match value? {
+    JSXAttrValue::Lit(Lit::Str(str)) => Some(&str.value),
+    JSXAttrValue::JSXExprContainer(JSXExprContainer {
+      expr: JSXExpr::Expr(expr),
+      ..
+    }) => match expr.as_ref() {
+      Expr::Lit(Lit::Str(str)) => Some(&str.value),
+      _ => None,
+    },
+    _ => None,
+  }
+}
+
+/// The fallback of a dynamic title, like `id={titleId || "title"}`.
+fn get_fallback(attr: &JSXAttr) -> Option<&str> {
+  match attr.value.as_ref()? {
+    JSXAttrValue::JSXExprContainer(JSXExprContainer {
+      expr: JSXExpr::Expr(expr),
+      ..
+    }) => match expr.as_ref() {
+      Expr::Bin(BinExpr {
+        op: BinaryOp::LogicalOr,
+        right,
+        ..
+      }) => match right.as_ref() {
+        Expr::Lit(Lit::Str(str)) => Some(&str.value),
+        _ => None,
+      },
+      _ => None,
+    },
+    _ => None,
+  }
+}
+
+fn get_attr_name(name: &JSXAttrName) -> String {
+  match name {
+    JSXAttrName::Ident(ident) => ident.sym.to_string(),
+    JSXAttrName::JSXNamespacedName(name) => format!("{}:{}", name.ns.sym, name.name.sym),
+  }
+}
+
+/// The CSS of a child of a `<style>` element, when it is a string.
+fn get_style_text(child: &JSXElementChild) -> Option<String> {
+  match child {
+    JSXElementChild::JSXText(text) => Some(text.value.to_string()),
+    JSXElementChild::JSXExprContainer(JSXExprContainer {
+      expr: JSXExpr::Expr(expr),
+      ..
+    }) => match expr.as_ref() {
+      Expr::Lit(Lit::Str(str)) => Some(str.value.to_string()),
+      _ => None,
+    },
+    _ => None,
+  }
+}
+
+/// The ids of the SVG selected by the `#id` selectors of its `<style>` elements.
+struct SelectedIdCollector<'a> {
+  ids: &'a HashSet<String>,
+  selected: HashSet<String>,
+}
+
+impl Visit for SelectedIdCollector<'_> {
+  fn visit_jsx_element(&mut self, n: &JSXElement) {
+    if matches!(&n.opening.name, JSXElementName::Ident(ident) if ident.sym == "style") {
+      for css in n.children.iter().filter_map(get_style_text) {
+        self.selected.extend(
+          id_selector_ranges(&css, self.ids)
+            .into_iter()
+            .map(|range| css[range].to_string()),
+        );
+      }
+    }
+    n.visit_children_with(self);
+  }
+}
+
+/// Prefix the ids of the SVG and the references to them.
+struct Visitor {
+  ids: HashSet<String>,
+  /// The static prefix derived from the file path.
+  prefix: String,
+  /// The ids prefixed with the id generated at runtime, the other ones get the static prefix.
+  runtime_ids: HashSet<String>,
+}
+
+impl Visitor {
+  /// The value with the ids prefixed, a string or a template literal reading the generated id.
+  fn create_value(&self, parts: Vec<Part>) -> Expr {
+    let is_runtime = |part: &Part| matches!(part, Part::Id(id) if self.runtime_ids.contains(id));
+    if !parts.iter().any(is_runtime) {
+      return Expr::Lit(Lit::Str(join_parts(parts, &self.prefix).into()));
+    }
+
+    let mut quasis = vec![];
+    let mut exprs = vec![];
+    let mut text = String::new();
+    for part in parts {
+      match part {
+        Part::Id(id) if self.runtime_ids.contains(&id) => {
+          quasis.push(create_tpl_element(&text, false));
+          exprs.push(Box::new(Expr::Ident(create_ident(UNIQUE_ID))));
+          text = format!("-{}", id);
+        }
+        Part::Id(id) => text.push_str(&format!("{}-{}", self.prefix, id)),
+        Part::Text(value) => text.push_str(&value),
+      }
+    }
+    quasis.push(create_tpl_element(&text, true));
+    Expr::Tpl(Tpl {
+      span: DUMMY_SP,
+      exprs,
+      quasis,
+    })
+  }
+
+  fn rewrite(&self, name: &str, value: &str) -> Option<Expr> {
+    let parts = split_references(name, value, &self.ids);
+    (!parts.is_empty()).then(|| self.create_value(parts))
+  }
+
+  fn rewrite_style_object(&self, object: &mut ObjectLit) {
+    for prop in object.props.iter_mut() {
+      if let PropOrSpread::Prop(prop) = prop {
+        if let Prop::KeyValue(KeyValueProp { value, .. }) = prop.as_mut() {
+          if let Expr::Lit(Lit::Str(str)) = value.as_ref() {
+            if let Some(expr) = self.rewrite("style", &str.value) {
+              *value = Box::new(expr);
+            }
+          }
+        }
+      }
+    }
+  }
+
+  /// The CSS of a `<style>` element, its `#id` selectors and `url(#id)` references are prefixed.
+  fn rewrite_style_element(&self, children: &mut [JSXElementChild]) {
+    for child in children.iter_mut() {
+      let Some(value) = get_style_text(child) else {
+        continue;
+      };
+      let parts = split_css_references(&value, &self.ids);
+      if !parts.is_empty() {
+        let expr = self.create_value(parts);
+        *child = JSXElementChild::JSXExprContainer(JSXExprContainer {
+          span: DUMMY_SP,
+          expr: JSXExpr::Expr(Box::new(expr)),
+        });
+      }
+    }
+  }
+}
+
+impl VisitMut for Visitor {
+  fn visit_mut_jsx_attr(&mut self, n: &mut JSXAttr) {
+    let name = get_attr_name(&n.name);
+    if let Some(value) = get_str_value(n.value.as_ref()) {
+      if let Some(expr) = self.rewrite(&name, value) {
+        n.value = Some(match expr {
+          Expr::Lit(Lit::Str(str)) => JSXAttrValue::Lit(Lit::Str(str)),
+          expr => JSXAttrValue::JSXExprContainer(JSXExprContainer {
+            span: DUMMY_SP,
+            expr: JSXExpr::Expr(Box::new(expr)),
+          }),
+        });
+      }
+      return;
+    }
+
+    let Some(JSXAttrValue::JSXExprContainer(JSXExprContainer {
+      expr: JSXExpr::Expr(expr),
+      ..
+    })) = &mut n.value
+    else {
+      return;
+    };
+    match expr.as_mut() {
+      Expr::Object(object) if name == "style" => self.rewrite_style_object(object),
+      Expr::Bin(BinExpr {
+        op: BinaryOp::LogicalOr,
+        right,
+        ..
+      }) => {
+        if let Expr::Lit(Lit::Str(str)) = right.as_ref() {
+          if let Some(expr) = self.rewrite(&name, &str.value) {
+            *right = Box::new(expr);
+          }
+        }
+      }
+      _ => {}
+    }
+  }
+
+  fn visit_mut_jsx_element(&mut self, n: &mut JSXElement) {
+    if let JSXElementName::Ident(ident) = &n.opening.name {
+      if ident.sym == "style" {
+        self.rewrite_style_element(&mut n.children);
+      }
+    }
+    n.visit_mut_children_with(self);
+  }
+}
+
+/// Whether the node contains JSX.
+#[derive(Default)]
+struct JsxFinder {
+  found: bool,
+}
+
+impl Visit for JsxFinder {
+  fn visit_jsx_element(&mut self, _: &JSXElement) {
+    self.found = true;
+  }
+}
+
+fn contains_jsx<N: VisitWith<JsxFinder>>(n: &N) -> bool {
+  let mut finder = JsxFinder::default();
+  n.visit_with(&mut finder);
+  finder.found
+}
+
+/// Insert statements at the start of the component, the innermost function rendering the JSX.
+/// An arrow function returning the JSX directly is given a block body.
+struct ComponentStatements {
+  stmts: Option<Vec<Stmt>>,
+}
+
+impl VisitMut for ComponentStatements {
+  fn visit_mut_arrow_expr(&mut self, n: &mut ArrowExpr) {
+    n.visit_mut_children_with(self);
+    if self.stmts.is_none() || !contains_jsx(&*n.body) {
+      return;
+    }
+    let mut stmts = self.stmts.take().unwrap();
+    match n.body.as_mut() {
+      BlockStmtOrExpr::BlockStmt(block) => {
+        block.stmts.splice(0..0, stmts);
+      }
+      BlockStmtOrExpr::Expr(expr) => {
+        stmts.push(Stmt::Return(ReturnStmt {
+          span: DUMMY_SP,
+          arg: Some(expr.clone()),
+        }));
+        *n.body = BlockStmtOrExpr::BlockStmt(BlockStmt {
+          span: DUMMY_SP,
+          ctxt: SyntaxContext::empty(),
+          stmts,
+        });
+      }
+    }
+  }
+
+  fn visit_mut_function(&mut self, n: &mut Function) {
+    n.visit_mut_children_with(self);
+    if self.stmts.is_none() {
+      return;
+    }
+    if let Some(body) = &mut n.body {
+      if contains_jsx(&*body) {
+        body.stmts.splice(0..0, self.stmts.take().unwrap());
+      }
+    }
+  }
+}
+
+/// Insert a statement at the start of the component, for example to call a hook.
+pub fn add_component_statements(m: &mut Module, stmts: Vec<Stmt>) -> Result<(), SvgrError> {
+  let mut visitor = ComponentStatements { stmts: Some(stmts) };
+  m.visit_mut_with(&mut visitor);
+  match visitor.stmts {
+    None => Ok(()),
+    Some(_) => Err(SvgrError::Configuration(
+      "the JSX must be rendered in a function component to generate ids at runtime".to_string(),
+    )),
+  }
+}
+
+/// Import a named export at the top of the module, next to the other imports of its source.
+pub fn add_named_import(m: &mut Module, source: &str, name: &str) {
+  let index = m
+    .body
+    .iter()
+    .position(|item| !matches!(item, ModuleItem::ModuleDecl(ModuleDecl::Import(_))))
+    .unwrap_or(m.body.len());
+  let mut imports = m.body.drain(..index).collect::<Vec<ModuleItem>>();
+  get_or_create_named_import(&mut imports, source, name);
+  m.body.splice(0..0, imports);
+}
+
+/// Whether the module imports the namespace of React as `React`, like the classic runtime does.
+fn has_react_namespace(m: &Module) -> bool {
+  m.body.iter().any(|item| match item {
+    ModuleItem::ModuleDecl(ModuleDecl::Import(import)) => {
+      import.src.value == *"react"
+        && import.specifiers.iter().any(|specifier| {
+          matches!(specifier, ImportSpecifier::Namespace(namespace) if namespace.local.sym == "React")
+        })
+    }
+    _ => false,
+  })
+}
+
+/// The callee of a React hook, `React.useId` when React is imported as a namespace, otherwise the
+/// hook is imported from React or Preact.
+pub fn create_hook_callee(m: &mut Module, config: &Config, hook: &str) -> Expr {
+  if has_react_namespace(m) {
+    return Expr::Member(MemberExpr {
+      span: DUMMY_SP,
+      obj: Box::new(Expr::Ident(create_ident("React"))),
+      prop: MemberProp::Ident(IdentName::new(hook.into(), DUMMY_SP)),
+    });
+  }
+  let source = match config.jsx_runtime {
+    JSXRuntime::ClassicPreact => "preact/hooks",
+    _ => "react",
+  };
+  add_named_import(m, source, hook);
+  Expr::Ident(create_ident(hook))
+}
+
+/// The call generating a unique id at runtime, `React.useId()` or `createUniqueId()` with SolidJS.
+/// Its import is added to the module.
+pub fn create_unique_id_call(m: &mut Module, config: &Config) -> Result<Expr, SvgrError> {
+  let callee = match config.target {
+    Target::React => create_hook_callee(m, config, "useId"),
+    Target::Solid => {
+      add_named_import(m, "solid-js", "createUniqueId");
+      Expr::Ident(create_ident("createUniqueId"))
+    }
+    _ => {
+      return Err(SvgrError::Configuration(
+        r#"ids can only be generated at runtime for the "react" and "solid" targets, use "prefix" instead"#
+          .to_string(),
+      ))
+    }
+  };
+  Ok(Expr::Call(CallExpr {
+    span: DUMMY_SP,
+    ctxt: SyntaxContext::empty(),
+    callee: Callee::Expr(Box::new(callee)),
+    args: vec![],
+    type_args: None,
+  }))
+}
+
+pub fn create_const(name: &str, init: Expr) -> Stmt {
+  Stmt::Decl(Decl::Var(Box::new(VarDecl {
+    span: DUMMY_SP,
+    ctxt: SyntaxContext::empty(),
+    kind: VarDeclKind::Const,
+    declare: false,
+    decls: vec![VarDeclarator {
+      span: DUMMY_SP,
+      name: Pat::Ident(BindingIdent::from(create_ident(name))),
+      init: Some(Box::new(init)),
+      definite: false,
+    }],
+  })))
+}
+
+/// Make the ids of the SVG unique, with a prefix derived from the file path or with an id
+/// generated at runtime.
+///
+/// The generated id is not a valid CSS identifier, like `:r0:`, so the ids selected by the `#id`
+/// selectors of the `<style>` elements keep the prefix derived from the file path.
+pub fn transform(
+  m: &mut Module,
+  config: &Config,
+  component_name: &str,
+  file_path: Option<&str>,
+) -> Result<(), SvgrError> {
+  if config.unique_ids == UniqueIds::None {
+    return Ok(());
+  }
+
+  let mut collector = IdCollector::default();
+  m.visit_with(&mut collector);
+  if collector.ids.is_empty() {
+    return Ok(());
+  }
+
+  let mut runtime_ids = HashSet::new();
+  if config.unique_ids == UniqueIds::UseId {
+    let mut selected = SelectedIdCollector {
+      ids: &collector.ids,
+      selected: HashSet::new(),
+    };
+    m.visit_with(&mut selected);
+    runtime_ids = &collector.ids - &selected.selected;
+  }
+
+  if !runtime_ids.is_empty() {
+    let call = create_unique_id_call(m, config)?;
+    add_component_statements(m, vec![create_const(UNIQUE_ID, call)])?;
+  }
+  m.visit_mut_with(&mut Visitor {
+    ids: collector.ids,
+    prefix: get_prefix(file_path, component_name),
+    runtime_ids,
+  });
+  Ok(())
+}
+
+/// The ids of an SVG document.
+#[derive(Default)]
+struct DocumentIdCollector {
+  ids: HashSet<String>,
+}
+
+impl xml_visit::Visit for DocumentIdCollector {
+  fn visit_attribute(&mut self, n: &xml::Attribute) {
+    if n.name == "id" {
+      if let Some(value) = &n.value {
+        self.ids.insert(value.to_string());
+      }
+    }
+  }
+}
+
+/// Prefix the ids of an SVG document and the references to them, in its attributes and in the
+/// CSS of its `<style>` elements.
+struct DocumentVisitor {
+  ids: HashSet<String>,
+  prefix: String,
+}
+
+impl xml_visit::VisitMut for DocumentVisitor {
+  fn visit_mut_attribute(&mut self, n: &mut xml::Attribute) {
+    let Some(value) = &n.value else {
+      return;
+    };
+    let parts = split_references(&n.name, value, &self.ids);
+    if !parts.is_empty() {
+      n.value = Some(join_parts(parts, &self.prefix).into());
+      n.raw_value = None;
+    }
+  }
+
+  fn visit_mut_element(&mut self, n: &mut xml::Element) {
+    if n.tag_name == "style" {
+      for child in n.children.iter_mut() {
+        if let xml::Child::Text(xml::Text { data, raw, .. }) = child {
+          let parts = split_css_references(data, &self.ids);
+          if !parts.is_empty() {
+            *data = join_parts(parts, &self.prefix).into();
+            *raw = None;
+          }
+        }
+      }
+    }
+    n.visit_mut_children_with(self);
+  }
+}
+
+/// Prefix the ids of the elements of an SVG document, and the references to them, with a static
+/// prefix. The symbols of a sprite sheet are prefixed with their id so they do not collide.
+pub fn prefix_document_ids(children: &mut Vec<xml::Child>, prefix: &str) {
+  let mut collector = DocumentIdCollector::default();
+  children.visit_with(&mut collector);
+  children.visit_mut_with(&mut DocumentVisitor {
+    ids: collector.ids,
+    prefix: prefix.to_string(),
+  });
+}
+
+#[cfg(test)]
+mod tests {
+  use super::*;
+
+  #[test]
+  fn derives_prefix_from_file_path() {
+    assert_eq!(get_prefix(None, "SvgStar"), "svg-star");
+    let prefix = get_prefix(Some("icons/star.svg"), "SvgStar");
+    assert!(prefix.starts_with("star-"));
+    assert_ne!(prefix, get_prefix(Some("flags/star.svg"), "SvgStar"));
+  }
+
+  #[test]
+  fn splits_references_to_known_ids() {
+    let ids = HashSet::from(["a".to_string()]);
+    assert_eq!(
+      split_references("fill", "url(#a) url(#b)", &ids),
+      vec![
+        Part::Text("url(#".to_string()),
+        Part::Id("a".to_string()),
+        Part::Text(") url(#b)".to_string()),
+      ]
+    );
+    assert_eq!(split_references("fill", "url(#b)", &ids), vec![]);
+    assert_eq!(
+      split_css_references("#a, #b { fill: #a } .c { mask: url(#a) }", &ids),
+      vec![
+        Part::Text("#".to_string()),
+        Part::Id("a".to_string()),
+        Part::Text(", #b { fill: #a } .c { mask: url(#".to_string()),
+        Part::Id("a".to_string()),
+        Part::Text(") }".to_string()),
+      ]
+    );
+    assert_eq!(
+      split_references("aria-labelledby", "a b", &ids),
+      vec![
+        Part::Id("a".to_string()),
+        Part::Text(" ".to_string()),
+        Part::Text("b".to_string()),
+      ]
+    );
+  }
+}
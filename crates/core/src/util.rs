@@ -0,0 +1,46 @@
+use swc_core::{
+  common::{SyntaxContext, DUMMY_SP},
+  ecma::ast::{Ident, TplElement},
+};
+use swc_xml::ast::{Attribute, Element};
+
+pub fn create_ident(name: &str) -> Ident {
+  Ident::new(name.into(), DUMMY_SP, SyntaxContext::empty())
+}
+
+pub fn create_tpl_element(value: &str, tail: bool) -> TplElement {
+  let raw = value
+    .replace('\\', "\\\\")
+    .replace('`', "\\`")
+    .replace("${", "\\${");
+  TplElement {
+    span: DUMMY_SP,
+    tail,
+    cooked: Some(value.into()),
+    raw: raw.into(),
+  }
+}
+
+pub fn create_attr(name: &str, value: &str) -> Attribute {
+  Attribute {
+    span: DUMMY_SP,
+    namespace: None,
+    prefix: None,
+    name: name.into(),
+    raw_name: None,
+    value: Some(value.into()),
+    raw_value: None,
+  }
+}
+
+pub fn get_attr<'a>(element: &'a Element, name: &str) -> Option<&'a str> {
+  element
+    .attributes
+    .iter()
+    .find(|attr| attr.name == *name)
+    .and_then(|attr| attr.value.as_deref())
+}
+
+pub fn has_attr(element: &Element, name: &str) -> bool {
+  element.attributes.iter().any(|attr| attr.name == *name)
+}
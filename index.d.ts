@@ -1,3 +1,26 @@
+export interface SvgoConfig {
+  removeDoctype?: boolean
+  removeXmlProcInst?: boolean
+  removeComments?: boolean
+  removeMetadata?: boolean
+  removeEditorsNsData?: boolean
+  cleanupAttrs?: boolean
+  removeUselessDefs?: boolean
+  collapseGroups?: boolean
+  removeEmptyAttrs?: boolean
+  removeEmptyText?: boolean
+  removeEmptyContainers?: boolean
+}
+
+export interface PrettierConfig {
+  printWidth?: number
+  tabWidth?: number
+  useTabs?: boolean
+  singleQuote?: boolean
+  semi?: boolean
+  trailingComma?: 'none' | 'es5' | 'all'
+}
+
 export interface Config {
   ref?: boolean
   titleProp?: boolean
@@ -6,6 +29,10 @@ export interface Config {
   dimensions?: boolean
   icon?: boolean | string | number
   native?: boolean
+  nativeUnsupported?: 'drop' | 'keep' | 'error'
+  nativeComponents?: { [element: string]: string }
+  nativeImportSource?: string
+  nativeElements?: { [element: string]: 'drop' | 'keep' | 'error' }
   svgProps?: {
     [key: string]: string
   }
@@ -15,11 +42,24 @@ export interface Config {
   runtimeConfig?: boolean
   typescript?: boolean
   prettier?: boolean
-  // prettierConfig?: PrettierOptions
+  prettierConfig?: PrettierConfig
   svgo?: boolean
-  // svgoConfig?: SvgoConfig
+  svgoConfig?: SvgoConfig
   configFile?: string
-  // template?: TransformOptions['template']
+  sourceMaps?: boolean
+  strict?: boolean
+  preserveComments?: boolean
+  inlineStyles?: boolean
+  cssModules?: boolean
+  target?: 'react' | 'vue' | 'vue-sfc' | 'solid' | 'svelte' | 'lit' | 'web-component'
+  elementName?: string
+  baseClass?: {
+    name: string
+    source?: string
+  }
+  uniqueIds?: 'none' | 'prefix' | 'use-id'
+  fallbackIds?: 'none' | 'use-id' | 'counter'
+  template?: string
   memo?: boolean
   exportType?: 'named' | 'default'
   namedExport?: string
@@ -31,9 +71,20 @@ export interface Config {
     defaultSpecifier?: string
   }
 
+  plugins?: (
+    | 'remove-jsx-attribute'
+    | 'add-jsx-attribute'
+    | 'svg-em-dimensions'
+    | 'replace-jsx-attribute-value'
+    | 'svg-dynamic-title'
+    | 'svg-dynamic-desc'
+    | 'unique-ids'
+    | 'transform-react-native-svg'
+  )[]
+
   // CLI only
   index?: boolean
-  // plugins?: ConfigPlugin[]
+  indexTemplate?: (files: IndexFile[], config: Config) => string
 
   // JSX
   // jsx?: {
@@ -51,4 +102,83 @@ export interface State {
   }
 }
 
-export function transform(code: string, config?: Config, state?: Partial<State>): Promise<string>
+export interface IndexFile {
+  path: string
+  componentName: string
+}
+
+export interface Warning {
+  code: string
+  message: string
+  loc?: {
+    line: number
+    column: number
+  }
+}
+
+export interface OutputFile {
+  path: string
+  code: string
+}
+
+export interface TransformOutput {
+  code: string
+  map?: string
+  componentName: string
+  exports: string[]
+  imports: string[]
+  warnings: Warning[]
+  files: OutputFile[]
+}
+
+export function transform(code: string, config?: Config, state?: Partial<State>): Promise<TransformOutput>
+
+export interface TransformInput {
+  code: string
+  state?: Partial<State>
+}
+
+export type ErrorCode =
+  | 'parse-error'
+  | 'empty-input'
+  | 'no-root-element'
+  | 'non-svg-root'
+  | 'invalid-xml'
+  | 'unsupported-native-elements'
+  | 'invalid-file-path'
+  | 'invalid-configuration'
+  | 'source-map-error'
+  | 'codegen-error'
+
+export interface TransformError {
+  message: string
+  code: ErrorCode
+  filePath?: string
+  loc?: {
+    line: number
+    column: number
+  }
+  snippet?: string
+}
+
+export interface TransformResult {
+  output?: TransformOutput
+  error?: TransformError
+}
+
+export function transformBatch(inputs: TransformInput[], config?: Config): Promise<TransformResult[]>
+
+export interface SpriteOutput {
+  sprite: string
+  results: TransformResult[]
+}
+
+export function transformSprite(
+  inputs: TransformInput[],
+  href: string,
+  config?: Config,
+): Promise<SpriteOutput>
+
+export function resolveConfig(path: string): Config | null
+
+export function createIndex(files: IndexFile[], config?: Config): string
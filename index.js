@@ -1,5 +1,22 @@
-const { transform } = require('./binding')
+const { transform, transformBatch, transformSprite, resolveConfig, createIndex } = require('./binding')
 
 module.exports.transform = function (code, config, state) {
 	return transform(code, config, state)
 }
+
+module.exports.transformBatch = function (inputs, config) {
+	return transformBatch(inputs, config)
+}
+
+module.exports.transformSprite = function (inputs, href, config) {
+	return transformSprite(inputs, href, config)
+}
+
+module.exports.resolveConfig = resolveConfig
+
+module.exports.createIndex = function (files, config) {
+	if (config && typeof config.indexTemplate === 'function') {
+		return config.indexTemplate(files, config)
+	}
+	return createIndex(files, config)
+}